@@ -1,6 +1,6 @@
 mod build_helpers;
 
-use build_helpers::{ascii, date, hash, keybinds, statusline, theme, version};
+use build_helpers::{ascii, date, editor, hash, keybinds, statusline, theme, version};
 
 fn main() {
     // Load environment from sys/env/.env
@@ -23,6 +23,9 @@ fn main() {
     // Load keybinds configuration
     keybinds::load_keybinds_config();
 
+    // Load editor options configuration
+    editor::load_editor_config();
+
     // Load ASCII art
     ascii::load_ascii_art();
 
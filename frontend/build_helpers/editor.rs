@@ -0,0 +1,51 @@
+use super::keybinds::expand_tilde;
+
+/// Load editor options configuration with XDG compliance support.
+///
+/// Order of precedence:
+/// 1. User-specified editor file (USER_EDITOR_FILE env var)
+/// 2. Default frontend/editor.toml
+///
+/// The selected file path is set as EDITOR_CONFIG_FILE env var for
+/// embedding into the WASM binary. Path must be relative to
+/// frontend/src/state/editor_options.rs (where include_str! is called).
+pub fn load_editor_config() {
+    const BLUE: &str = "\x1b[38;2;137;180;250m";
+    const GREEN: &str = "\x1b[38;2;166;227;161m";
+    const NC: &str = "\x1b[0m";
+    const INFO_ICON: &str = "\u{f05a}"; //
+    const CHECK_ICON: &str = "\u{f00c}"; //
+
+    // Path relative to src/state/editor_options.rs where include_str! is called
+    let default_path = "../../editor.toml";
+
+    // Try user-specified editor file first
+    if let Ok(user_editor) = std::env::var("USER_EDITOR_FILE") {
+        let expanded_path = expand_tilde(&user_editor);
+
+        if expanded_path.exists() {
+            eprintln!(
+                "{}{}  {}[editor] Using XDG config: {}{}",
+                GREEN,
+                CHECK_ICON,
+                NC,
+                BLUE,
+                expanded_path.display()
+            );
+            println!(
+                "cargo:rustc-env=EDITOR_CONFIG_FILE={}",
+                expanded_path.display()
+            );
+            println!("cargo:rerun-if-changed={}", expanded_path.display());
+            return;
+        }
+    }
+
+    // Fall back to default editor.toml
+    eprintln!(
+        "{}{}  {}[editor] Using default config: editor.toml",
+        BLUE, INFO_ICON, NC
+    );
+    println!("cargo:rustc-env=EDITOR_CONFIG_FILE={}", default_path);
+    println!("cargo:rerun-if-changed=editor.toml");
+}
@@ -48,7 +48,7 @@ pub fn load_keybinds_config() {
 }
 
 /// Expand tilde (~/) in path to HOME directory.
-fn expand_tilde(path: &str) -> PathBuf {
+pub fn expand_tilde(path: &str) -> PathBuf {
     if let Some(stripped) = path.strip_prefix("~/")
         && let Ok(home) = std::env::var("HOME")
     {
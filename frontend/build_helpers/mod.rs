@@ -1,5 +1,6 @@
 pub mod ascii;
 pub mod date;
+pub mod editor;
 pub mod hash;
 pub mod keybinds;
 pub mod statusline;
@@ -0,0 +1,31 @@
+use super::jobs::post_job;
+use super::types::{ApplyPlan, JobInfo};
+use gloo_net::http::Request;
+use wasm_bindgen::JsValue;
+
+/// Validate a project's compose files and list services that would change
+pub async fn fetch_apply_plan(project: &str) -> Result<ApplyPlan, JsValue> {
+    let url = format!("/api/projects/{}/plan", project);
+    let response = Request::get(&url)
+        .send()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to fetch apply plan: {}", e)))?;
+
+    if !response.ok() {
+        return Err(JsValue::from_str(&format!(
+            "Server returned error: {}",
+            response.status()
+        )));
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))
+}
+
+/// Validate a project's compose files and start `compose up -d` as a job
+pub async fn apply_project(project: &str) -> Result<JobInfo, JsValue> {
+    let url = format!("/api/projects/{}/apply", project);
+    post_job(&url, "apply project").await
+}
@@ -2,7 +2,8 @@ use super::types::{FileContentResponse, FileInfo, FileListResponse, WriteConfigR
 use gloo_net::http::Request;
 use wasm_bindgen::JsValue;
 
-pub async fn fetch_file_list() -> Result<Vec<FileInfo>, JsValue> {
+/// Fetch managed files and the containers whose files can be browsed
+pub async fn fetch_file_list() -> Result<(Vec<FileInfo>, Vec<String>), JsValue> {
     let response = Request::get("/api/configs")
         .send()
         .await
@@ -20,7 +21,7 @@ pub async fn fetch_file_list() -> Result<Vec<FileInfo>, JsValue> {
         .await
         .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
 
-    Ok(data.files)
+    Ok((data.files, data.containers))
 }
 
 pub async fn fetch_file_content(filename: &str) -> Result<String, JsValue> {
@@ -30,6 +31,12 @@ pub async fn fetch_file_content(filename: &str) -> Result<String, JsValue> {
         .await
         .map_err(|e| JsValue::from_str(&format!("Failed to fetch file: {}", e)))?;
 
+    // Files inside containers explain why they can't be opened
+    if matches!(response.status(), 403 | 404 | 409 | 413 | 422) {
+        let reason = response.text().await.unwrap_or_default();
+        return Err(JsValue::from_str(&reason));
+    }
+
     if !response.ok() {
         return Err(JsValue::from_str(&format!(
             "Server returned error: {}",
@@ -56,6 +63,39 @@ pub async fn save_file_content(filename: &str, content: String) -> Result<(), Js
         .await
         .map_err(|e| JsValue::from_str(&format!("Failed to save file: {}", e)))?;
 
+    if matches!(response.status(), 403 | 404 | 409 | 413 | 422) {
+        let reason = response.text().await.unwrap_or_default();
+        return Err(JsValue::from_str(&reason));
+    }
+
+    if !response.ok() {
+        return Err(JsValue::from_str(&format!(
+            "Server returned error: {}",
+            response.status()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Create a new file in a configured directory (fails if it exists)
+pub async fn create_file_content(filename: &str, content: String) -> Result<(), JsValue> {
+    let url = format!("/api/configs/{}", filename);
+    let payload = WriteConfigRequest { content };
+
+    let response = Request::put(&url)
+        .json(&payload)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize JSON: {}", e)))?
+        .send()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to create file: {}", e)))?;
+
+    // 400/409 explain why the file can't be created there
+    if matches!(response.status(), 400 | 409) {
+        let reason = response.text().await.unwrap_or_default();
+        return Err(JsValue::from_str(&reason));
+    }
+
     if !response.ok() {
         return Err(JsValue::from_str(&format!(
             "Server returned error: {}",
@@ -1,11 +1,14 @@
+use super::jobs::{post_job, post_job_json};
 use super::types::{
-    ContainerActionResponse, ContainerDetails, ContainerDetailsResponse, ContainerInfo,
-    ContainerListResponse,
+    ComposeSnippet, ContainerActionResponse, ContainerDetails, ContainerDetailsResponse,
+    ContainerDir, ContainerInfo, ContainerListResponse, JobInfo, ProjectInfo,
+    UpdateSettingsRequest,
 };
 use gloo_net::http::Request;
 use wasm_bindgen::JsValue;
 
-pub async fn fetch_container_list() -> Result<Vec<ContainerInfo>, JsValue> {
+/// Fetch all containers together with their compose project grouping
+pub async fn fetch_container_list() -> Result<(Vec<ContainerInfo>, Vec<ProjectInfo>), JsValue> {
     let response = Request::get("/api/containers")
         .send()
         .await
@@ -23,7 +26,7 @@ pub async fn fetch_container_list() -> Result<Vec<ContainerInfo>, JsValue> {
         .await
         .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
 
-    Ok(data.containers)
+    Ok((data.containers, data.projects))
 }
 
 pub async fn fetch_container_details(container_id: &str) -> Result<ContainerDetails, JsValue> {
@@ -48,24 +51,142 @@ pub async fn fetch_container_details(container_id: &str) -> Result<ContainerDeta
     Ok(data.details)
 }
 
-pub async fn start_container(container_id: &str) -> Result<String, JsValue> {
+/// Compose service definition generated from a container
+pub async fn fetch_compose_snippet(container_id: &str) -> Result<ComposeSnippet, JsValue> {
+    let url = format!("/api/containers/{}/compose", container_id);
+    let response = Request::get(&url)
+        .send()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to fetch compose snippet: {}", e)))?;
+
+    if !response.ok() {
+        return Err(JsValue::from_str(&format!(
+            "Server returned error: {}",
+            response.status()
+        )));
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))
+}
+
+/// List a directory inside a running container
+pub async fn fetch_container_dir(container: &str, path: &str) -> Result<ContainerDir, JsValue> {
+    let url = format!("/api/containers/{}/files", container);
+    let response = Request::get(&url)
+        .query([("path", path)])
+        .send()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to list directory: {}", e)))?;
+
+    // 403/409 explain why the directory can't be listed
+    if matches!(response.status(), 403 | 409) {
+        let reason = response.text().await.unwrap_or_default();
+        return Err(JsValue::from_str(&reason));
+    }
+
+    if !response.ok() {
+        return Err(JsValue::from_str(&format!(
+            "Server returned error: {}",
+            response.status()
+        )));
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))
+}
+
+pub async fn start_container(container_id: &str) -> Result<JobInfo, JsValue> {
     execute_container_action(container_id, "start").await
 }
 
-pub async fn stop_container(container_id: &str) -> Result<String, JsValue> {
+pub async fn stop_container(container_id: &str) -> Result<JobInfo, JsValue> {
     execute_container_action(container_id, "stop").await
 }
 
-pub async fn restart_container(container_id: &str) -> Result<String, JsValue> {
+pub async fn restart_container(container_id: &str) -> Result<JobInfo, JsValue> {
     execute_container_action(container_id, "restart").await
 }
 
-async fn execute_container_action(container_id: &str, action: &str) -> Result<String, JsValue> {
+pub async fn pause_container(container_id: &str) -> Result<JobInfo, JsValue> {
+    execute_container_action(container_id, "pause").await
+}
+
+pub async fn unpause_container(container_id: &str) -> Result<JobInfo, JsValue> {
+    execute_container_action(container_id, "unpause").await
+}
+
+pub async fn kill_container(container_id: &str, signal: &str) -> Result<JobInfo, JsValue> {
+    let url = format!("/api/containers/{}/kill?signal={}", container_id, signal);
+    post_job(&url, "kill").await
+}
+
+/// Remove a container (`force` is needed for running containers)
+pub async fn remove_container(
+    container_id: &str,
+    volumes: bool,
+    force: bool,
+) -> Result<JobInfo, JsValue> {
+    let url = format!(
+        "/api/containers/{}/remove?volumes={}&force={}",
+        container_id, volumes, force
+    );
+    post_job(&url, "remove").await
+}
+
+/// Pull the image and recreate the container (compose services only)
+pub async fn recreate_container(container_id: &str) -> Result<JobInfo, JsValue> {
+    execute_container_action(container_id, "recreate").await
+}
+
+/// Change restart policy and resource limits in place
+pub async fn update_container(
+    container_id: &str,
+    request: &UpdateSettingsRequest,
+) -> Result<JobInfo, JsValue> {
+    let url = format!("/api/containers/{}/update", container_id);
+    post_job_json(&url, "update", request).await
+}
+
+pub async fn start_project(project: &str) -> Result<JobInfo, JsValue> {
+    execute_project_action(project, "start").await
+}
+
+pub async fn stop_project(project: &str) -> Result<JobInfo, JsValue> {
+    execute_project_action(project, "stop").await
+}
+
+pub async fn restart_project(project: &str) -> Result<JobInfo, JsValue> {
+    execute_project_action(project, "restart").await
+}
+
+pub async fn compose_up(project: &str) -> Result<JobInfo, JsValue> {
+    execute_project_action(project, "up").await
+}
+
+pub async fn compose_down(project: &str) -> Result<JobInfo, JsValue> {
+    execute_project_action(project, "down").await
+}
+
+async fn execute_container_action(container_id: &str, action: &str) -> Result<JobInfo, JsValue> {
     let url = format!("/api/containers/{}/{}", container_id, action);
-    let response = Request::post(&url)
+    post_job(&url, action).await
+}
+
+async fn execute_project_action(project: &str, action: &str) -> Result<JobInfo, JsValue> {
+    let url = format!("/api/projects/{}/{}", project, action);
+    post_job(&url, action).await
+}
+
+pub(super) async fn post_action(url: &str, action: &str) -> Result<String, JsValue> {
+    let response = Request::post(url)
         .send()
         .await
-        .map_err(|e| JsValue::from_str(&format!("Failed to {} container: {}", action, e)))?;
+        .map_err(|e| JsValue::from_str(&format!("Failed to {}: {}", action, e)))?;
 
     if !response.ok() {
         return Err(JsValue::from_str(&format!(
@@ -0,0 +1,117 @@
+use js_sys::{ArrayBuffer, Uint8Array};
+use std::{cell::Cell, rc::Rc};
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+use web_sys::{BinaryType, CloseEvent, Event, MessageEvent, WebSocket};
+
+/// An open exec session (WebSocket to `/api/containers/:id/exec`)
+/// The socket is closed when the session is dropped.
+pub struct ExecSession {
+    socket: WebSocket,
+    /// Size requested before the socket was open (sent on open)
+    pending_size: Rc<Cell<Option<(u16, u16)>>>,
+    _on_open: Closure<dyn FnMut(Event)>,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    _on_close: Closure<dyn FnMut(CloseEvent)>,
+}
+
+impl ExecSession {
+    /// Send terminal input (keystrokes)
+    pub fn send_input(&self, data: &[u8]) {
+        let _ = self.socket.send_with_u8_array(data);
+    }
+
+    /// Tell the server about a new terminal size
+    pub fn resize(&self, cols: u16, rows: u16) {
+        if self.socket.ready_state() == WebSocket::CONNECTING {
+            self.pending_size.set(Some((cols, rows)));
+        } else {
+            send_resize(&self.socket, cols, rows);
+        }
+    }
+}
+
+impl Drop for ExecSession {
+    fn drop(&mut self) {
+        self.socket.set_onopen(None);
+        self.socket.set_onmessage(None);
+        self.socket.set_onclose(None);
+        let _ = self.socket.close();
+    }
+}
+
+/// Open an exec session with a shell in a container
+///
+/// `on_output` receives raw terminal output, `on_close` the close reason
+/// (`exit: <code>` when the shell exited).
+pub fn open_exec(
+    container_id: &str,
+    cols: u16,
+    rows: u16,
+    mut on_output: impl FnMut(&[u8]) + 'static,
+    mut on_close: impl FnMut(String) + 'static,
+) -> Result<ExecSession, JsValue> {
+    let url = format!(
+        "{}/api/containers/{}/exec?cols={}&rows={}",
+        websocket_origin()?,
+        container_id,
+        cols,
+        rows
+    );
+    let socket = WebSocket::new(&url)?;
+    socket.set_binary_type(BinaryType::Arraybuffer);
+
+    let pending_size: Rc<Cell<Option<(u16, u16)>>> = Rc::new(Cell::new(None));
+    let open_socket = socket.clone();
+    let open_size = Rc::clone(&pending_size);
+    let on_open = Closure::<dyn FnMut(Event)>::new(move |_: Event| {
+        if let Some((cols, rows)) = open_size.take() {
+            send_resize(&open_socket, cols, rows);
+        }
+    });
+    socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+
+    let on_message = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+        if let Ok(buffer) = event.data().dyn_into::<ArrayBuffer>() {
+            on_output(&Uint8Array::new(&buffer).to_vec());
+        }
+    });
+    socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+
+    // The server rejects the upgrade (403/404) before any message, which
+    // surfaces as an abnormal close without a reason
+    let on_close = Closure::<dyn FnMut(CloseEvent)>::new(move |event: CloseEvent| {
+        let reason = event.reason();
+        on_close(if reason.is_empty() {
+            format!("connection closed ({})", event.code())
+        } else {
+            reason
+        });
+    });
+    socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+
+    Ok(ExecSession {
+        socket,
+        pending_size,
+        _on_open: on_open,
+        _on_message: on_message,
+        _on_close: on_close,
+    })
+}
+
+fn send_resize(socket: &WebSocket, cols: u16, rows: u16) {
+    let message = format!(r#"{{"type":"resize","cols":{},"rows":{}}}"#, cols, rows);
+    let _ = socket.send_with_str(&message);
+}
+
+/// `ws://host` or `wss://host` for the current page
+fn websocket_origin() -> Result<String, JsValue> {
+    let location = web_sys::window()
+        .ok_or_else(|| JsValue::from_str("No window"))?
+        .location();
+    let scheme = if location.protocol()? == "https:" {
+        "wss"
+    } else {
+        "ws"
+    };
+    Ok(format!("{}://{}", scheme, location.host()?))
+}
@@ -0,0 +1,57 @@
+use super::containers::post_action;
+use super::types::{ImageDetails, ImageDetailsResponse, ImageInfo, ImageListResponse};
+use gloo_net::http::Request;
+use wasm_bindgen::JsValue;
+
+pub async fn fetch_image_list() -> Result<Vec<ImageInfo>, JsValue> {
+    let response = Request::get("/api/images")
+        .send()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to fetch images: {}", e)))?;
+
+    if !response.ok() {
+        return Err(JsValue::from_str(&format!(
+            "Server returned error: {}",
+            response.status()
+        )));
+    }
+
+    let data: ImageListResponse = response
+        .json()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+    Ok(data.images)
+}
+
+pub async fn fetch_image_details(image_id: &str) -> Result<ImageDetails, JsValue> {
+    let url = format!("/api/images/{}", image_id);
+    let response = Request::get(&url)
+        .send()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to fetch image details: {}", e)))?;
+
+    if !response.ok() {
+        return Err(JsValue::from_str(&format!(
+            "Server returned error: {}",
+            response.status()
+        )));
+    }
+
+    let data: ImageDetailsResponse = response
+        .json()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+    Ok(data.details)
+}
+
+pub async fn remove_image(image_id: &str, force: bool) -> Result<String, JsValue> {
+    let url = format!("/api/images/{}/remove?force={}", image_id, force);
+    post_action(&url, "remove image").await
+}
+
+/// Remove all dangling images
+pub async fn prune_images() -> Result<String, JsValue> {
+    post_action("/api/images/prune", "prune images").await
+}
@@ -0,0 +1,75 @@
+use super::types::{JobInfo, JobResponse};
+use gloo_net::http::{Request, Response};
+use serde::Serialize;
+use wasm_bindgen::JsValue;
+
+pub async fn fetch_job(id: u64) -> Result<JobInfo, JsValue> {
+    let url = format!("/api/jobs/{}", id);
+    let response = Request::get(&url)
+        .send()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to fetch job: {}", e)))?;
+
+    if !response.ok() {
+        return Err(JsValue::from_str(&format!(
+            "Server returned error: {}",
+            response.status()
+        )));
+    }
+
+    let data: JobResponse = response
+        .json()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+    Ok(data.job)
+}
+
+/// POST to an action endpoint that starts a background job
+pub(super) async fn post_job(url: &str, action: &str) -> Result<JobInfo, JsValue> {
+    let response = Request::post(url)
+        .send()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to {}: {}", action, e)))?;
+
+    job_from_response(response).await
+}
+
+/// POST a JSON body to an action endpoint that starts a background job
+pub(super) async fn post_job_json<T: Serialize>(
+    url: &str,
+    action: &str,
+    body: &T,
+) -> Result<JobInfo, JsValue> {
+    let response = Request::post(url)
+        .json(body)
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize request: {}", e)))?
+        .send()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to {}: {}", action, e)))?;
+
+    job_from_response(response).await
+}
+
+async fn job_from_response(response: Response) -> Result<JobInfo, JsValue> {
+    // 400 (invalid input), 409 (another job holds the container) and 422
+    // (invalid compose files) say why in the body
+    if matches!(response.status(), 400 | 409 | 422) {
+        let reason = response.text().await.unwrap_or_default();
+        return Err(JsValue::from_str(&reason));
+    }
+
+    if !response.ok() {
+        return Err(JsValue::from_str(&format!(
+            "Server returned error: {}",
+            response.status()
+        )));
+    }
+
+    let data: JobResponse = response
+        .json()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+    Ok(data.job)
+}
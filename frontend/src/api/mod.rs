@@ -1,10 +1,28 @@
+mod compose;
 mod configs;
 mod containers;
+mod exec;
+mod images;
+mod jobs;
+mod networks;
 mod types;
+mod volumes;
 
-pub use configs::{fetch_file_content, fetch_file_list, save_file_content};
+pub use compose::{apply_project, fetch_apply_plan};
+pub use configs::{create_file_content, fetch_file_content, fetch_file_list, save_file_content};
 pub use containers::{
-    fetch_container_details, fetch_container_list, restart_container, start_container,
-    stop_container,
+    compose_down, compose_up, fetch_compose_snippet, fetch_container_details, fetch_container_dir,
+    fetch_container_list, kill_container, pause_container, recreate_container, remove_container,
+    restart_container, restart_project, start_container, start_project, stop_container,
+    stop_project, unpause_container, update_container,
 };
-pub use types::{ContainerDetails, ContainerInfo, FileInfo};
+pub use exec::{ExecSession, open_exec};
+pub use images::{fetch_image_details, fetch_image_list, prune_images, remove_image};
+pub use jobs::fetch_job;
+pub use networks::{fetch_network_list, prune_networks, remove_network};
+pub use types::{
+    ContainerDetails, ContainerDirEntry, ContainerInfo, FileInfo, ImageDetails, ImageInfo, JobInfo,
+    MountUser, NetworkInfo, ProjectInfo, ResourceLimits, ServicePlan, UpdateSettingsRequest,
+    VolumeInfo,
+};
+pub use volumes::{fetch_volume_list, prune_volumes, remove_volume};
@@ -0,0 +1,35 @@
+use super::containers::post_action;
+use super::types::{NetworkInfo, NetworkListResponse};
+use gloo_net::http::Request;
+use wasm_bindgen::JsValue;
+
+pub async fn fetch_network_list() -> Result<Vec<NetworkInfo>, JsValue> {
+    let response = Request::get("/api/networks")
+        .send()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to fetch networks: {}", e)))?;
+
+    if !response.ok() {
+        return Err(JsValue::from_str(&format!(
+            "Server returned error: {}",
+            response.status()
+        )));
+    }
+
+    let data: NetworkListResponse = response
+        .json()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+    Ok(data.networks)
+}
+
+pub async fn remove_network(id: &str) -> Result<String, JsValue> {
+    let url = format!("/api/networks/{}/remove", id);
+    post_action(&url, "remove network").await
+}
+
+/// Remove all networks without attached containers
+pub async fn prune_networks() -> Result<String, JsValue> {
+    post_action("/api/networks/prune", "prune networks").await
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Deserialize, Serialize, Clone, PartialEq)]
 pub struct FileInfo {
@@ -8,11 +9,29 @@ pub struct FileInfo {
     /// Optional theme variant for this file
     #[serde(default)]
     pub theme: Option<String>,
+    /// Compose project for discovered compose files
+    #[serde(default)]
+    pub compose_project: Option<String>,
+    /// Containers bind-mounting this file (or one of its parent directories)
+    #[serde(default)]
+    pub used_by: Vec<MountUser>,
+}
+
+/// A container that bind-mounts a managed config
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
+pub struct MountUser {
+    pub id: String,
+    pub name: String,
+    /// Mount destination inside the container
+    pub destination: String,
 }
 
 #[derive(Deserialize)]
 pub(super) struct FileListResponse {
     pub files: Vec<FileInfo>,
+    /// Containers whose files can be browsed
+    #[serde(default)]
+    pub containers: Vec<String>,
 }
 
 #[derive(Deserialize)]
@@ -31,11 +50,53 @@ pub struct ContainerInfo {
     pub name: String,
     pub state: String,
     pub status: String,
+    #[serde(default)]
+    pub image: String,
+    /// Creation time as printed by `docker ps`
+    #[serde(default)]
+    pub created: String,
+    /// Compose project this container belongs to
+    #[serde(default)]
+    pub project: Option<String>,
+    /// Compose service name within the project
+    #[serde(default)]
+    pub service: Option<String>,
 }
 
 #[derive(Deserialize)]
 pub(super) struct ContainerListResponse {
     pub containers: Vec<ContainerInfo>,
+    #[serde(default)]
+    pub projects: Vec<ProjectInfo>,
+}
+
+/// What `compose up` would do with a service
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct ServicePlan {
+    pub service: String,
+    /// One of: create, recreate, unchanged, orphan
+    pub action: String,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct ApplyPlan {
+    pub valid: bool,
+    #[serde(default)]
+    pub error: Option<String>,
+    pub services: Vec<ServicePlan>,
+}
+
+/// Aggregated Docker Compose project
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
+pub struct ProjectInfo {
+    pub name: String,
+    pub containers: Vec<String>,
+    pub running: usize,
+    pub unhealthy: usize,
+    #[serde(default)]
+    pub config_files: Vec<String>,
+    #[serde(default)]
+    pub working_dir: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -47,10 +108,73 @@ pub(super) struct ContainerActionResponse {
 #[derive(Deserialize, Clone)]
 pub struct PortMapping {
     pub container_port: String,
-    pub host_port: String,
+    /// None for exposed ports that are not published
+    pub host_ip: Option<String>,
+    pub host_port: Option<String>,
     pub protocol: String,
 }
 
+/// Resource limits (0 means unlimited/default)
+#[derive(Deserialize, Clone)]
+pub struct ResourceLimits {
+    pub memory: u64,
+    pub memory_reservation: u64,
+    pub cpu_shares: u64,
+    pub cpu_quota: i64,
+    pub cpu_period: u64,
+    pub nano_cpus: u64,
+}
+
+/// Directory listing inside a container
+#[derive(Deserialize)]
+pub struct ContainerDir {
+    pub path: String,
+    pub entries: Vec<ContainerDirEntry>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct ContainerDirEntry {
+    pub name: String,
+    pub dir: bool,
+}
+
+/// Compose service generated from a container
+#[derive(Deserialize)]
+pub struct ComposeSnippet {
+    pub service: String,
+    pub content: String,
+    /// Managed file name the snippet can be saved as
+    #[serde(default)]
+    pub suggested_name: Option<String>,
+}
+
+/// Settings changed with `docker update`; `None` leaves a setting alone
+#[derive(Serialize, Default)]
+pub struct UpdateSettingsRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restart_policy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpus: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct HealthcheckConfig {
+    pub test: Vec<String>,
+    pub interval: String,
+    pub timeout: String,
+    pub start_period: String,
+    pub retries: u64,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct HealthLogEntry {
+    pub start: String,
+    pub exit_code: i64,
+    pub output: String,
+}
+
 #[derive(Deserialize, Clone)]
 pub struct VolumeMount {
     pub source: String,
@@ -73,9 +197,127 @@ pub struct ContainerDetails {
     pub environment: Vec<String>,
     pub restart_policy: String,
     pub health: Option<String>,
+    pub labels: BTreeMap<String, String>,
+    pub command: Vec<String>,
+    pub entrypoint: Vec<String>,
+    pub working_dir: String,
+    pub user: String,
+    pub limits: ResourceLimits,
+    pub restart_count: u64,
+    pub exit_code: i64,
+    pub oom_killed: bool,
+    pub finished: String,
+    pub healthcheck: Option<HealthcheckConfig>,
+    pub health_log: Vec<HealthLogEntry>,
+    /// Unmodified `docker inspect` output
+    pub raw: serde_json::Value,
 }
 
 #[derive(Deserialize)]
 pub(super) struct ContainerDetailsResponse {
     pub details: ContainerDetails,
 }
+
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
+pub struct ImageInfo {
+    /// Full image ID (`sha256:...`)
+    pub id: String,
+    /// `repository:tag` references (empty for dangling images)
+    pub tags: Vec<String>,
+    pub size: String,
+    /// Relative creation time (e.g. "3 weeks ago")
+    pub created: String,
+    pub dangling: bool,
+    /// Names of containers created from this image
+    pub containers: Vec<String>,
+}
+
+#[derive(Deserialize)]
+pub(super) struct ImageListResponse {
+    pub images: Vec<ImageInfo>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct ImageDetails {
+    pub id: String,
+    pub digests: Vec<String>,
+    pub created: String,
+    pub architecture: String,
+    pub os: String,
+    pub entrypoint: Vec<String>,
+    pub cmd: Vec<String>,
+    pub environment: Vec<String>,
+    pub exposed_ports: Vec<String>,
+    pub layers: usize,
+}
+
+#[derive(Deserialize)]
+pub(super) struct ImageDetailsResponse {
+    pub details: ImageDetails,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct VolumeInfo {
+    pub name: String,
+    pub driver: String,
+    pub mountpoint: String,
+    pub scope: String,
+    /// Disk usage (only reported for local volumes)
+    pub size: Option<String>,
+    /// Names of containers mounting this volume
+    pub containers: Vec<String>,
+}
+
+#[derive(Deserialize)]
+pub(super) struct VolumeListResponse {
+    pub volumes: Vec<VolumeInfo>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct NetworkInfo {
+    pub id: String,
+    pub name: String,
+    pub driver: String,
+    pub scope: String,
+    pub internal: bool,
+    /// bridge, host and none cannot be removed
+    pub builtin: bool,
+    pub subnets: Vec<String>,
+    /// Names of containers attached to this network
+    pub containers: Vec<String>,
+}
+
+#[derive(Deserialize)]
+pub(super) struct NetworkListResponse {
+    pub networks: Vec<NetworkInfo>,
+}
+
+/// Background container operation started by an action endpoint
+#[derive(Deserialize, Clone)]
+pub struct JobInfo {
+    pub id: u64,
+    pub action: String,
+    /// Container or project name
+    pub target: String,
+    #[serde(default)]
+    pub project: Option<String>,
+    pub container_ids: Vec<String>,
+    /// running, succeeded or failed
+    pub status: String,
+    /// Current step while running
+    pub step: String,
+    pub output: Vec<String>,
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+impl JobInfo {
+    pub fn is_running(&self) -> bool {
+        self.status == "running"
+    }
+}
+
+#[derive(Deserialize)]
+pub(super) struct JobResponse {
+    pub job: JobInfo,
+}
@@ -0,0 +1,35 @@
+use super::containers::post_action;
+use super::types::{VolumeInfo, VolumeListResponse};
+use gloo_net::http::Request;
+use wasm_bindgen::JsValue;
+
+pub async fn fetch_volume_list() -> Result<Vec<VolumeInfo>, JsValue> {
+    let response = Request::get("/api/volumes")
+        .send()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to fetch volumes: {}", e)))?;
+
+    if !response.ok() {
+        return Err(JsValue::from_str(&format!(
+            "Server returned error: {}",
+            response.status()
+        )));
+    }
+
+    let data: VolumeListResponse = response
+        .json()
+        .await
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse JSON: {}", e)))?;
+
+    Ok(data.volumes)
+}
+
+pub async fn remove_volume(name: &str) -> Result<String, JsValue> {
+    let url = format!("/api/volumes/{}/remove", name);
+    post_action(&url, "remove volume").await
+}
+
+/// Remove unused anonymous volumes
+pub async fn prune_volumes() -> Result<String, JsValue> {
+    post_action("/api/volumes/prune", "prune volumes").await
+}
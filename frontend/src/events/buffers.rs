@@ -0,0 +1,97 @@
+use super::key_matches;
+use crate::state::{AppState, Pane};
+use ratzilla::event::KeyEvent;
+
+/// Buffer switching keys (file list and editor)
+pub fn handle_keys(state: &mut AppState, key_event: &KeyEvent) -> bool {
+    if !matches!(state.focus, Pane::FileList | Pane::Editor) {
+        return false;
+    }
+    let keybinds = &state.keybinds.global;
+    let result = if key_matches(key_event, &keybinds.next_buffer) {
+        cycle(state, true)
+    } else if key_matches(key_event, &keybinds.previous_buffer) {
+        cycle(state, false)
+    } else if key_matches(key_event, &keybinds.switch_split) {
+        switch_split(state)
+    } else {
+        return false;
+    };
+
+    match result {
+        Ok(()) => state.focus = Pane::Editor,
+        Err(message) => state.set_status(format!("[ERROR {}]", message)),
+    }
+    true
+}
+
+/// Show the next (or previous) open file, wrapping around
+pub fn cycle(state: &mut AppState, forward: bool) -> Result<(), String> {
+    let count = state.editor.buffer_count();
+    if count < 2 {
+        return Err("No other buffer".to_string());
+    }
+    let active = state.editor.active;
+    let index = if forward {
+        (active + 1) % count
+    } else {
+        (active + count - 1) % count
+    };
+    state.switch_buffer(index);
+    Ok(())
+}
+
+/// Edit the other side of the split
+pub fn switch_split(state: &mut AppState) -> Result<(), String> {
+    let other = state
+        .editor
+        .split_buffer()
+        .map(|buffer| buffer.name.clone())
+        .ok_or_else(|| "No split open".to_string())?;
+    let index = state
+        .editor
+        .buffer_index(&other)
+        .ok_or_else(|| "No split open".to_string())?;
+    state.switch_buffer(index);
+    Ok(())
+}
+
+/// Position of the buffer named by `:b`/`:bd`: a 1-based number, a file name,
+/// or a unique part of one
+pub fn find(state: &AppState, arg: &str) -> Result<usize, String> {
+    let count = state.editor.buffer_count();
+    if let Ok(number) = arg.parse::<usize>() {
+        return (1..=count)
+            .contains(&number)
+            .then(|| number - 1)
+            .ok_or_else(|| format!("Buffer {} does not exist", number));
+    }
+    let buffers = state.open_buffers();
+    if let Some(index) = buffers.iter().position(|(name, _)| *name == arg) {
+        return Ok(index);
+    }
+    let matching: Vec<usize> = (0..buffers.len())
+        .filter(|&i| buffers[i].0.contains(arg))
+        .collect();
+    match matching.as_slice() {
+        [index] => Ok(*index),
+        [] => Err(format!("No matching buffer for {}", arg)),
+        _ => Err(format!("More than one match for {}", arg)),
+    }
+}
+
+/// Open files for `:ls` (`%` marks the current one, `+` unsaved changes)
+pub fn list(state: &AppState) -> String {
+    let active = state.editor.active;
+    state
+        .open_buffers()
+        .iter()
+        .enumerate()
+        .map(|(i, (name, dirty))| {
+            let current = if i == active { "%" } else { "" };
+            let dirty = if *dirty { " [+]" } else { "" };
+            format!("{} {}{}{}", i + 1, current, name, dirty)
+        })
+        .collect::<Vec<_>>()
+        .join("  ")
+}
@@ -0,0 +1,128 @@
+use crate::{
+    api,
+    state::{
+        AppState, ApplyStage, ComposeApplyState, Pane, VimMode, follow_job_output, refresh,
+        status_helper,
+    },
+    utils,
+};
+use ratzilla::event::{KeyCode, KeyEvent};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Close the apply panel with Esc (editor normal mode, nothing in flight)
+pub fn handle_keys(state: &mut AppState, key_event: &KeyEvent) -> bool {
+    let closable = state
+        .compose_apply
+        .as_ref()
+        .is_some_and(|apply| !apply.is_busy());
+
+    if closable
+        && key_event.code == KeyCode::Esc
+        && state.focus == Pane::Editor
+        && state.vim_mode == VimMode::Normal
+    {
+        state.compose_apply = None;
+        return true;
+    }
+    false
+}
+
+/// Apply key: first press validates and shows the plan, second press runs `compose up`
+pub fn apply(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let Some(project) = state.current_compose_project() else {
+        state.set_status("Not a compose file");
+        return;
+    };
+
+    if state.dirty {
+        state.set_status("Save the file before applying");
+        return;
+    }
+
+    match &state.compose_apply {
+        Some(apply) if apply.is_busy() => {
+            state.set_status("Apply already in progress");
+        }
+        Some(apply) if apply.project == project && apply.stage == ApplyStage::Review => {
+            start_apply(state, state_rc, project);
+        }
+        _ => start_plan(state, state_rc, project),
+    }
+}
+
+fn start_plan(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, project: String) {
+    state.compose_apply = Some(ComposeApplyState::new(project.clone()));
+    state.set_status(format!("Validating compose files of {}...", project));
+
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let result = api::fetch_apply_plan(&project).await;
+        let apply_key = state_clone.borrow().keybinds.global.apply_compose.clone();
+        let mut st = state_clone.borrow_mut();
+        let Some(apply) = st.compose_apply.as_mut() else {
+            return;
+        };
+
+        let status = match result {
+            Ok(plan) if plan.valid => {
+                apply.stage = ApplyStage::Review;
+                apply.services = plan.services;
+                apply.push_log("compose config: OK");
+                format!("Review changes, {} to run compose up", apply_key)
+            }
+            Ok(plan) => {
+                apply.stage = ApplyStage::Invalid;
+                for line in plan.error.unwrap_or_default().lines() {
+                    apply.push_log(line);
+                }
+                "[ERROR compose config failed]".to_string()
+            }
+            Err(e) => {
+                apply.stage = ApplyStage::Invalid;
+                let error = utils::error::format_error(&e);
+                apply.push_log(error.clone());
+                format!("[ERROR planning apply: {}]", error)
+            }
+        };
+        st.set_status(status);
+    });
+}
+
+fn start_apply(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, project: String) {
+    if let Some(apply) = state.compose_apply.as_mut() {
+        apply.stage = ApplyStage::Running;
+        apply.log.clear();
+    }
+    state.set_status(format!("Running compose up for {}...", project));
+
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        // The job keeps running on the server if the page goes away
+        let result = match api::apply_project(&project).await {
+            Ok(job) => {
+                follow_job_output(&state_clone, job, |st, output| {
+                    if let Some(apply) = st.compose_apply.as_mut() {
+                        apply.log = output.to_vec();
+                    }
+                })
+                .await
+            }
+            Err(e) => Err(utils::error::format_error(&e)),
+        };
+
+        let (success, message) = match result {
+            Ok(_) => (true, format!("compose up finished for {}", project)),
+            Err(error) => (false, format!("[ERROR applying: {}]", error)),
+        };
+
+        if let Some(apply) = state_clone.borrow_mut().compose_apply.as_mut() {
+            apply.stage = ApplyStage::Done(success);
+            if !success {
+                apply.push_log(message.clone());
+            }
+        }
+        status_helper::set_status_timed(&state_clone, message);
+        refresh::refresh_pane(Pane::ContainerList, &state_clone);
+    });
+}
@@ -1,32 +1,14 @@
 use crate::{
     api,
-    state::{AppState, Pane, refresh, status_helper},
+    state::{AppState, run_job},
 };
 use std::{cell::RefCell, rc::Rc};
-use wasm_bindgen_futures::spawn_local;
 
 pub(super) fn start_container(state: &AppState, state_rc: &Rc<RefCell<AppState>>) {
     if let Some(container) = state.container_list._selected() {
         let container_id = container.id.clone();
-        let container_name = container.name.clone();
-        let state_clone = Rc::clone(state_rc);
-        spawn_local(async move {
-            match api::start_container(&container_id).await {
-                Ok(msg) => {
-                    status_helper::set_status_timed(
-                        &state_clone,
-                        format!("Started {}: {}", container_name, msg),
-                    );
-                    refresh::refresh_pane(Pane::ContainerList, &state_clone);
-                }
-                Err(e) => {
-                    status_helper::set_status_timed(
-                        &state_clone,
-                        format!("Failed to start {}: {:?}", container_name, e),
-                    );
-                    refresh::refresh_pane(Pane::ContainerList, &state_clone);
-                }
-            }
+        run_job(state_rc, format!("start {}", container.name), async move {
+            api::start_container(&container_id).await
         });
     }
 }
@@ -34,25 +16,8 @@ pub(super) fn start_container(state: &AppState, state_rc: &Rc<RefCell<AppState>>
 pub(super) fn stop_container(state: &AppState, state_rc: &Rc<RefCell<AppState>>) {
     if let Some(container) = state.container_list._selected() {
         let container_id = container.id.clone();
-        let container_name = container.name.clone();
-        let state_clone = Rc::clone(state_rc);
-        spawn_local(async move {
-            match api::stop_container(&container_id).await {
-                Ok(msg) => {
-                    status_helper::set_status_timed(
-                        &state_clone,
-                        format!("Stopped {}: {}", container_name, msg),
-                    );
-                    refresh::refresh_pane(Pane::ContainerList, &state_clone);
-                }
-                Err(e) => {
-                    status_helper::set_status_timed(
-                        &state_clone,
-                        format!("Failed to stop {}: {:?}", container_name, e),
-                    );
-                    refresh::refresh_pane(Pane::ContainerList, &state_clone);
-                }
-            }
+        run_job(state_rc, format!("stop {}", container.name), async move {
+            api::stop_container(&container_id).await
         });
     }
 }
@@ -60,25 +25,10 @@ pub(super) fn stop_container(state: &AppState, state_rc: &Rc<RefCell<AppState>>)
 pub(super) fn restart_container(state: &AppState, state_rc: &Rc<RefCell<AppState>>) {
     if let Some(container) = state.container_list._selected() {
         let container_id = container.id.clone();
-        let container_name = container.name.clone();
-        let state_clone = Rc::clone(state_rc);
-        spawn_local(async move {
-            match api::restart_container(&container_id).await {
-                Ok(msg) => {
-                    status_helper::set_status_timed(
-                        &state_clone,
-                        format!("Restarted {}: {}", container_name, msg),
-                    );
-                    refresh::refresh_pane(Pane::ContainerList, &state_clone);
-                }
-                Err(e) => {
-                    status_helper::set_status_timed(
-                        &state_clone,
-                        format!("Failed to restart {}: {:?}", container_name, e),
-                    );
-                    refresh::refresh_pane(Pane::ContainerList, &state_clone);
-                }
-            }
-        });
+        run_job(
+            state_rc,
+            format!("restart {}", container.name),
+            async move { api::restart_container(&container_id).await },
+        );
     }
 }
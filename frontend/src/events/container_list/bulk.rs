@@ -0,0 +1,118 @@
+use crate::{
+    api::{self, JobInfo},
+    state::{AppState, Pane, follow_job, refresh, status_helper},
+    utils,
+};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+
+/// Action that can run across all marked containers
+#[derive(Clone, Copy)]
+pub(super) enum BulkAction {
+    Start,
+    Stop,
+    Restart,
+}
+
+impl BulkAction {
+    fn label(self) -> &'static str {
+        match self {
+            BulkAction::Start => "start",
+            BulkAction::Stop => "stop",
+            BulkAction::Restart => "restart",
+        }
+    }
+
+    async fn run(self, container_id: &str) -> Result<JobInfo, JsValue> {
+        match self {
+            BulkAction::Start => api::start_container(container_id).await,
+            BulkAction::Stop => api::stop_container(container_id).await,
+            BulkAction::Restart => api::restart_container(container_id).await,
+        }
+    }
+}
+
+pub(super) fn toggle_mark(state: &mut AppState) {
+    state.container_list.toggle_mark();
+}
+
+pub(super) fn toggle_mark_visible(state: &mut AppState) {
+    state.container_list.toggle_mark_visible();
+}
+
+pub(super) fn mark_same_state(state: &mut AppState) {
+    match state.container_list.mark_same_state() {
+        Some(container_state) => {
+            let count = state.container_list.marked.len();
+            state.set_status(format!("Marked {} containers ({})", container_state, count));
+        }
+        None => state.set_status("Select a container to mark by state"),
+    }
+}
+
+pub(super) fn clear_marks(state: &mut AppState) {
+    state.container_list.marked.clear();
+}
+
+/// Run an action on every marked container and report which ones failed
+pub(super) fn run(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, action: BulkAction) {
+    let targets: Vec<(String, String)> = state
+        .container_list
+        .marked_containers()
+        .into_iter()
+        .map(|c| (c.id.clone(), c.name.clone()))
+        .collect();
+
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let total = targets.len();
+        let mut failed = Vec::new();
+
+        // Start all jobs first so they run side by side on the server
+        let mut started = Vec::new();
+        for (id, name) in targets {
+            match action.run(&id).await {
+                Ok(job) => {
+                    state_clone.borrow_mut().jobs.update(job.clone());
+                    started.push((name, job));
+                }
+                Err(e) => failed.push(format!("{} ({})", name, utils::error::format_error(&e))),
+            }
+        }
+
+        let mut succeeded = Vec::new();
+        for (name, job) in started {
+            match follow_job(&state_clone, job).await {
+                Ok(_) => succeeded.push(name),
+                Err(error) => failed.push(format!("{} ({})", name, error)),
+            }
+        }
+
+        let message = if failed.is_empty() {
+            format!(
+                "{}: {}/{} succeeded ({})",
+                action.label(),
+                succeeded.len(),
+                total,
+                succeeded.join(", ")
+            )
+        } else {
+            let ok = if succeeded.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", succeeded.join(", "))
+            };
+            format!(
+                "[ERROR {}: {}/{} succeeded{}; failed: {}]",
+                action.label(),
+                succeeded.len(),
+                total,
+                ok,
+                failed.join("; ")
+            )
+        };
+        status_helper::set_status_timed(&state_clone, message);
+        refresh::refresh_pane(Pane::ContainerList, &state_clone);
+    });
+}
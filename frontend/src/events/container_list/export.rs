@@ -0,0 +1,55 @@
+use crate::{
+    api,
+    state::{AppState, Pane, VimMode, status_helper},
+    utils,
+};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Open a compose service generated from the selected container in the
+/// editor as a new, unsaved file
+pub(super) fn open_compose_snippet(state: &AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let Some(container) = state.container_list._selected() else {
+        return;
+    };
+    let container_id = container.id.clone();
+
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let snippet = match api::fetch_compose_snippet(&container_id).await {
+            Ok(snippet) => snippet,
+            Err(e) => {
+                status_helper::set_status_timed(
+                    &state_clone,
+                    format!(
+                        "[ERROR generating compose file: {}]",
+                        utils::error::format_error(&e)
+                    ),
+                );
+                return;
+            }
+        };
+
+        let mut st = state_clone.borrow_mut();
+        let message = match &snippet.suggested_name {
+            Some(name) => format!(
+                "Compose service {}: save to create {}",
+                snippet.service, name
+            ),
+            None => format!(
+                "Compose service {}: no writable directory accepts .yml files",
+                snippet.service
+            ),
+        };
+        let filename = snippet
+            .suggested_name
+            .unwrap_or_else(|| format!("{}.compose.yml", snippet.service));
+
+        st.add_buffer(&filename);
+        st.editor.load_new(filename, snippet.content);
+        st.dirty = true;
+        st.focus = Pane::Editor;
+        st.vim_mode = VimMode::Normal;
+        st.set_status(message);
+    });
+}
@@ -0,0 +1,106 @@
+use crate::{
+    api,
+    state::{AppState, ConfirmKind, PendingAction, run_job},
+};
+use ratzilla::event::{KeyCode, KeyEvent};
+use std::{cell::RefCell, rc::Rc};
+
+/// Pause a running container or unpause a paused one
+pub(super) fn toggle_pause(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let Some(container) = state.container_list._selected() else {
+        return;
+    };
+    let id = container.id.clone();
+    let name = container.name.clone();
+
+    if container.state == "paused" {
+        run_job(state_rc, format!("unpause {}", name), async move {
+            api::unpause_container(&id).await
+        });
+    } else {
+        run_job(state_rc, format!("pause {}", name), async move {
+            api::pause_container(&id).await
+        });
+    }
+}
+
+/// Ask for confirmation before a destructive action on the selected container
+pub(super) fn request(state: &mut AppState, kind: ConfirmKind) {
+    let Some(container) = state.container_list._selected() else {
+        return;
+    };
+
+    // The compose file is what a container is recreated from
+    if kind == ConfirmKind::Recreate && container.service.is_none() {
+        state.set_status(format!(
+            "{} is not a compose service: only compose services can be recreated",
+            container.name
+        ));
+        return;
+    }
+
+    state.container_list.pending = Some(PendingAction {
+        container_id: container.id.clone(),
+        container_name: container.name.clone(),
+        running: container.state == "running",
+        kind,
+    });
+}
+
+/// Handle keys while a confirmation is pending (consumes all keys)
+pub(super) fn handle_pending(
+    state: &mut AppState,
+    state_rc: &Rc<RefCell<AppState>>,
+    key_event: &KeyEvent,
+) -> bool {
+    let Some(pending) = state.container_list.pending.as_mut() else {
+        return false;
+    };
+
+    match key_event.code {
+        KeyCode::Char('y') | KeyCode::Enter => {
+            if let Some(pending) = state.container_list.pending.take() {
+                confirm(state_rc, pending);
+            }
+        }
+        KeyCode::Char('n') | KeyCode::Esc => {
+            state.container_list.pending = None;
+            state.set_status("Cancelled");
+        }
+        KeyCode::Left | KeyCode::Char('h') => pending.cycle_signal(false),
+        KeyCode::Right | KeyCode::Char('l') => pending.cycle_signal(true),
+        KeyCode::Char('v') => pending.toggle_volumes(),
+        _ => {}
+    }
+    true
+}
+
+fn confirm(state_rc: &Rc<RefCell<AppState>>, pending: PendingAction) {
+    let id = pending.container_id.clone();
+    let name = pending.container_name.clone();
+
+    match pending.kind {
+        ConfirmKind::Kill { .. } => {
+            let signal = pending.signal();
+            run_job(state_rc, format!("kill {}", name), async move {
+                api::kill_container(&id, signal).await
+            });
+        }
+        ConfirmKind::Remove { volumes } => {
+            let force = pending.running;
+            run_job(state_rc, format!("remove {}", name), async move {
+                api::remove_container(&id, volumes, force).await
+            });
+        }
+        ConfirmKind::Recreate => {
+            run_job(state_rc, format!("recreate {}", name), async move {
+                api::recreate_container(&id).await
+            });
+        }
+        ConfirmKind::ComposeDown => {
+            run_job(state_rc, format!("compose down {}", name), async move {
+                api::compose_down(&name).await
+            });
+        }
+    }
+}
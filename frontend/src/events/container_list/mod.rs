@@ -1,13 +1,42 @@
 mod actions;
+mod bulk;
 mod details;
+mod export;
+mod lifecycle;
 mod navigation;
+mod projects;
+mod settings;
 
-use crate::state::{AppState, Pane};
+use crate::state::{AppState, ConfirmKind, ContainerRow, Pane};
+use bulk::BulkAction;
+use projects::ProjectAction;
 use ratzilla::event::KeyEvent;
 use std::{cell::RefCell, rc::Rc};
 
+/// Lines scrolled per PageUp/PageDown in the details pane
+const DETAILS_SCROLL_STEP: u16 = 10;
+
 pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    // A pending confirmation takes all keys
+    if lifecycle::handle_pending(state, state_rc, &key_event) {
+        return;
+    }
+    // So do the settings form and the filter query input
+    if settings::handle_form(state, state_rc, &key_event) {
+        return;
+    }
+    if navigation::handle_filter_input(state, &key_event) {
+        return;
+    }
+
     let keybinds = &state.keybinds.container_list;
+    // Start/stop/restart act on marked containers if there are any, otherwise
+    // on the whole stack when a project header is selected
+    let bulk = !state.container_list.marked.is_empty();
+    let on_project = matches!(
+        state.container_list.selected_row(),
+        Some(ContainerRow::Project(_))
+    );
 
     if super::key_matches(&key_event, &keybinds.navigate_down)
         || super::key_matches(&key_event, &keybinds.navigate_down_alt)
@@ -17,18 +46,85 @@ pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_e
         || super::key_matches(&key_event, &keybinds.navigate_up_alt)
     {
         navigation::previous(state);
+    } else if super::key_matches(&key_event, &keybinds.filter) {
+        navigation::start_filter(state);
+    } else if super::key_matches(&key_event, &keybinds.cycle_sort) {
+        navigation::cycle_sort(state);
+    } else if super::key_matches(&key_event, &keybinds.toggle_running_only) {
+        navigation::toggle_running_only(state);
+    } else if super::key_matches(&key_event, &keybinds.toggle_group) {
+        navigation::toggle_group(state);
     } else if super::key_matches(&key_event, &keybinds.start_container) {
-        actions::start_container(state, state_rc);
+        if bulk {
+            bulk::run(state, state_rc, BulkAction::Start);
+        } else if on_project {
+            projects::run_project_action(state, state_rc, ProjectAction::Start);
+        } else {
+            actions::start_container(state, state_rc);
+        }
     } else if super::key_matches(&key_event, &keybinds.stop_container) {
-        actions::stop_container(state, state_rc);
+        if bulk {
+            bulk::run(state, state_rc, BulkAction::Stop);
+        } else if on_project {
+            projects::run_project_action(state, state_rc, ProjectAction::Stop);
+        } else {
+            actions::stop_container(state, state_rc);
+        }
     } else if super::key_matches(&key_event, &keybinds.restart_container) {
-        actions::restart_container(state, state_rc);
+        if bulk {
+            bulk::run(state, state_rc, BulkAction::Restart);
+        } else if on_project {
+            projects::run_project_action(state, state_rc, ProjectAction::Restart);
+        } else {
+            actions::restart_container(state, state_rc);
+        }
+    } else if super::key_matches(&key_event, &keybinds.toggle_mark) {
+        bulk::toggle_mark(state);
+    } else if super::key_matches(&key_event, &keybinds.mark_all) {
+        bulk::toggle_mark_visible(state);
+    } else if super::key_matches(&key_event, &keybinds.mark_same_state) {
+        bulk::mark_same_state(state);
+    } else if super::key_matches(&key_event, &keybinds.clear_marks) {
+        bulk::clear_marks(state);
+    } else if super::key_matches(&key_event, &keybinds.pause_container) {
+        lifecycle::toggle_pause(state, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.kill_container) {
+        lifecycle::request(state, ConfirmKind::Kill { signal: 0 });
+    } else if super::key_matches(&key_event, &keybinds.remove_container) {
+        lifecycle::request(state, ConfirmKind::Remove { volumes: false });
+    } else if super::key_matches(&key_event, &keybinds.recreate_container) {
+        lifecycle::request(state, ConfirmKind::Recreate);
+    } else if super::key_matches(&key_event, &keybinds.edit_settings) {
+        settings::open(state);
+    } else if super::key_matches(&key_event, &keybinds.export_compose) {
+        export::open_compose_snippet(state, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.exec_shell) {
+        super::terminal::open(state, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.toggle_raw_inspect) {
+        let list = &mut state.container_list;
+        list.raw_inspect = !list.raw_inspect;
+        list.details_scroll = 0;
+    } else if super::key_matches(&key_event, &keybinds.scroll_details_down) {
+        let list = &mut state.container_list;
+        list.details_scroll = list.details_scroll.saturating_add(DETAILS_SCROLL_STEP);
+    } else if super::key_matches(&key_event, &keybinds.scroll_details_up) {
+        let list = &mut state.container_list;
+        list.details_scroll = list.details_scroll.saturating_sub(DETAILS_SCROLL_STEP);
+    } else if super::key_matches(&key_event, &keybinds.compose_up) {
+        projects::run_project_action(state, state_rc, ProjectAction::Up);
+    } else if super::key_matches(&key_event, &keybinds.compose_down) {
+        projects::run_project_action(state, state_rc, ProjectAction::Down);
     } else if super::key_matches(&key_event, &keybinds.back_to_menu) {
         state.focus = Pane::Menu;
     } else {
         // Enter to view details (not configurable for now)
+        // On a project header Enter collapses/expands the group instead
         if super::match_key_without_mods(&key_event, "Enter") {
-            details::load_details(state, state_rc);
+            if on_project {
+                navigation::toggle_group(state);
+            } else {
+                details::load_details(state, state_rc);
+            }
         }
     }
 }
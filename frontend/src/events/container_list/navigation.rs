@@ -1,4 +1,5 @@
 use crate::state::{AppState, Pane, refresh};
+use ratzilla::event::{KeyCode, KeyEvent};
 
 pub(super) fn next(state: &mut AppState) {
     state.container_list.next();
@@ -9,3 +10,61 @@ pub(super) fn previous(state: &mut AppState) {
     state.container_list.previous();
     refresh::save_selection(Pane::ContainerList, state);
 }
+
+pub(super) fn toggle_group(state: &mut AppState) {
+    state.container_list.toggle_group();
+    crate::storage::generic::save("container-list-collapsed", &state.container_list.collapsed);
+    refresh::save_selection(Pane::ContainerList, state);
+}
+
+/// Start typing a filter query
+pub(super) fn start_filter(state: &mut AppState) {
+    state.container_list.filter_input = true;
+}
+
+/// Handle keys while the filter query is being typed (consumes all keys)
+pub(super) fn handle_filter_input(state: &mut AppState, key_event: &KeyEvent) -> bool {
+    if !state.container_list.filter_input {
+        return false;
+    }
+
+    let list = &mut state.container_list;
+    match key_event.code {
+        KeyCode::Enter => list.filter_input = false,
+        KeyCode::Esc => {
+            list.filter_input = false;
+            list.view.query.clear();
+        }
+        KeyCode::Backspace => {
+            list.view.query.pop();
+        }
+        KeyCode::Char(c) if !key_event.ctrl && !key_event.alt => list.view.query.push(c),
+        _ => return true,
+    }
+
+    // Jump to the first match while typing
+    list.selected_index = 0;
+    list.clamp_selection();
+    save_view(state);
+    true
+}
+
+pub(super) fn cycle_sort(state: &mut AppState) {
+    let view = &mut state.container_list.view;
+    view.sort = view.sort.next();
+    let label = view.sort.label();
+    state.set_status(format!("Sorted by {}", label));
+    save_view(state);
+}
+
+pub(super) fn toggle_running_only(state: &mut AppState) {
+    let list = &mut state.container_list;
+    list.view.running_only = !list.view.running_only;
+    list.clamp_selection();
+    save_view(state);
+}
+
+fn save_view(state: &AppState) {
+    crate::storage::generic::save("container-list-view", &state.container_list.view);
+    refresh::save_selection(Pane::ContainerList, state);
+}
@@ -0,0 +1,76 @@
+use crate::{
+    api::{self, JobInfo},
+    state::{AppState, ConfirmKind, PendingAction, run_job},
+};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen::JsValue;
+
+/// Stack-level action on a compose project
+#[derive(Clone, Copy)]
+pub(super) enum ProjectAction {
+    Start,
+    Stop,
+    Restart,
+    Up,
+    Down,
+}
+
+impl ProjectAction {
+    fn label(self) -> &'static str {
+        match self {
+            ProjectAction::Start => "start",
+            ProjectAction::Stop => "stop",
+            ProjectAction::Restart => "restart",
+            ProjectAction::Up => "compose up",
+            ProjectAction::Down => "compose down",
+        }
+    }
+
+    async fn run(self, project: &str) -> Result<JobInfo, JsValue> {
+        match self {
+            ProjectAction::Start => api::start_project(project).await,
+            ProjectAction::Stop => api::stop_project(project).await,
+            ProjectAction::Restart => api::restart_project(project).await,
+            ProjectAction::Up => api::compose_up(project).await,
+            ProjectAction::Down => api::compose_down(project).await,
+        }
+    }
+}
+
+/// Run a stack action on the project of the selected row
+///
+/// `compose down` removes the containers, so it asks for confirmation first.
+pub(super) fn run_project_action(
+    state: &mut AppState,
+    state_rc: &Rc<RefCell<AppState>>,
+    action: ProjectAction,
+) {
+    let Some(project) = state.container_list.selected_project() else {
+        state.set_status("Not part of a compose project");
+        return;
+    };
+
+    if matches!(action, ProjectAction::Up | ProjectAction::Down) && project.config_files.is_empty()
+    {
+        state.set_status(format!("No compose file known for {}", project.name));
+        return;
+    }
+
+    let project_name = project.name.clone();
+    if matches!(action, ProjectAction::Down) {
+        state.container_list.pending = Some(PendingAction {
+            container_id: String::new(),
+            container_name: project_name,
+            running: false,
+            kind: ConfirmKind::ComposeDown,
+        });
+        return;
+    }
+
+    let label = format!("{} {}", action.label(), project_name);
+    run_job(
+        state_rc,
+        label,
+        async move { action.run(&project_name).await },
+    );
+}
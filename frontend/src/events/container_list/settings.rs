@@ -0,0 +1,95 @@
+use super::details;
+use crate::{
+    api,
+    state::{AppState, Pane, SettingsForm, follow_job, refresh, status_helper},
+    utils,
+};
+use ratzilla::event::{KeyCode, KeyEvent};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Open the settings form for the container shown in the details pane
+pub(super) fn open(state: &mut AppState) {
+    let Some(selected) = state.container_list._selected() else {
+        return;
+    };
+    let form = match &state.container_details {
+        Some(details) if details.id.starts_with(&selected.id) => {
+            SettingsForm::from_details(details)
+        }
+        _ => {
+            state.set_status("Open the container details (Enter) before editing settings");
+            return;
+        }
+    };
+    state.container_list.settings_form = Some(form);
+}
+
+/// Handle keys while the settings form is open (consumes all keys)
+pub(super) fn handle_form(
+    state: &mut AppState,
+    state_rc: &Rc<RefCell<AppState>>,
+    key_event: &KeyEvent,
+) -> bool {
+    let Some(form) = state.container_list.settings_form.as_mut() else {
+        return false;
+    };
+    if form.submitting {
+        return true;
+    }
+
+    match key_event.code {
+        KeyCode::Esc => {
+            state.container_list.settings_form = None;
+            state.set_status("Cancelled");
+        }
+        KeyCode::Enter => submit(state_rc, form),
+        KeyCode::Tab | KeyCode::Down => form.next_field(),
+        KeyCode::Up => form.previous_field(),
+        KeyCode::Left => form.cycle_policy(false),
+        KeyCode::Right => form.cycle_policy(true),
+        KeyCode::Backspace => form.pop_char(),
+        KeyCode::Char(c) if !key_event.ctrl && !key_event.alt => form.push_char(c),
+        _ => {}
+    }
+    true
+}
+
+/// Send the changed settings; the form stays open if the server rejects them
+fn submit(state_rc: &Rc<RefCell<AppState>>, form: &mut SettingsForm) {
+    let Some(request) = form.request() else {
+        form.error = Some("No changes".to_string());
+        return;
+    };
+    form.submitting = true;
+
+    let container_id = form.container_id.clone();
+    let label = format!("update {}", form.container_name);
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let job = match api::update_container(&container_id, &request).await {
+            Ok(job) => job,
+            Err(e) => {
+                if let Some(form) = state_clone
+                    .borrow_mut()
+                    .container_list
+                    .settings_form
+                    .as_mut()
+                {
+                    form.submitting = false;
+                    form.error = Some(utils::error::format_error(&e));
+                }
+                return;
+            }
+        };
+        state_clone.borrow_mut().container_list.settings_form = None;
+
+        let status = match follow_job(&state_clone, job).await {
+            Ok(message) => format!("{}: {}", label, message),
+            Err(error) => format!("[ERROR {}: {}]", label, error),
+        };
+        status_helper::set_status_timed(&state_clone, status);
+        refresh::refresh_pane(Pane::ContainerList, &state_clone);
+        details::fetch_details(&state_clone, container_id);
+    });
+}
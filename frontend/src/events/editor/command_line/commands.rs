@@ -0,0 +1,293 @@
+use super::range::{self, Lines};
+use super::{set, substitute};
+use crate::{
+    api,
+    events::{buffers, file_list, leave_prompt, menu, save_review},
+    state::{
+        AppState, LeaveAction, Pane, file_list::CONTAINER_FILE_PREFIX, refresh, status_helper,
+    },
+    utils,
+};
+use std::{cell::RefCell, rc::Rc};
+use tui_textarea::CursorMove;
+use wasm_bindgen_futures::spawn_local;
+
+/// Command names and how many letters of each must be typed at least
+pub(super) const COMMANDS: &[(&str, usize)] = &[
+    ("bdelete", 2),
+    ("bnext", 2),
+    ("bprevious", 2),
+    ("buffer", 1),
+    ("buffers", 7),
+    ("edit", 1),
+    ("ls", 2),
+    ("nohlsearch", 3),
+    ("only", 2),
+    ("quit", 1),
+    ("set", 2),
+    ("substitute", 1),
+    ("vsplit", 2),
+    ("wq", 2),
+    ("write", 1),
+    ("xit", 1),
+];
+
+/// Full name of a (possibly abbreviated) command
+pub(super) fn resolve(name: &str) -> Option<&'static str> {
+    COMMANDS
+        .iter()
+        .find(|(full, min)| name.len() >= *min && full.starts_with(name))
+        .map(|(full, _)| *full)
+}
+
+/// Split a command into its name, `!` and the rest (after the range)
+pub(super) fn split(input: &str) -> (&str, bool, &str) {
+    let end = input
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(input.len());
+    let (name, rest) = input.split_at(end);
+    match rest.strip_prefix('!') {
+        Some(rest) => (name, true, rest.trim_start()),
+        None => (name, false, rest.trim_start()),
+    }
+}
+
+/// Run a command line, showing errors in the status line
+pub(super) fn execute(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, input: &str) {
+    if let Err(message) = run(state, state_rc, input) {
+        state.set_status(format!("[ERROR {}]", message));
+    }
+}
+
+fn run(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, input: &str) -> Result<(), String> {
+    let input = input.trim_start_matches(|c: char| c == ':' || c.is_whitespace());
+    if input.is_empty() {
+        return Ok(());
+    }
+
+    let textarea = &state.editor.textarea;
+    let lines = Lines {
+        cursor: textarea.cursor().0,
+        last: textarea.lines().len() - 1,
+        marks: state.editor.visual_marks,
+    };
+    let (range, rest) = range::parse(input, &lines)?;
+    let (name, bang, args) = split(rest.trim_start());
+
+    if name.is_empty() && rest.trim().is_empty() {
+        // A range alone goes to its last line
+        if let Some((_, end)) = range {
+            state
+                .editor
+                .textarea
+                .move_cursor(CursorMove::Jump(end as u16, 0));
+        }
+        return Ok(());
+    }
+
+    let command =
+        resolve(name).ok_or_else(|| format!("Not an editor command: {}", input.trim_end()))?;
+    if command == "substitute" {
+        let range = range.unwrap_or((lines.cursor, lines.cursor));
+        return substitute::run(state, range, rest.trim_start()[name.len()..].trim_start());
+    }
+    if range.is_some() {
+        return Err("No range allowed".to_string());
+    }
+
+    match command {
+        "write" => write(state, state_rc, args.trim(), bang, false),
+        "wq" | "xit" => write(state, state_rc, args.trim(), bang, true),
+        "quit" => quit(state, bang),
+        "edit" => edit(state, state_rc, args.trim(), bang),
+        "nohlsearch" => {
+            state.editor.highlight_search = false;
+            Ok(())
+        }
+        "bnext" => buffers::cycle(state, true),
+        "bprevious" => buffers::cycle(state, false),
+        "buffer" => buffer(state, args.trim()),
+        "bdelete" => bdelete(state, args.trim(), bang),
+        "buffers" | "ls" => {
+            let list = buffers::list(state);
+            state.set_status(list);
+            Ok(())
+        }
+        "vsplit" => vsplit(state, state_rc, args.trim()),
+        "only" => {
+            state.editor.split = None;
+            Ok(())
+        }
+        _ => set::run(state, args),
+    }
+}
+
+/// `:w [name]`; another name writes a copy and leaves the buffer as it is
+/// (`:w! name` to overwrite an existing file). A plain `:w` shows the diff
+/// review first when `reviewsave` is on
+fn write(
+    state: &mut AppState,
+    state_rc: &Rc<RefCell<AppState>>,
+    name: &str,
+    bang: bool,
+    then_quit: bool,
+) -> Result<(), String> {
+    let current = state
+        .editor
+        .current_file
+        .clone()
+        .ok_or_else(|| "No file name".to_string())?;
+    let content = state.editor.get_content();
+
+    if (name.is_empty() || name == current) && !then_quit {
+        save_review::save(state, state_rc);
+    } else if name.is_empty() || name == current {
+        menu::save_file(Rc::clone(state_rc), current, content);
+    } else {
+        let exists = name.starts_with(CONTAINER_FILE_PREFIX)
+            || state.file_list.files.iter().any(|f| f.name == name);
+        if exists && !bang {
+            return Err("File exists (add ! to override)".to_string());
+        }
+        write_copy(state_rc, name.to_string(), content, exists);
+    }
+
+    if then_quit {
+        state.focus = Pane::FileList;
+    }
+    Ok(())
+}
+
+fn write_copy(state_rc: &Rc<RefCell<AppState>>, name: String, content: String, exists: bool) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let result = if exists {
+            api::save_file_content(&name, content.clone()).await
+        } else {
+            api::create_file_content(&name, content.clone()).await
+        };
+        match result {
+            Ok(_) => {
+                // An open buffer of the file now differs from what is saved
+                state_clone.borrow_mut().mark_saved(&name, content);
+                if !exists {
+                    refresh::refresh_pane(Pane::FileList, &state_clone);
+                }
+                status_helper::set_status_timed(&state_clone, format!("Written: {}", name));
+            }
+            Err(e) => {
+                status_helper::set_status_timed(
+                    &state_clone,
+                    format!(
+                        "[ERROR writing {}: {}]",
+                        name,
+                        utils::error::format_error(&e)
+                    ),
+                );
+            }
+        }
+    });
+}
+
+/// `:q` returns to the file list (asking about unsaved changes first); `:q!`
+/// also closes the buffer, throwing them away
+fn quit(state: &mut AppState, bang: bool) -> Result<(), String> {
+    if state.dirty {
+        if !bang {
+            leave_prompt::ask(state, LeaveAction::Quit);
+            return Ok(());
+        }
+        state.close_buffer();
+    }
+    state.focus = Pane::FileList;
+    Ok(())
+}
+
+/// `:e name` opens another file (or switches to its buffer), `:e` reloads
+/// the current one
+fn edit(
+    state: &mut AppState,
+    state_rc: &Rc<RefCell<AppState>>,
+    name: &str,
+    bang: bool,
+) -> Result<(), String> {
+    if name.is_empty() {
+        let current = match &state.editor.current_file {
+            Some(current) if !state.editor.new_file => current.clone(),
+            _ => return Err("No file name".to_string()),
+        };
+        if state.dirty && !bang {
+            leave_prompt::ask(state, LeaveAction::Reload);
+        } else {
+            file_list::load_file(state_rc, current);
+        }
+        return Ok(());
+    }
+
+    let known = name.starts_with(CONTAINER_FILE_PREFIX)
+        || state.file_list.files.iter().any(|f| f.name == name)
+        || state.editor.buffer_index(name).is_some();
+    if !known {
+        return Err(format!("No such file: {}", name));
+    }
+    file_list::open_file(state, state_rc, name.to_string());
+    Ok(())
+}
+
+/// `:b N` or `:b name` switches to an open file
+fn buffer(state: &mut AppState, arg: &str) -> Result<(), String> {
+    if arg.is_empty() {
+        return Ok(());
+    }
+    let index = buffers::find(state, arg)?;
+    state.switch_buffer(index);
+    Ok(())
+}
+
+/// `:bd [N|name]` closes a buffer, asking about unsaved changes first (`!`
+/// throws them away)
+fn bdelete(state: &mut AppState, arg: &str, bang: bool) -> Result<(), String> {
+    let index = if arg.is_empty() {
+        state.editor.active
+    } else {
+        buffers::find(state, arg)?
+    };
+    state.switch_buffer(index);
+    if state.dirty && !bang {
+        leave_prompt::ask(state, LeaveAction::Close);
+    } else {
+        state.close_buffer();
+    }
+    Ok(())
+}
+
+/// `:vs name` edits a file next to the current one, `:vs` shows the next
+/// open file next to it
+fn vsplit(
+    state: &mut AppState,
+    state_rc: &Rc<RefCell<AppState>>,
+    name: &str,
+) -> Result<(), String> {
+    let current = state
+        .editor
+        .current_file
+        .clone()
+        .ok_or_else(|| "No file name".to_string())?;
+    if name.is_empty() {
+        let count = state.editor.buffer_count();
+        if count < 2 {
+            return Err("No other buffer to split with".to_string());
+        }
+        let next = state.editor.buffer_at((state.editor.active + 1) % count);
+        state.editor.split = next.map(|buffer| buffer.name.clone());
+        return Ok(());
+    }
+    if name == current {
+        return Err(format!("{} is already shown", name));
+    }
+
+    // The current file moves to the other side once `name` is shown
+    edit(state, state_rc, name, false)?;
+    state.editor.split = Some(current);
+    Ok(())
+}
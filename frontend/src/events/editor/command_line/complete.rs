@@ -0,0 +1,73 @@
+use super::commands::{self, COMMANDS};
+use super::set::OPTIONS;
+use crate::state::{AppState, Completion};
+
+/// Tab completes command names, file names after `:e`/`:w`/`:vs`, open
+/// files after `:b`/`:bd` and option names after `:set`; pressing it again
+/// cycles through the candidates
+pub(super) fn complete(state: &mut AppState, backwards: bool) {
+    let command_line = &mut state.command_line;
+    if let Some(completion) = &mut command_line.completion {
+        let count = completion.candidates.len();
+        completion.index = if backwards {
+            (completion.index + count - 1) % count
+        } else {
+            (completion.index + 1) % count
+        };
+        command_line.apply_completion();
+        return;
+    }
+
+    let input = &command_line.input;
+    // Skip the range in front of the command
+    let name_start = input
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(input.len());
+    let (name, _, args) = commands::split(&input[name_start..]);
+    let args_start = input.len() - args.len();
+
+    let (start, candidates): (usize, Vec<String>) = if args_start == name_start + name.len() {
+        // Still typing the command name
+        let names = COMMANDS.iter().map(|(full, _)| full.to_string());
+        (name_start, matching(names, name))
+    } else {
+        match commands::resolve(name) {
+            Some("edit" | "write" | "vsplit") => {
+                let files = state.file_list.files.iter().map(|f| f.name.clone());
+                (args_start, matching(files, args))
+            }
+            Some("buffer" | "bdelete") => {
+                let editor = &state.editor;
+                let names = editor.buffers.iter().map(|b| b.name.clone());
+                let names = names.chain(editor.current_file.clone());
+                (args_start, matching(names, args))
+            }
+            Some("set") => {
+                // Complete the last option
+                let word_start = args.rfind(' ').map_or(0, |i| i + 1);
+                let names = OPTIONS.iter().map(|(full, _)| full.to_string());
+                (
+                    args_start + word_start,
+                    matching(names, &args[word_start..]),
+                )
+            }
+            _ => return,
+        }
+    };
+
+    if !candidates.is_empty() {
+        command_line.completion = Some(Completion {
+            candidates,
+            index: 0,
+            start,
+        });
+        command_line.apply_completion();
+    }
+}
+
+fn matching(candidates: impl Iterator<Item = String>, prefix: &str) -> Vec<String> {
+    let mut matching: Vec<String> = candidates.filter(|c| c.starts_with(prefix)).collect();
+    matching.sort();
+    matching.dedup();
+    matching
+}
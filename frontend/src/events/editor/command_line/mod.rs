@@ -0,0 +1,55 @@
+mod commands;
+mod complete;
+mod range;
+mod set;
+mod substitute;
+
+use super::search;
+use crate::state::{AppState, VimMode};
+use ratzilla::event::{KeyCode, KeyEvent};
+use std::{cell::RefCell, rc::Rc};
+
+/// Open the command line (`:`) or search prompt (`/`, `?`) with `initial`
+/// already typed
+pub(super) fn open(state: &mut AppState, prompt: char, initial: &str) {
+    state.command_line.open(prompt, initial);
+    state.vim_mode = VimMode::Command;
+}
+
+/// Editing keys, Tab completion and Up/Down history; Enter runs the command
+/// or search
+pub(super) fn handle_command_line(
+    state: &mut AppState,
+    state_rc: &Rc<RefCell<AppState>>,
+    key_event: KeyEvent,
+) {
+    let command_line = &mut state.command_line;
+    match key_event.code {
+        KeyCode::Esc => state.vim_mode = VimMode::Normal,
+        KeyCode::Enter => {
+            let input = std::mem::take(&mut command_line.input);
+            command_line.push_history(&input);
+            state.vim_mode = VimMode::Normal;
+            match command_line.prompt {
+                ':' => commands::execute(state, state_rc, &input),
+                prompt => {
+                    let count = command_line.count.unwrap_or(1);
+                    search::run(state, &input, prompt == '/', count);
+                }
+            }
+        }
+        KeyCode::Tab if command_line.prompt == ':' => complete::complete(state, key_event.shift),
+        // Backspace on an empty line closes it
+        KeyCode::Backspace if command_line.input.is_empty() => state.vim_mode = VimMode::Normal,
+        KeyCode::Backspace => command_line.backspace(),
+        KeyCode::Delete => command_line.delete(),
+        KeyCode::Left => command_line.move_left(),
+        KeyCode::Right => command_line.move_right(),
+        KeyCode::Home => command_line.move_home(),
+        KeyCode::End => command_line.move_end(),
+        KeyCode::Up => command_line.history_previous(),
+        KeyCode::Down => command_line.history_next(),
+        KeyCode::Char(c) if !key_event.ctrl && !key_event.alt => command_line.insert(c),
+        _ => {}
+    }
+}
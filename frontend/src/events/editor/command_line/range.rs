@@ -0,0 +1,79 @@
+/// First and last line a command applies to (0-based, inclusive)
+pub(super) type LineRange = (usize, usize);
+
+/// What line addresses are resolved against
+pub(super) struct Lines {
+    pub cursor: usize,
+    pub last: usize,
+    /// Lines of the last visual selection (`'<` and `'>`)
+    pub marks: Option<(usize, usize)>,
+}
+
+/// Parse the range in front of a command: `%`, `N`, `.`, `$`, `'<`, `'>`,
+/// each with optional `+N`/`-N` offsets, and `a,b` pairs
+pub(super) fn parse<'a>(
+    input: &'a str,
+    lines: &Lines,
+) -> Result<(Option<LineRange>, &'a str), String> {
+    let input = input.trim_start();
+    if let Some(rest) = input.strip_prefix('%') {
+        return Ok((Some((0, lines.last)), rest));
+    }
+
+    let (first, rest) = address(input, lines)?;
+    let Some(first) = first else {
+        return Ok((None, rest));
+    };
+    let Some(rest) = rest.trim_start().strip_prefix([',', ';']) else {
+        return Ok((Some((first, first)), rest));
+    };
+    let (second, rest) = address(rest, lines)?;
+    let second = second.unwrap_or(lines.cursor);
+    Ok((Some((first.min(second), first.max(second))), rest))
+}
+
+/// One line address; None if the input doesn't start with one
+fn address<'a>(input: &'a str, lines: &Lines) -> Result<(Option<usize>, &'a str), String> {
+    let input = input.trim_start();
+    let marks = || lines.marks.ok_or_else(|| "Mark not set".to_string());
+
+    let (mut line, mut rest) = if let Some(rest) = input.strip_prefix('.') {
+        (Some(lines.cursor as i64), rest)
+    } else if let Some(rest) = input.strip_prefix('$') {
+        (Some(lines.last as i64), rest)
+    } else if let Some(rest) = input.strip_prefix("'<") {
+        (Some(marks()?.0 as i64), rest)
+    } else if let Some(rest) = input.strip_prefix("'>") {
+        (Some(marks()?.1 as i64), rest)
+    } else {
+        match number(input) {
+            // Line numbers are 1-based (0 means the first line too)
+            (Some(n), rest) => (Some((n - 1).max(0)), rest),
+            (None, rest) => (None, rest),
+        }
+    };
+
+    while let Some(sign @ ('+' | '-')) = rest.chars().next() {
+        let (n, after) = number(&rest[1..]);
+        let offset = n.unwrap_or(1);
+        let base = line.unwrap_or(lines.cursor as i64);
+        line = Some(if sign == '+' {
+            base + offset
+        } else {
+            base - offset
+        });
+        rest = after;
+    }
+
+    match line {
+        Some(line) if line < 0 || line > lines.last as i64 => Err("Invalid range".to_string()),
+        line => Ok((line.map(|l| l as usize), rest)),
+    }
+}
+
+fn number(input: &str) -> (Option<i64>, &str) {
+    let end = input
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len());
+    (input[..end].parse().ok(), &input[end..])
+}
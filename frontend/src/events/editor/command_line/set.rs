@@ -0,0 +1,107 @@
+use crate::state::{AppState, EditorOptions, OptionValues};
+
+/// Option names with their short forms
+pub(super) const OPTIONS: &[(&str, &str)] = &[
+    ("cursorline", "cul"),
+    ("expandtab", "et"),
+    ("ignorecase", "ic"),
+    ("list", "list"),
+    ("number", "nu"),
+    ("regex", "re"),
+    ("relativenumber", "rnu"),
+    ("reviewsave", "rs"),
+    ("tabstop", "ts"),
+    ("wrap", "wrap"),
+];
+
+const MAX_TAB_WIDTH: u8 = 16;
+
+/// `:set` shows all options; `:set name`, `noname`, `invname`/`name!`,
+/// `name=value` change them and `name?` shows one
+///
+/// Changes apply to all files and override the defaults from `editor.toml`.
+pub(super) fn run(state: &mut AppState, args: &str) -> Result<(), String> {
+    let editor = &mut state.editor;
+    let mut shown = Vec::new();
+
+    if args.trim().is_empty() {
+        shown.extend(
+            OPTIONS
+                .iter()
+                .map(|(name, _)| describe(&editor.options, name)),
+        );
+    }
+    for arg in args.split_whitespace() {
+        if let Some(text) = set_one(&editor.options, &mut editor.session, arg)? {
+            shown.push(text);
+        }
+        editor.update_options();
+    }
+
+    if !shown.is_empty() {
+        state.set_status(shown.join("  "));
+    }
+    Ok(())
+}
+
+fn resolve(name: &str) -> Result<&'static str, String> {
+    OPTIONS
+        .iter()
+        .find(|(full, short)| name == *full || name == *short)
+        .map(|(full, _)| *full)
+        .ok_or_else(|| format!("Unknown option: {}", name))
+}
+
+/// Apply one argument to the `:set` changes; returns text to show for queries
+fn set_one(
+    options: &EditorOptions,
+    session: &mut OptionValues,
+    arg: &str,
+) -> Result<Option<String>, String> {
+    if let Some((name, value)) = arg.split_once(['=', ':']) {
+        let invalid = || format!("Invalid argument: {}", arg);
+        if resolve(name)? != "tabstop" {
+            return Err(invalid());
+        }
+        let tab_width = value
+            .parse()
+            .ok()
+            .filter(|width| (1..=MAX_TAB_WIDTH).contains(width))
+            .ok_or_else(invalid)?;
+        session.tabstop = Some(tab_width);
+        return Ok(None);
+    }
+    if let Some(name) = arg.strip_suffix('?') {
+        return Ok(Some(describe(options, resolve(name)?)));
+    }
+
+    let (name, value) = if let Some(name) = arg.strip_suffix('!') {
+        (name, None)
+    } else if let Some(name) = arg.strip_prefix("inv") {
+        (name, None)
+    } else if let Some(name) = arg.strip_prefix("no").filter(|name| resolve(name).is_ok()) {
+        (name, Some(false))
+    } else {
+        (arg, Some(true))
+    };
+
+    let full = resolve(name)?;
+    match (options.flag(full), session.flag_mut(full)) {
+        (Some(current), Some(flag)) => *flag = Some(value.unwrap_or(!current)),
+        // A number option without a value is shown
+        _ if value == Some(true) => return Ok(Some(describe(options, full))),
+        _ => return Err(format!("Invalid argument: {}", arg)),
+    }
+    Ok(None)
+}
+
+fn describe(options: &EditorOptions, name: &str) -> String {
+    let Some(flag) = options.flag(name) else {
+        return format!("{}={}", name, options.tab_width);
+    };
+    if flag {
+        name.to_string()
+    } else {
+        format!("no{}", name)
+    }
+}
@@ -0,0 +1,196 @@
+use super::super::operations::jump;
+use super::super::search;
+use super::range::LineRange;
+use crate::state::AppState;
+use regex_lite::{Captures, RegexBuilder};
+
+/// Part of a replacement string
+enum Piece {
+    Text(String),
+    /// `&` or `\0` (the whole match) and `\1`-`\9`
+    Group(usize),
+}
+
+/// `:s/pattern/replacement/flags` on a range of lines
+///
+/// Patterns use Rust regex syntax and only match within a line. Flags are
+/// `g` (every match in a line), `i` (ignore case) and `I` (match case).
+/// An empty pattern reuses the previous search or substitution pattern.
+pub(super) fn run(state: &mut AppState, (start, end): LineRange, args: &str) -> Result<(), String> {
+    let mut chars = args.chars();
+    let delimiter = chars.next().ok_or_else(|| "Missing pattern".to_string())?;
+    if delimiter.is_alphanumeric() || delimiter.is_whitespace() || matches!(delimiter, '\\' | '"') {
+        return Err(format!("Invalid delimiter: {}", delimiter));
+    }
+    let (pattern, rest) = split_part(chars.as_str(), delimiter);
+    let (replacement, flags) = split_part(rest, delimiter);
+
+    let pattern = if pattern.is_empty() {
+        state
+            .editor
+            .last_pattern
+            .clone()
+            .ok_or_else(|| "No previous pattern".to_string())?
+    } else {
+        pattern
+    };
+    let mut global = false;
+    let mut ignore_case = state.editor.options.ignore_case;
+    for flag in flags.trim_end().chars() {
+        match flag {
+            'g' => global = true,
+            'i' => ignore_case = true,
+            'I' => ignore_case = false,
+            _ => return Err(format!("Invalid flag: {}", flag)),
+        }
+    }
+
+    let regex = RegexBuilder::new(&pattern)
+        .case_insensitive(ignore_case)
+        .build()
+        .map_err(|e| format!("Invalid pattern: {}", e))?;
+    // `n` and `N` search for the pattern afterwards
+    let forward = state.editor.search.as_ref().is_none_or(|s| s.forward);
+    search::set_search(
+        state,
+        pattern.clone(),
+        pattern.clone(),
+        regex.clone(),
+        forward,
+    );
+    let pieces = parse_replacement(&replacement);
+
+    let lines = state.editor.textarea.lines();
+    let mut replaced = Vec::with_capacity(end - start + 1);
+    let mut substitutions = 0;
+    let mut changed_lines = 0;
+    // Where the last changed line ends up (replacements may add lines)
+    let mut last_changed = None;
+    let mut row = start;
+    for line in &lines[start..=end] {
+        let mut count = 0;
+        let new = regex.replacen(line, if global { 0 } else { 1 }, |caps: &Captures| {
+            count += 1;
+            expand(&pieces, caps)
+        });
+        if count > 0 {
+            substitutions += count;
+            changed_lines += 1;
+            last_changed = Some(row);
+        }
+        row += new.matches('\n').count() + 1;
+        replaced.push(new.into_owned());
+    }
+    let Some(last_changed) = last_changed else {
+        return Err(format!("Pattern not found: {}", pattern));
+    };
+
+    // Replace the lines as one cut and insert, so undo restores them
+    let end_col = lines[end].chars().count();
+    let textarea = &mut state.editor.textarea;
+    textarea.cancel_selection();
+    jump(textarea, (start, 0));
+    textarea.start_selection();
+    jump(textarea, (end, end_col));
+    textarea.cut();
+    textarea.insert_str(replaced.join("\n"));
+    jump(textarea, (last_changed, 0));
+
+    state.set_status(format!(
+        "{} substitution{} on {} line{}",
+        substitutions,
+        plural(substitutions),
+        changed_lines,
+        plural(changed_lines)
+    ));
+    Ok(())
+}
+
+fn plural(count: usize) -> &'static str {
+    if count == 1 { "" } else { "s" }
+}
+
+/// Text up to an unescaped delimiter and what follows it; `\` before the
+/// delimiter makes it literal
+fn split_part(input: &str, delimiter: char) -> (String, &str) {
+    let mut part = String::new();
+    let mut chars = input.char_indices();
+    while let Some((i, c)) = chars.next() {
+        if c == delimiter {
+            return (part, &input[i + c.len_utf8()..]);
+        }
+        if c == '\\' {
+            match chars.next() {
+                Some((_, next)) if next == delimiter => part.push(next),
+                Some((_, next)) => {
+                    part.push(c);
+                    part.push(next);
+                }
+                None => part.push(c),
+            }
+        } else {
+            part.push(c);
+        }
+    }
+    (part, "")
+}
+
+/// Vim replacement syntax: `&`, `\0`-`\9`, `\n`, `\t` and `\` escapes
+fn parse_replacement(replacement: &str) -> Vec<Piece> {
+    let mut pieces = Vec::new();
+    let mut text = String::new();
+    let mut chars = replacement.chars();
+    while let Some(c) = chars.next() {
+        let group = match c {
+            '&' => Some(0),
+            '\\' => match chars.next() {
+                Some(digit @ '0'..='9') => digit.to_digit(10).map(|d| d as usize),
+                Some('n') => {
+                    text.push('\n');
+                    None
+                }
+                Some('t') => {
+                    text.push('\t');
+                    None
+                }
+                Some(other) => {
+                    text.push(other);
+                    None
+                }
+                None => {
+                    text.push('\\');
+                    None
+                }
+            },
+            _ => {
+                text.push(c);
+                None
+            }
+        };
+        if let Some(group) = group {
+            if !text.is_empty() {
+                pieces.push(Piece::Text(std::mem::take(&mut text)));
+            }
+            pieces.push(Piece::Group(group));
+        }
+    }
+    if !text.is_empty() {
+        pieces.push(Piece::Text(text));
+    }
+    pieces
+}
+
+fn expand(pieces: &[Piece], caps: &Captures) -> String {
+    let mut out = String::new();
+    for piece in pieces {
+        match piece {
+            Piece::Text(text) => out.push_str(text),
+            Piece::Group(group) => {
+                if let Some(m) = caps.get(*group) {
+                    out.push_str(m.as_str());
+                }
+            }
+        }
+    }
+    out
+}
@@ -6,10 +6,21 @@ pub(super) fn handle_insert_mode(state: &mut AppState, key_event: KeyEvent) {
     match key_event.code {
         KeyCode::Esc => {
             state.vim_mode = VimMode::Normal;
+            state.editor.recording_insert = false;
         }
         _ => {
-            let input = convert_key_event_to_input(key_event);
-            state.editor.textarea.input(input);
+            // Typed text becomes part of the change `.` repeats
+            if state.editor.recording_insert
+                && let Some(change) = &mut state.editor.last_change
+            {
+                change.inserted.push(key_event.clone());
+            }
+            insert_key(state, key_event);
         }
     }
 }
+
+pub(super) fn insert_key(state: &mut AppState, key_event: KeyEvent) {
+    let input = convert_key_event_to_input(key_event);
+    state.editor.textarea.input(input);
+}
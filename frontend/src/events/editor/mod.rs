@@ -1,17 +1,50 @@
+mod command_line;
 mod input;
 mod insert_mode;
+mod motions;
 mod normal_mode;
+mod operations;
+mod parser;
+mod search;
+mod visual_mode;
 
 use crate::state::{AppState, VimMode};
+use command_line::handle_command_line;
+use gloo_timers::callback::Timeout;
 use insert_mode::handle_insert_mode;
 use normal_mode::handle_normal_mode;
 use ratzilla::event::KeyEvent;
+use std::{cell::RefCell, rc::Rc};
+use visual_mode::handle_visual_mode;
 
-pub fn handle_keys(state: &mut AppState, key_event: KeyEvent) {
+/// Milliseconds without changes before the buffer is checked again
+const CHECK_DELAY_MS: u32 = 400;
+
+pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
     match state.vim_mode {
         VimMode::Normal => handle_normal_mode(state, key_event),
         VimMode::Insert => handle_insert_mode(state, key_event),
+        VimMode::Visual | VimMode::VisualLine => handle_visual_mode(state, key_event),
+        VimMode::Command => handle_command_line(state, state_rc, key_event),
     }
 
     state.check_dirty();
+    schedule_check(state, state_rc);
+}
+
+/// Validate the buffer once typing pauses (each change restarts the timer)
+fn schedule_check(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let editor = &mut state.editor;
+    if !editor.is_validated() {
+        return;
+    }
+    let content = editor.get_content();
+    if content == editor.checked_content {
+        return;
+    }
+    editor.checked_content = content;
+    let state_rc = Rc::clone(state_rc);
+    editor.check_timer = Some(Timeout::new(CHECK_DELAY_MS, move || {
+        state_rc.borrow_mut().editor.validate();
+    }));
 }
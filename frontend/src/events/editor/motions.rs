@@ -0,0 +1,476 @@
+use super::operations::jump;
+use crate::state::{AppState, TextRange};
+
+/// Cursor position as `(row, col)`
+pub(super) type Pos = (usize, usize);
+
+/// Cursor motions; `bool` fields select WORDs (separated by whitespace only)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Motion {
+    Left,
+    Right,
+    Up,
+    Down,
+    /// `0`
+    LineStart,
+    /// `^`
+    FirstNonBlank,
+    /// `$`
+    LineEnd,
+    /// `w`/`W`
+    WordForward(bool),
+    /// `b`/`B`
+    WordBackward(bool),
+    /// `e`/`E`
+    WordEnd(bool),
+    /// `gg` (line `[count]` with a count)
+    FirstLine,
+    /// `G` (line `[count]` with a count)
+    LastLine,
+    /// `%` (`[count]%` goes to that percentage of the file)
+    MatchingBracket,
+    /// `}`
+    ParagraphForward,
+    /// `{`
+    ParagraphBackward,
+    /// `f`, `F`, `t` or `T` and the character to find
+    Find(char, char),
+    /// `;` repeats the last find, `,` (`true`) in the other direction
+    RepeatFind(bool),
+}
+
+/// How an operator treats the text between the cursor and a motion's target
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum MotionKind {
+    /// Up to the target, without it
+    Exclusive,
+    /// Up to and including the target
+    Inclusive,
+    /// Whole lines
+    Linewise,
+}
+
+impl Motion {
+    /// Motion of a single key
+    pub(super) fn from_key(key: char) -> Option<Self> {
+        Some(match key {
+            'h' => Motion::Left,
+            'l' => Motion::Right,
+            'k' => Motion::Up,
+            'j' => Motion::Down,
+            '0' => Motion::LineStart,
+            '^' => Motion::FirstNonBlank,
+            '$' => Motion::LineEnd,
+            'w' | 'W' => Motion::WordForward(key == 'W'),
+            'b' | 'B' => Motion::WordBackward(key == 'B'),
+            'e' | 'E' => Motion::WordEnd(key == 'E'),
+            'G' => Motion::LastLine,
+            '%' => Motion::MatchingBracket,
+            '}' => Motion::ParagraphForward,
+            '{' => Motion::ParagraphBackward,
+            ';' | ',' => Motion::RepeatFind(key == ','),
+            _ => return None,
+        })
+    }
+
+    pub(super) fn kind(self, count: Option<usize>) -> MotionKind {
+        match self {
+            Motion::Up | Motion::Down | Motion::FirstLine | Motion::LastLine => {
+                MotionKind::Linewise
+            }
+            Motion::MatchingBracket if count.is_some() => MotionKind::Linewise,
+            Motion::LineEnd | Motion::WordEnd(_) | Motion::MatchingBracket => MotionKind::Inclusive,
+            Motion::Find(key, _) if key == 'f' || key == 't' => MotionKind::Inclusive,
+            _ => MotionKind::Exclusive,
+        }
+    }
+}
+
+/// Turn `;`/`,` into the find they repeat, and remember finds for them
+pub(super) fn resolve_find(state: &mut AppState, motion: Motion) -> Option<Motion> {
+    match motion {
+        Motion::Find(key, target) => {
+            state.editor.last_find = Some((key, target));
+            Some(motion)
+        }
+        Motion::RepeatFind(reverse) => {
+            let (key, target) = state.editor.last_find?;
+            let key = if reverse {
+                match key {
+                    'f' => 'F',
+                    'F' => 'f',
+                    't' => 'T',
+                    _ => 't',
+                }
+            } else {
+                key
+            };
+            Some(Motion::Find(key, target))
+        }
+        _ => Some(motion),
+    }
+}
+
+/// Move the cursor by a motion (normal and visual mode)
+pub(super) fn move_cursor(state: &mut AppState, motion: Motion, count: Option<usize>) {
+    let Some(motion) = resolve_find(state, motion) else {
+        return;
+    };
+    let textarea = &mut state.editor.textarea;
+    if let Some(target) = target(textarea.lines(), textarea.cursor(), motion, count, false) {
+        let target = clamp(textarea.lines(), target);
+        jump(textarea, target);
+    }
+}
+
+/// Keep the cursor on a character (normal mode can't go past the last one)
+pub(super) fn clamp(lines: &[String], (row, col): Pos) -> Pos {
+    (row, col.min(lines[row].chars().count().saturating_sub(1)))
+}
+
+/// Where `motion` repeated `count` times goes from `cursor`; None if it fails
+///
+/// `operator` applies vim's special case for `dw` on the last word of a
+/// line: the operator stops at the end of the line.
+pub(super) fn target(
+    lines: &[String],
+    cursor: Pos,
+    motion: Motion,
+    count: Option<usize>,
+    operator: bool,
+) -> Option<Pos> {
+    let text = Text::new(lines);
+    let n = count.unwrap_or(1).max(1);
+    let (row, col) = cursor;
+    let last = text.lines.len() - 1;
+
+    let target = match motion {
+        Motion::Left if col > 0 => (row, col.saturating_sub(n)),
+        Motion::Right if col < text.len(row) => (row, (col + n).min(text.len(row))),
+        Motion::Up if row > 0 => (row.saturating_sub(n), col),
+        Motion::Down if row < last => ((row + n).min(last), col),
+        Motion::Left | Motion::Right | Motion::Up | Motion::Down => return None,
+        Motion::LineStart => (row, 0),
+        Motion::FirstNonBlank => (row, text.first_non_blank(row)),
+        Motion::LineEnd => {
+            let row = (row + n - 1).min(last);
+            (row, text.len(row).saturating_sub(1))
+        }
+        Motion::WordForward(big) => {
+            let mut pos = cursor;
+            for step in 0..n {
+                let from = pos;
+                pos = text.word_forward(pos, big);
+                if operator && step == n - 1 && pos.0 > from.0 && text.len(from.0) > 0 {
+                    pos = (from.0, text.len(from.0));
+                }
+            }
+            pos
+        }
+        Motion::WordBackward(big) => (0..n).fold(cursor, |pos, _| text.word_backward(pos, big)),
+        Motion::WordEnd(big) => (0..n).fold(cursor, |pos, _| text.word_end(pos, big)),
+        Motion::FirstLine | Motion::LastLine => {
+            let default = if motion == Motion::FirstLine { 0 } else { last };
+            let row = count.map_or(default, |c| c.max(1) - 1).min(last);
+            (row, text.first_non_blank(row))
+        }
+        Motion::MatchingBracket => match count {
+            Some(percent) => {
+                let row = ((percent.min(100) * (last + 1)).div_ceil(100)).max(1) - 1;
+                (row, text.first_non_blank(row))
+            }
+            None => text.matching_bracket(cursor)?,
+        },
+        Motion::ParagraphForward => (0..n).fold(cursor, |pos, _| text.paragraph_forward(pos.0)),
+        Motion::ParagraphBackward => (0..n).fold(cursor, |pos, _| text.paragraph_backward(pos.0)),
+        Motion::Find(key, target) => text.find(cursor, key, target, n)?,
+        Motion::RepeatFind(_) => return None,
+    };
+    Some(target)
+}
+
+/// `cw` acts like `ce`, but only changes the character under the cursor
+/// when it ends a word
+pub(super) fn change_word_target(lines: &[String], cursor: Pos, count: usize, big: bool) -> Pos {
+    let text = Text::new(lines);
+    let at_word_end = text
+        .next(cursor)
+        .is_none_or(|next| class(text.char_at(next), big) != class(text.char_at(cursor), big));
+    let first = if at_word_end {
+        cursor
+    } else {
+        text.word_end(cursor, big)
+    };
+    (1..count.max(1)).fold(first, |pos, _| text.word_end(pos, big))
+}
+
+/// Whether the cursor is on whitespace (or past the end of its line)
+pub(super) fn on_blank(lines: &[String], cursor: Pos) -> bool {
+    Text::new(lines).char_at(cursor).is_whitespace()
+}
+
+/// Text an operator covers when the cursor moves to `target`
+pub(super) fn operator_range(
+    lines: &[String],
+    cursor: Pos,
+    target: Pos,
+    kind: MotionKind,
+) -> Option<TextRange> {
+    let text = Text::new(lines);
+    let (start, end) = (cursor.min(target), cursor.max(target));
+
+    // Exclusive end of the range
+    let end = match kind {
+        MotionKind::Linewise => {
+            return Some(TextRange::Lines {
+                start: start.0,
+                end: end.0,
+            });
+        }
+        // The line break is never included
+        MotionKind::Inclusive => (end.0, (end.1 + 1).min(text.len(end.0))),
+        // Ending at the start of a later line stops at the end of the line
+        // before it (and takes whole lines when starting at the indent)
+        MotionKind::Exclusive if end.1 == 0 && end.0 > start.0 => {
+            if start.1 <= text.first_non_blank(start.0) {
+                return Some(TextRange::Lines {
+                    start: start.0,
+                    end: end.0 - 1,
+                });
+            }
+            (end.0 - 1, text.len(end.0 - 1))
+        }
+        MotionKind::Exclusive => end,
+    };
+    if start >= end {
+        return None;
+    }
+
+    // Inclusive end (the line break of the line before for column 0)
+    let end = if end.1 > 0 {
+        (end.0, end.1 - 1)
+    } else {
+        (end.0 - 1, text.len(end.0 - 1))
+    };
+    Some(TextRange::Chars { start, end })
+}
+
+/// Whitespace, word characters and other non-blank characters
+fn class(c: char, big: bool) -> u8 {
+    if c.is_whitespace() {
+        0
+    } else if big || c.is_alphanumeric() || c == '_' {
+        1
+    } else {
+        2
+    }
+}
+
+/// Lines as characters; the position after the last character of a line is
+/// its line break
+struct Text {
+    lines: Vec<Vec<char>>,
+}
+
+impl Text {
+    fn new(lines: &[String]) -> Self {
+        Self {
+            lines: lines.iter().map(|line| line.chars().collect()).collect(),
+        }
+    }
+
+    fn len(&self, row: usize) -> usize {
+        self.lines[row].len()
+    }
+
+    fn char_at(&self, (row, col): Pos) -> char {
+        self.lines[row].get(col).copied().unwrap_or('\n')
+    }
+
+    fn next(&self, (row, col): Pos) -> Option<Pos> {
+        if col < self.len(row) {
+            Some((row, col + 1))
+        } else if row + 1 < self.lines.len() {
+            Some((row + 1, 0))
+        } else {
+            None
+        }
+    }
+
+    fn prev(&self, (row, col): Pos) -> Option<Pos> {
+        if col > 0 {
+            Some((row, col - 1))
+        } else if row > 0 {
+            Some((row - 1, self.len(row - 1)))
+        } else {
+            None
+        }
+    }
+
+    fn is_empty_line(&self, row: usize) -> bool {
+        self.lines[row].is_empty()
+    }
+
+    fn first_non_blank(&self, row: usize) -> usize {
+        let line = &self.lines[row];
+        line.iter()
+            .position(|c| !c.is_whitespace())
+            .unwrap_or(line.len().saturating_sub(1))
+    }
+
+    /// Start of the next word (empty lines count as words)
+    fn word_forward(&self, from: Pos, big: bool) -> Pos {
+        let mut pos = from;
+        let start_class = class(self.char_at(pos), big);
+        if start_class != 0 {
+            while let Some(next) = self.next(pos)
+                && class(self.char_at(next), big) == start_class
+            {
+                pos = next;
+            }
+        }
+        loop {
+            let Some(next) = self.next(pos) else {
+                // End of the buffer
+                let last = self.lines.len() - 1;
+                return (last, self.len(last));
+            };
+            pos = next;
+            if !self.char_at(pos).is_whitespace() || (self.is_empty_line(pos.0) && pos.0 != from.0)
+            {
+                return pos;
+            }
+        }
+    }
+
+    /// Start of the current or previous word
+    fn word_backward(&self, from: Pos, big: bool) -> Pos {
+        let mut pos = from;
+        loop {
+            let Some(prev) = self.prev(pos) else {
+                return pos;
+            };
+            pos = prev;
+            if !self.char_at(pos).is_whitespace() {
+                break;
+            }
+            if self.is_empty_line(pos.0) && pos.0 != from.0 {
+                return pos;
+            }
+        }
+        let word_class = class(self.char_at(pos), big);
+        while let Some(prev) = self.prev(pos)
+            && class(self.char_at(prev), big) == word_class
+        {
+            pos = prev;
+        }
+        pos
+    }
+
+    /// End of the current or next word
+    fn word_end(&self, from: Pos, big: bool) -> Pos {
+        let mut pos = from;
+        loop {
+            let Some(next) = self.next(pos) else {
+                return pos;
+            };
+            pos = next;
+            if !self.char_at(pos).is_whitespace() {
+                break;
+            }
+        }
+        let word_class = class(self.char_at(pos), big);
+        while let Some(next) = self.next(pos)
+            && class(self.char_at(next), big) == word_class
+        {
+            pos = next;
+        }
+        pos
+    }
+
+    /// Next empty line after the current paragraph (or the end of the buffer)
+    fn paragraph_forward(&self, row: usize) -> Pos {
+        let last = self.lines.len() - 1;
+        let mut row = row;
+        while row < last && self.is_empty_line(row) {
+            row += 1;
+        }
+        while row < last && !self.is_empty_line(row) {
+            row += 1;
+        }
+        if self.is_empty_line(row) {
+            (row, 0)
+        } else {
+            (row, self.len(row))
+        }
+    }
+
+    /// Previous empty line before the current paragraph (or the first line)
+    fn paragraph_backward(&self, row: usize) -> Pos {
+        let mut row = row;
+        while row > 0 && self.is_empty_line(row) {
+            row -= 1;
+        }
+        while row > 0 && !self.is_empty_line(row) {
+            row -= 1;
+        }
+        (row, 0)
+    }
+
+    /// Bracket matching the first one at or after the cursor in its line
+    fn matching_bracket(&self, (row, col): Pos) -> Option<Pos> {
+        const PAIRS: [(char, char); 3] = [('(', ')'), ('[', ']'), ('{', '}')];
+
+        let (start, bracket) = self.lines[row]
+            .iter()
+            .enumerate()
+            .skip(col)
+            .find(|(_, c)| PAIRS.iter().any(|(open, close)| *c == open || *c == close))?;
+        let (open, close) = *PAIRS
+            .iter()
+            .find(|(open, close)| bracket == open || bracket == close)?;
+        let forward = *bracket == open;
+        let (this, other) = if forward {
+            (open, close)
+        } else {
+            (close, open)
+        };
+
+        let mut depth = 0;
+        let mut pos = (row, start);
+        loop {
+            let c = self.char_at(pos);
+            if c == this {
+                depth += 1;
+            } else if c == other {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(pos);
+                }
+            }
+            pos = if forward {
+                self.next(pos)?
+            } else {
+                self.prev(pos)?
+            };
+        }
+    }
+
+    /// `count`-th `target` in the line for `f`/`F`/`t`/`T`
+    fn find(&self, (row, col): Pos, key: char, target: char, count: usize) -> Option<Pos> {
+        let line = &self.lines[row];
+        let found = if key == 'f' || key == 't' {
+            let index = (col + 1..line.len())
+                .filter(|&i| line[i] == target)
+                .nth(count - 1)?;
+            if key == 't' { index - 1 } else { index }
+        } else {
+            let index = (0..col.min(line.len()))
+                .rev()
+                .filter(|&i| line[i] == target)
+                .nth(count - 1)?;
+            if key == 'T' { index + 1 } else { index }
+        };
+        Some((row, found))
+    }
+}
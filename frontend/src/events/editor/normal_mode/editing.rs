@@ -1,18 +1,14 @@
 use crate::state::AppState;
-use ratzilla::event::{KeyCode, KeyEvent};
 
-pub(super) fn handle_editing(state: &mut AppState, key_event: &KeyEvent) -> bool {
-    match key_event.code {
-        KeyCode::Char('d') => {
-            state.editor.textarea.delete_line_by_head();
-            true
-        }
-        KeyCode::Char('u') => {
-            state.editor.textarea.undo();
-            true
-        }
-        KeyCode::Char('r') if key_event.ctrl => {
-            state.editor.textarea.redo();
+/// `u` undoes `[count]` changes
+pub(super) fn handle_editing(state: &mut AppState, key: char, count: Option<usize>) -> bool {
+    match key {
+        'u' => {
+            for _ in 0..count.unwrap_or(1) {
+                if !state.editor.textarea.undo() {
+                    break;
+                }
+            }
             true
         }
         _ => false,
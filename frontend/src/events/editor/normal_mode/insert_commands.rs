@@ -1,13 +1,12 @@
 use crate::state::{AppState, VimMode};
-use ratzilla::event::{KeyCode, KeyEvent};
 
-pub(super) fn handle_insert_commands(state: &mut AppState, key_event: &KeyEvent) -> bool {
-    match key_event.code {
-        KeyCode::Char('i') => {
+pub(super) fn handle_insert_commands(state: &mut AppState, key: char) -> bool {
+    match key {
+        'i' => {
             state.vim_mode = VimMode::Insert;
             true
         }
-        KeyCode::Char('a') => {
+        'a' => {
             state.vim_mode = VimMode::Insert;
             state
                 .editor
@@ -15,7 +14,7 @@ pub(super) fn handle_insert_commands(state: &mut AppState, key_event: &KeyEvent)
                 .move_cursor(tui_textarea::CursorMove::Forward);
             true
         }
-        KeyCode::Char('A') => {
+        'A' => {
             state.vim_mode = VimMode::Insert;
             state
                 .editor
@@ -23,7 +22,7 @@ pub(super) fn handle_insert_commands(state: &mut AppState, key_event: &KeyEvent)
                 .move_cursor(tui_textarea::CursorMove::End);
             true
         }
-        KeyCode::Char('I') => {
+        'I' => {
             state.vim_mode = VimMode::Insert;
             state
                 .editor
@@ -31,7 +30,7 @@ pub(super) fn handle_insert_commands(state: &mut AppState, key_event: &KeyEvent)
                 .move_cursor(tui_textarea::CursorMove::Head);
             true
         }
-        KeyCode::Char('o') => {
+        'o' => {
             state.vim_mode = VimMode::Insert;
             state
                 .editor
@@ -40,7 +39,7 @@ pub(super) fn handle_insert_commands(state: &mut AppState, key_event: &KeyEvent)
             state.editor.textarea.insert_newline();
             true
         }
-        KeyCode::Char('O') => {
+        'O' => {
             state.vim_mode = VimMode::Insert;
             state
                 .editor
@@ -1,19 +1,96 @@
 mod editing;
 mod insert_commands;
-mod navigation;
+mod operators;
 
-use crate::state::AppState;
+use super::command_line;
+use super::motions;
+use super::parser::{self, Action, Command, Operator, Parsed};
+use super::search::handle_search_keys;
+use crate::state::{AppState, LastChange, VimMode};
 use editing::handle_editing;
 use insert_commands::handle_insert_commands;
-use navigation::handle_navigation;
-use ratzilla::event::KeyEvent;
+use operators::{handle_operator_keys, operate};
+use ratzilla::event::{KeyCode, KeyEvent};
 
+/// Collect keys until they form a command (`3dw`, `"ayy`, `gg`), then run it
 pub(super) fn handle_normal_mode(state: &mut AppState, key_event: KeyEvent) {
-    if handle_insert_commands(state, &key_event) {
+    if key_event.ctrl && key_event.code == KeyCode::Char('r') {
+        state.editor.pending.clear();
+        state.editor.textarea.redo();
         return;
     }
-    if handle_navigation(state, &key_event) {
+    // Esc and other special keys drop a pending command
+    let Some(key) = parser::command_key(&key_event) else {
+        state.editor.pending.clear();
         return;
+    };
+
+    state.editor.pending.push(key);
+    match parser::parse(&state.editor.pending, true) {
+        Parsed::Pending => {}
+        Parsed::Invalid => state.editor.pending.clear(),
+        Parsed::Complete(command) => {
+            let keys = std::mem::take(&mut state.editor.pending);
+            if command.action == Action::Key('.') {
+                repeat(state, command.count);
+            } else {
+                execute(state, command);
+                if is_change(&command) {
+                    state.editor.last_change = Some(LastChange {
+                        keys,
+                        inserted: Vec::new(),
+                    });
+                    state.editor.recording_insert = state.vim_mode == VimMode::Insert;
+                }
+            }
+        }
+    }
+}
+
+fn execute(state: &mut AppState, command: Command) {
+    state.editor.recording_insert = false;
+    match command.action {
+        Action::Move(motion) => motions::move_cursor(state, motion, command.count),
+        Action::Operate(operator, motion) => {
+            operate(state, operator, motion, command.register, command.count)
+        }
+        Action::Key(':') => command_line::open(state, ':', ""),
+        Action::Key(key) => {
+            let _ = handle_operator_keys(state, key, command.register, command.count)
+                || handle_insert_commands(state, key)
+                || handle_search_keys(state, key, command.count)
+                || handle_editing(state, key, command.count);
+        }
+    }
+}
+
+/// Changes that `.` repeats
+fn is_change(command: &Command) -> bool {
+    match command.action {
+        Action::Operate(operator, _) => operator != Operator::Yank,
+        Action::Key(key) => "xXDCspPiaAIoO".contains(key),
+        Action::Move(_) => false,
+    }
+}
+
+/// `.` runs the last change again (with a new count if given), including
+/// text typed in insert mode after it
+fn repeat(state: &mut AppState, count: Option<usize>) {
+    let Some(change) = state.editor.last_change.clone() else {
+        return;
+    };
+    let Parsed::Complete(mut command) = parser::parse(&change.keys, true) else {
+        return;
+    };
+    if count.is_some() {
+        command.count = count;
+    }
+
+    execute(state, command);
+    if state.vim_mode == VimMode::Insert {
+        for key_event in change.inserted {
+            super::insert_mode::insert_key(state, key_event);
+        }
+        state.vim_mode = VimMode::Normal;
     }
-    handle_editing(state, &key_event);
 }
@@ -0,0 +1,126 @@
+use super::super::motions::{self, Motion, MotionKind};
+use super::super::operations;
+use super::super::parser::Operator;
+use crate::state::{AppState, TextRange, VimMode};
+
+/// `d`, `y` or `c` over a motion, or over `[count]` lines when doubled
+pub(super) fn operate(
+    state: &mut AppState,
+    operator: Operator,
+    motion: Option<Motion>,
+    register: Option<char>,
+    count: Option<usize>,
+) {
+    let textarea = &state.editor.textarea;
+    let lines = textarea.lines();
+    let cursor = textarea.cursor();
+
+    // None if the motion fails, Some(None) if it covers no text
+    let range = match motion {
+        None => Some(Some(TextRange::Lines {
+            start: cursor.0,
+            end: (cursor.0 + count.unwrap_or(1) - 1).min(lines.len() - 1),
+        })),
+        // `cw` on a word changes to its end, not up to the next word
+        Some(Motion::WordForward(big))
+            if operator == Operator::Change && !motions::on_blank(lines, cursor) =>
+        {
+            let target = motions::change_word_target(lines, cursor, count.unwrap_or(1), big);
+            Some(motions::operator_range(
+                lines,
+                cursor,
+                target,
+                MotionKind::Inclusive,
+            ))
+        }
+        Some(motion) => {
+            let Some(motion) = motions::resolve_find(state, motion) else {
+                return;
+            };
+            let lines = state.editor.textarea.lines();
+            motions::target(lines, cursor, motion, count, true)
+                .map(|target| motions::operator_range(lines, cursor, target, motion.kind(count)))
+        }
+    };
+
+    match (operator, range) {
+        (_, None) => {}
+        (Operator::Change, Some(range)) => {
+            if let Some(range) = range {
+                operations::change(state, range, register);
+            }
+            state.vim_mode = VimMode::Insert;
+        }
+        (_, Some(None)) => {}
+        (Operator::Delete, Some(Some(range))) => operations::delete(state, range, register),
+        (Operator::Yank, Some(Some(range))) => {
+            operations::yank(state, range, register);
+            // The cursor goes to the start of the text (keeping its column for lines)
+            let start = match range {
+                TextRange::Chars { start, .. } => start,
+                TextRange::Lines { start, .. } => (start, cursor.1),
+            };
+            let textarea = &mut state.editor.textarea;
+            let start = motions::clamp(textarea.lines(), start);
+            operations::jump(textarea, start);
+        }
+    }
+}
+
+/// `x`, `X`, `D`, `C`, `s`, `Y`, `p`/`P` and entering visual modes
+pub(super) fn handle_operator_keys(
+    state: &mut AppState,
+    key: char,
+    register: Option<char>,
+    count: Option<usize>,
+) -> bool {
+    match key {
+        'x' => operate(
+            state,
+            Operator::Delete,
+            Some(Motion::Right),
+            register,
+            count,
+        ),
+        'X' => operate(state, Operator::Delete, Some(Motion::Left), register, count),
+        'D' => operate(
+            state,
+            Operator::Delete,
+            Some(Motion::LineEnd),
+            register,
+            count,
+        ),
+        'C' => operate(
+            state,
+            Operator::Change,
+            Some(Motion::LineEnd),
+            register,
+            count,
+        ),
+        's' => {
+            operate(
+                state,
+                Operator::Change,
+                Some(Motion::Right),
+                register,
+                count,
+            );
+            // Also on an empty line
+            state.vim_mode = VimMode::Insert;
+        }
+        'Y' => operate(state, Operator::Yank, None, register, count),
+        'p' | 'P' => {
+            operations::paste(state, key == 'P', register, count.unwrap_or(1));
+        }
+        'v' | 'V' => {
+            state.editor.visual_anchor = state.editor.textarea.cursor();
+            state.vim_mode = if key == 'v' {
+                VimMode::Visual
+            } else {
+                VimMode::VisualLine
+            };
+        }
+        _ => return false,
+    }
+    true
+}
@@ -0,0 +1,193 @@
+use crate::state::{AppState, Register, TextRange};
+use tui_textarea::{CursorMove, TextArea};
+
+/// Copy a range into the chosen register (or the unnamed one)
+pub(super) fn yank(state: &mut AppState, range: TextRange, name: Option<char>) {
+    let register = text_of(&state.editor.textarea, range);
+    state.registers.store(name, register, true);
+}
+
+/// Put the cursor at the beginning of a range (after yanking a selection)
+pub(super) fn move_to_start(state: &mut AppState, range: TextRange) {
+    let start = match range {
+        TextRange::Chars { start, .. } => start,
+        TextRange::Lines { start, .. } => (start, 0),
+    };
+    jump(&mut state.editor.textarea, start);
+}
+
+/// Delete a range, keeping the text in the chosen register
+pub(super) fn delete(state: &mut AppState, range: TextRange, name: Option<char>) {
+    let register = text_of(&state.editor.textarea, range);
+    state.registers.store(name, register, false);
+    remove(&mut state.editor.textarea, range);
+}
+
+/// Delete a range and start insert mode in its place (an empty line for
+/// linewise ranges)
+pub(super) fn change(state: &mut AppState, range: TextRange, name: Option<char>) {
+    let textarea = &state.editor.textarea;
+    let reopen = matches!(range, TextRange::Lines { start, end } if start > 0 || end + 1 < textarea.lines().len());
+    let appended =
+        matches!(range, TextRange::Lines { end, .. } if end + 1 >= textarea.lines().len());
+
+    delete(state, range, name);
+
+    if reopen {
+        let textarea = &mut state.editor.textarea;
+        if appended {
+            textarea.move_cursor(CursorMove::End);
+            textarea.insert_newline();
+        } else {
+            textarea.move_cursor(CursorMove::Head);
+            textarea.insert_newline();
+            textarea.move_cursor(CursorMove::Up);
+        }
+    }
+}
+
+/// Paste the chosen register `count` times after (`p`) or before (`P`) the
+/// cursor
+///
+/// Returns false if the register is empty.
+pub(super) fn paste(state: &mut AppState, before: bool, name: Option<char>, count: usize) -> bool {
+    let Some(register) = state.registers.get(name) else {
+        return false;
+    };
+    let separator = if register.linewise { "\n" } else { "" };
+    let register = Register {
+        text: vec![register.text.as_str(); count.max(1)].join(separator),
+        linewise: register.linewise,
+    };
+    insert_register(&mut state.editor.textarea, &register, before);
+    true
+}
+
+/// Replace a visual selection with the chosen register; the replaced text
+/// goes to the unnamed register
+pub(super) fn replace(state: &mut AppState, range: TextRange, name: Option<char>) -> bool {
+    let Some(mut register) = state.registers.get(name).cloned() else {
+        return false;
+    };
+
+    let line_count = state.editor.textarea.lines().len();
+    delete(state, range, None);
+
+    let textarea = &mut state.editor.textarea;
+    match range {
+        TextRange::Lines { start, end } => {
+            // The lines take the place of the selection
+            register.linewise = true;
+            let was_last = end + 1 >= line_count;
+            if start == 0 && was_last {
+                // Only the empty line is left
+                textarea.insert_str(&register.text);
+                textarea.move_cursor(CursorMove::Head);
+            } else {
+                insert_register(textarea, &register, !was_last);
+            }
+        }
+        TextRange::Chars { .. } => insert_register(textarea, &register, true),
+    }
+    true
+}
+
+fn insert_register(textarea: &mut TextArea, register: &Register, before: bool) {
+    let (row, col) = textarea.cursor();
+    if register.linewise {
+        if before {
+            textarea.move_cursor(CursorMove::Head);
+            textarea.insert_str(&register.text);
+            textarea.insert_newline();
+            jump(textarea, (row, 0));
+        } else {
+            textarea.move_cursor(CursorMove::End);
+            textarea.insert_newline();
+            textarea.insert_str(&register.text);
+            jump(textarea, (row + 1, 0));
+        }
+    } else {
+        let line_len = textarea.lines()[row].chars().count();
+        if !before && col < line_len {
+            textarea.move_cursor(CursorMove::Forward);
+        }
+        textarea.insert_str(&register.text);
+        // Cursor on the last pasted character
+        textarea.move_cursor(CursorMove::Back);
+    }
+}
+
+/// Text of a range as a register
+fn text_of(textarea: &TextArea, range: TextRange) -> Register {
+    let lines = textarea.lines();
+    match range {
+        TextRange::Lines { start, end } => Register {
+            text: lines[start..=end.min(lines.len() - 1)].join("\n"),
+            linewise: true,
+        },
+        TextRange::Chars { start, end } => {
+            let end = exclusive_end(lines, end);
+            let mut text = String::new();
+            for (row, line) in lines.iter().enumerate().take(end.0 + 1).skip(start.0) {
+                let from = if row == start.0 { start.1 } else { 0 };
+                let to = if row == end.0 {
+                    end.1
+                } else {
+                    line.chars().count()
+                };
+                text.extend(line.chars().skip(from).take(to.saturating_sub(from)));
+                if row < end.0 {
+                    text.push('\n');
+                }
+            }
+            Register {
+                text,
+                linewise: false,
+            }
+        }
+    }
+}
+
+/// Remove a range from the buffer (undoable)
+fn remove(textarea: &mut TextArea, range: TextRange) {
+    let lines = textarea.lines();
+    let last = lines.len() - 1;
+    let line_end = |row: usize| (row, lines[row].chars().count());
+
+    let (from, to, cursor) = match range {
+        TextRange::Chars { start, end } => (start, exclusive_end(lines, end), start),
+        // The line break after the lines, or before them at the end of the buffer
+        TextRange::Lines { start, end } if end < last => ((start, 0), (end + 1, 0), (start, 0)),
+        TextRange::Lines { start, end } if start > 0 => {
+            (line_end(start - 1), line_end(end), (start - 1, 0))
+        }
+        TextRange::Lines { end, .. } => ((0, 0), line_end(end), (0, 0)),
+    };
+    if from == to {
+        return;
+    }
+
+    textarea.cancel_selection();
+    jump(textarea, from);
+    textarea.start_selection();
+    jump(textarea, to);
+    textarea.cut();
+    jump(textarea, cursor);
+}
+
+/// Position just after an inclusive end; the line break is included when the
+/// end is past the last character of a line
+fn exclusive_end(lines: &[String], (row, col): (usize, usize)) -> (usize, usize) {
+    let len = lines[row].chars().count();
+    if col < len {
+        (row, col + 1)
+    } else if row + 1 < lines.len() {
+        (row + 1, 0)
+    } else {
+        (row, len)
+    }
+}
+
+pub(super) fn jump(textarea: &mut TextArea, (row, col): (usize, usize)) {
+    textarea.move_cursor(CursorMove::Jump(row as u16, col as u16));
+}
@@ -0,0 +1,162 @@
+use super::motions::Motion;
+use crate::state::Registers;
+use ratzilla::event::{KeyCode, KeyEvent};
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Largest count accepted (keeps `99999999dd` from overflowing)
+const MAX_COUNT: usize = 99_999;
+
+/// Operators waiting for a motion (`dw`) or doubled for whole lines (`dd`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Operator {
+    Delete,
+    Yank,
+    Change,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Action {
+    Move(Motion),
+    /// Operator over a motion, or over lines when doubled (None)
+    Operate(Operator, Option<Motion>),
+    /// Any other key (`x`, `p`, `i`, `u`, `.`, ...), left to the mode
+    Key(char),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Command {
+    pub register: Option<char>,
+    /// Counts before the command and the motion multiplied (None if neither)
+    pub count: Option<usize>,
+    pub action: Action,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Parsed {
+    /// More keys are needed
+    Pending,
+    /// The keys can't form a command and are dropped
+    Invalid,
+    Complete(Command),
+}
+
+/// Key as typed into a pending command; arrows and Home/End stand for their
+/// motions, other special keys and modifiers can't be part of a command
+pub(super) fn command_key(key_event: &KeyEvent) -> Option<char> {
+    if key_event.ctrl || key_event.alt {
+        return None;
+    }
+    match key_event.code {
+        KeyCode::Char(c) => Some(c),
+        KeyCode::Left => Some('h'),
+        KeyCode::Right => Some('l'),
+        KeyCode::Up => Some('k'),
+        KeyCode::Down => Some('j'),
+        KeyCode::Home => Some('0'),
+        KeyCode::End => Some('$'),
+        _ => None,
+    }
+}
+
+/// Parse `["x][count]` followed by a motion, an operator with `[count]` and
+/// a motion (`d3w`, `dd`), or any other key. Operators are plain keys when
+/// `operators` is false (visual mode acts on the selection instead).
+pub(super) fn parse(keys: &str, operators: bool) -> Parsed {
+    let mut chars = keys.chars().peekable();
+    let mut register = None;
+    let mut count = None;
+
+    // Register and count may come in either order
+    loop {
+        match chars.peek() {
+            Some('"') => {
+                chars.next();
+                match chars.next() {
+                    None => return Parsed::Pending,
+                    Some(name) if Registers::is_valid_name(name) => register = Some(name),
+                    Some(_) => return Parsed::Invalid,
+                }
+            }
+            Some('1'..='9') => count = multiply(count, read_count(&mut chars)),
+            _ => break,
+        }
+    }
+
+    let Some(key) = chars.next() else {
+        return Parsed::Pending;
+    };
+    let operator = match key {
+        'd' => Some(Operator::Delete),
+        'y' => Some(Operator::Yank),
+        'c' => Some(Operator::Change),
+        _ => None,
+    };
+
+    let action = match operator.filter(|_| operators) {
+        None => match motion(key, &mut chars) {
+            MotionParse::Motion(motion) => Action::Move(motion),
+            MotionParse::NotMotion => Action::Key(key),
+            MotionParse::Pending => return Parsed::Pending,
+            MotionParse::Invalid => return Parsed::Invalid,
+        },
+        Some(operator) => {
+            if matches!(chars.peek(), Some('1'..='9')) {
+                count = multiply(count, read_count(&mut chars));
+            }
+            let Some(next) = chars.next() else {
+                return Parsed::Pending;
+            };
+            if next == key {
+                Action::Operate(operator, None)
+            } else {
+                match motion(next, &mut chars) {
+                    MotionParse::Motion(motion) => Action::Operate(operator, Some(motion)),
+                    MotionParse::Pending => return Parsed::Pending,
+                    MotionParse::NotMotion | MotionParse::Invalid => return Parsed::Invalid,
+                }
+            }
+        }
+    };
+
+    Parsed::Complete(Command {
+        register,
+        count,
+        action,
+    })
+}
+
+enum MotionParse {
+    Motion(Motion),
+    NotMotion,
+    Pending,
+    Invalid,
+}
+
+fn motion(key: char, chars: &mut Peekable<Chars>) -> MotionParse {
+    match key {
+        'g' => match chars.next() {
+            Some('g') => MotionParse::Motion(Motion::FirstLine),
+            Some(_) => MotionParse::Invalid,
+            None => MotionParse::Pending,
+        },
+        'f' | 'F' | 't' | 'T' => match chars.next() {
+            Some(target) => MotionParse::Motion(Motion::Find(key, target)),
+            None => MotionParse::Pending,
+        },
+        _ => Motion::from_key(key).map_or(MotionParse::NotMotion, MotionParse::Motion),
+    }
+}
+
+fn read_count(chars: &mut Peekable<Chars>) -> usize {
+    let mut count: usize = 0;
+    while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+        chars.next();
+        count = (count * 10 + digit as usize).min(MAX_COUNT);
+    }
+    count
+}
+
+fn multiply(count: Option<usize>, factor: usize) -> Option<usize> {
+    Some((count.unwrap_or(1) * factor).min(MAX_COUNT))
+}
@@ -0,0 +1,166 @@
+use super::command_line;
+use super::motions::Pos;
+use super::operations::jump;
+use crate::state::{AppState, EditorOptions, Search};
+use regex_lite::{Regex, RegexBuilder};
+
+/// `/` and `?` open the search prompt, `n`/`N` repeat the last search and
+/// `*`/`#` search for the word under the cursor
+pub(super) fn handle_search_keys(state: &mut AppState, key: char, count: Option<usize>) -> bool {
+    let result = match key {
+        '/' | '?' => {
+            command_line::open(state, key, "");
+            state.command_line.count = count;
+            Ok(())
+        }
+        'n' | 'N' => repeat(state, key == 'N', count.unwrap_or(1)),
+        '*' | '#' => search_word(state, key == '*', count.unwrap_or(1)),
+        _ => return false,
+    };
+    if let Err(message) = result {
+        state.set_status(format!("[ERROR {}]", message));
+    }
+    true
+}
+
+/// Search for a pattern typed at the `/` or `?` prompt; an empty pattern
+/// repeats the last search in the prompt's direction
+pub(super) fn run(state: &mut AppState, pattern: &str, forward: bool, count: usize) {
+    let result = if pattern.is_empty() {
+        match &mut state.editor.search {
+            Some(search) => {
+                search.forward = forward;
+                go_to_match(state, forward, count)
+            }
+            None => Err("No previous regular expression".to_string()),
+        }
+    } else {
+        let options = state.editor.options;
+        let source = if options.regex {
+            pattern.to_string()
+        } else {
+            regex_lite::escape(pattern)
+        };
+        compile(&options, &source).and_then(|regex| {
+            set_search(state, pattern.to_string(), source, regex, forward);
+            go_to_match(state, forward, count)
+        })
+    };
+    if let Err(message) = result {
+        state.set_status(format!("[ERROR {}]", message));
+    }
+}
+
+/// Build a search regex, ignoring case if `ignorecase` is set
+pub(super) fn compile(options: &EditorOptions, source: &str) -> Result<Regex, String> {
+    RegexBuilder::new(source)
+        .case_insensitive(options.ignore_case)
+        .build()
+        .map_err(|e| format!("Invalid pattern: {}", e))
+}
+
+/// Make a pattern the one `n`, `N` and an empty `:s` pattern use
+pub(super) fn set_search(
+    state: &mut AppState,
+    pattern: String,
+    source: String,
+    regex: Regex,
+    forward: bool,
+) {
+    let editor = &mut state.editor;
+    editor.last_pattern = Some(source);
+    editor.search = Some(Search {
+        pattern,
+        regex,
+        forward,
+    });
+    editor.highlight_search = true;
+}
+
+/// `n` goes to the next match in the search's direction, `N` the other way
+fn repeat(state: &mut AppState, reverse: bool, count: usize) -> Result<(), String> {
+    let search = state
+        .editor
+        .search
+        .as_ref()
+        .ok_or_else(|| "No previous regular expression".to_string())?;
+    let forward = search.forward != reverse;
+    state.editor.highlight_search = true;
+    go_to_match(state, forward, count)
+}
+
+/// `*` (forward) or `#` (backward) for the whole word under or after the cursor
+fn search_word(state: &mut AppState, forward: bool, count: usize) -> Result<(), String> {
+    let (row, col) = state.editor.textarea.cursor();
+    let line: Vec<char> = state.editor.textarea.lines()[row].chars().collect();
+    let is_word = |c: &char| c.is_alphanumeric() || *c == '_';
+
+    let start = if line.get(col).is_some_and(is_word) {
+        col - line[..col].iter().rev().take_while(|c| is_word(c)).count()
+    } else {
+        line.iter()
+            .skip(col)
+            .position(is_word)
+            .map(|offset| col + offset)
+            .ok_or_else(|| "No string under cursor".to_string())?
+    };
+    let word: String = line[start..].iter().take_while(|c| is_word(c)).collect();
+
+    let source = format!(r"\b{}\b", regex_lite::escape(&word));
+    let regex = compile(&state.editor.options, &source)?;
+    set_search(state, source.clone(), source, regex, forward);
+    // Start from the word so `#` skips the occurrence under the cursor
+    jump(&mut state.editor.textarea, (row, start));
+    go_to_match(state, forward, count)
+}
+
+/// Move to the `count`th match from the cursor, wrapping around the buffer,
+/// and show which match it is
+fn go_to_match(state: &mut AppState, forward: bool, count: usize) -> Result<(), String> {
+    let Some(search) = &state.editor.search else {
+        return Ok(());
+    };
+    let textarea = &state.editor.textarea;
+    let matches = matches(textarea.lines(), &search.regex);
+    if matches.is_empty() {
+        return Err(format!("Pattern not found: {}", search.pattern));
+    }
+
+    let cursor = textarea.cursor();
+    let total = matches.len() as isize;
+    let target = if forward {
+        let up_to_cursor = matches.iter().take_while(|&&m| m <= cursor).count();
+        (up_to_cursor + count - 1) as isize
+    } else {
+        let before_cursor = matches.iter().take_while(|&&m| m < cursor).count();
+        before_cursor as isize - count as isize
+    };
+    let wrapped = !(0..total).contains(&target);
+    let index = target.rem_euclid(total) as usize;
+
+    let prompt = if forward { '/' } else { '?' };
+    let message = format!(
+        "{}{} [{}/{}]{}",
+        prompt,
+        search.pattern,
+        index + 1,
+        matches.len(),
+        if wrapped { " (wrapped)" } else { "" }
+    );
+    jump(&mut state.editor.textarea, matches[index]);
+    state.set_status(message);
+    Ok(())
+}
+
+/// Start of every match in the buffer, in order
+fn matches(lines: &[String], regex: &Regex) -> Vec<Pos> {
+    lines
+        .iter()
+        .enumerate()
+        .flat_map(|(row, line)| {
+            regex
+                .find_iter(line)
+                .map(move |m| (row, line[..m.start()].chars().count()))
+        })
+        .collect()
+}
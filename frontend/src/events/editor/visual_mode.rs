@@ -0,0 +1,94 @@
+use super::command_line;
+use super::motions;
+use super::operations;
+use super::parser::{self, Action, Parsed};
+use super::search;
+use crate::state::{AppState, TextRange, VimMode};
+use ratzilla::event::{KeyCode, KeyEvent};
+
+/// Characterwise (`v`) and linewise (`V`) selection; motions (with counts)
+/// move the cursor end of the selection
+pub(super) fn handle_visual_mode(state: &mut AppState, key_event: KeyEvent) {
+    let Some(range) = state.editor.visual_range(state.vim_mode) else {
+        return;
+    };
+    // `'<,'>` on the command line refers to the selection before this key
+    state.editor.visual_marks = Some(match range {
+        TextRange::Chars { start, end } => (start.0, end.0),
+        TextRange::Lines { start, end } => (start, end),
+    });
+
+    if key_event.code == KeyCode::Esc {
+        leave(state);
+        return;
+    }
+    let Some(key) = parser::command_key(&key_event) else {
+        state.editor.pending.clear();
+        return;
+    };
+
+    state.editor.pending.push(key);
+    let command = match parser::parse(&state.editor.pending, false) {
+        Parsed::Pending => return,
+        Parsed::Invalid => {
+            state.editor.pending.clear();
+            return;
+        }
+        Parsed::Complete(command) => command,
+    };
+    state.editor.pending.clear();
+    let register = command.register;
+
+    match command.action {
+        Action::Move(motion) => motions::move_cursor(state, motion, command.count),
+        Action::Key(key @ ('v' | 'V')) => {
+            let mode = if key == 'v' {
+                VimMode::Visual
+            } else {
+                VimMode::VisualLine
+            };
+            // The same key leaves, the other one switches
+            if state.vim_mode == mode {
+                leave(state);
+            } else {
+                state.vim_mode = mode;
+            }
+        }
+        Action::Key('o') => {
+            let cursor = state.editor.textarea.cursor();
+            let anchor = std::mem::replace(&mut state.editor.visual_anchor, cursor);
+            operations::jump(&mut state.editor.textarea, anchor);
+        }
+        Action::Key('y') => {
+            operations::yank(state, range, register);
+            operations::move_to_start(state, range);
+            leave(state);
+        }
+        Action::Key('d' | 'x') => {
+            operations::delete(state, range, register);
+            leave(state);
+        }
+        Action::Key('c') => {
+            operations::change(state, range, register);
+            leave(state);
+            state.vim_mode = VimMode::Insert;
+        }
+        Action::Key('p' | 'P') => {
+            operations::replace(state, range, register);
+            leave(state);
+        }
+        Action::Key(key @ ('n' | 'N' | '*' | '#')) => {
+            search::handle_search_keys(state, key, command.count);
+        }
+        Action::Key(':') => {
+            leave(state);
+            command_line::open(state, ':', "'<,'>");
+        }
+        Action::Key(_) | Action::Operate(..) => {}
+    }
+}
+
+fn leave(state: &mut AppState) {
+    state.vim_mode = VimMode::Normal;
+    state.editor.pending.clear();
+}
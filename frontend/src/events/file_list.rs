@@ -1,5 +1,5 @@
 use crate::api;
-use crate::state::{AppState, Pane, refresh, status_helper};
+use crate::state::{AppState, ContainerBrowse, FileRow, Pane, refresh, status_helper};
 use crate::utils;
 use ratzilla::event::KeyEvent;
 use std::{cell::RefCell, rc::Rc};
@@ -9,8 +9,13 @@ pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_e
     let keybinds = &state.keybinds.file_list;
 
     if super::key_matches(&key_event, &keybinds.back_to_menu) {
-        state.focus = Pane::Menu;
-        state.status_message = None;
+        // Leave a container directory first
+        if state.file_list.browse.is_some() {
+            state.file_list.leave_browse();
+        } else {
+            state.focus = Pane::Menu;
+            state.status_message = None;
+        }
     } else if super::key_matches(&key_event, &keybinds.navigate_down)
         || super::key_matches(&key_event, &keybinds.navigate_down_alt)
     {
@@ -21,33 +26,111 @@ pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_e
     {
         state.file_list.previous();
         refresh::save_selection(Pane::FileList, state);
-    } else if super::key_matches(&key_event, &keybinds.select)
-        && let Some(fileinfo) = state.file_list.selected().cloned()
-    {
-        let state_clone = Rc::clone(state_rc);
-        spawn_local(async move {
-            match api::fetch_file_content(&fileinfo.name).await {
-                Ok(content) => {
-                    {
-                        let mut st = state_clone.borrow_mut();
-                        st.editor.load_content(fileinfo.name.clone(), content);
-                        st.dirty = false;
-                        st.focus = Pane::Editor;
-                    }
-                    status_helper::set_status_timed(&state_clone, "[loaded]");
-                }
-                Err(e) => {
-                    {
-                        let mut st = state_clone.borrow_mut();
-                        st.editor.clear();
-                        st.dirty = false;
-                    }
-                    status_helper::set_status_timed(
-                        &state_clone,
-                        format!("[ERROR loading: {}]", utils::error::format_error(&e)),
-                    );
-                }
+    } else if super::key_matches(&key_event, &keybinds.select) {
+        select(state, state_rc);
+    }
+}
+
+fn select(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let file_list = &state.file_list;
+    match (file_list.selected_row(), &file_list.browse) {
+        (Some(FileRow::File(file)), _) => {
+            let name = file.name.clone();
+            open_file(state, state_rc, name)
+        }
+        (Some(FileRow::Container(container)), _) => {
+            browse(state_rc, container.to_string(), "/".to_string(), None)
+        }
+        (Some(FileRow::Parent), Some(current)) => match current.parent_path() {
+            Some(parent) => {
+                // Keep the directory we came from selected
+                let from = current.path.rsplit('/').next().map(str::to_string);
+                browse(state_rc, current.container.clone(), parent, from)
+            }
+            None => state.file_list.leave_browse(),
+        },
+        (Some(FileRow::Entry(entry)), Some(current)) if entry.dir => browse(
+            state_rc,
+            current.container.clone(),
+            current.entry_path(&entry.name),
+            None,
+        ),
+        (Some(FileRow::Entry(entry)), Some(current)) => {
+            let name = current.file_name(&entry.name);
+            open_file(state, state_rc, name)
+        }
+        _ => {}
+    }
+}
+
+/// List a directory inside a container and show it in the file list
+fn browse(
+    state_rc: &Rc<RefCell<AppState>>,
+    container: String,
+    path: String,
+    select: Option<String>,
+) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        match api::fetch_container_dir(&container, &path).await {
+            Ok(dir) => {
+                let browse = ContainerBrowse {
+                    container,
+                    path: dir.path,
+                    entries: dir.entries,
+                };
+                state_clone
+                    .borrow_mut()
+                    .file_list
+                    .enter_dir(browse, select.as_deref());
+            }
+            Err(e) => {
+                status_helper::set_status_timed(
+                    &state_clone,
+                    format!(
+                        "[ERROR listing {}:{}: {}]",
+                        container,
+                        path,
+                        utils::error::format_error(&e)
+                    ),
+                );
             }
-        });
+        }
+    });
+}
+
+/// Show a file in the editor, switching to its buffer if it is already open
+pub fn open_file(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, name: String) {
+    match state.editor.buffer_index(&name) {
+        Some(index) => {
+            state.switch_buffer(index);
+            state.focus = Pane::Editor;
+        }
+        None => load_file(state_rc, name),
     }
 }
+
+/// Load a file into a new buffer (or the current one again) and focus it
+pub fn load_file(state_rc: &Rc<RefCell<AppState>>, name: String) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        match api::fetch_file_content(&name).await {
+            Ok(content) => {
+                {
+                    let mut st = state_clone.borrow_mut();
+                    st.add_buffer(&name);
+                    st.editor.load_content(name, content);
+                    st.dirty = false;
+                    st.focus = Pane::Editor;
+                }
+                status_helper::set_status_timed(&state_clone, "[loaded]");
+            }
+            Err(e) => {
+                status_helper::set_status_timed(
+                    &state_clone,
+                    format!("[ERROR loading: {}]", utils::error::format_error(&e)),
+                );
+            }
+        }
+    });
+}
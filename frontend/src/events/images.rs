@@ -0,0 +1,124 @@
+use crate::{
+    api,
+    state::{AppState, ImageConfirm, Pane, images::short_image_id, refresh, status_helper},
+    utils,
+};
+use ratzilla::event::{KeyCode, KeyEvent};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    // A pending confirmation takes all keys
+    if state.images.pending.is_some() {
+        handle_pending(state, state_rc, &key_event);
+        return;
+    }
+
+    let keybinds = &state.keybinds.images;
+
+    if super::key_matches(&key_event, &keybinds.navigate_down)
+        || super::key_matches(&key_event, &keybinds.navigate_down_alt)
+    {
+        state.images.next();
+    } else if super::key_matches(&key_event, &keybinds.navigate_up)
+        || super::key_matches(&key_event, &keybinds.navigate_up_alt)
+    {
+        state.images.previous();
+    } else if super::key_matches(&key_event, &keybinds.inspect) {
+        load_details(state, state_rc);
+    } else if super::key_matches(&key_event, &keybinds.remove_image) {
+        if let Some(image) = state.images.selected() {
+            let label = image
+                .tags
+                .first()
+                .cloned()
+                .unwrap_or_else(|| short_image_id(&image.id).to_string());
+            state.images.pending = Some(ImageConfirm::Remove {
+                id: image.id.clone(),
+                label,
+                force: false,
+            });
+        }
+    } else if super::key_matches(&key_event, &keybinds.prune_images) {
+        state.images.pending = Some(ImageConfirm::Prune);
+    } else if super::key_matches(&key_event, &keybinds.back_to_menu) {
+        state.focus = Pane::Menu;
+    }
+}
+
+fn handle_pending(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: &KeyEvent) {
+    match key_event.code {
+        KeyCode::Char('y') | KeyCode::Enter => {
+            if let Some(pending) = state.images.pending.take() {
+                confirm(state, state_rc, pending);
+            }
+        }
+        KeyCode::Char('n') | KeyCode::Esc => {
+            state.images.pending = None;
+            state.set_status("Cancelled");
+        }
+        KeyCode::Char('f') => {
+            if let Some(pending) = state.images.pending.as_mut() {
+                pending.toggle_force();
+            }
+        }
+        _ => {}
+    }
+}
+
+fn confirm(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, pending: ImageConfirm) {
+    let state_clone = Rc::clone(state_rc);
+    match pending {
+        ImageConfirm::Remove { id, label, force } => {
+            state.set_status(format!("Removing {}...", label));
+            spawn_local(async move {
+                let message = match api::remove_image(&id, force).await {
+                    Ok(msg) => format!("{}: {}", label, msg),
+                    Err(e) => format!(
+                        "[ERROR removing {}: {}]",
+                        label,
+                        utils::error::format_error(&e)
+                    ),
+                };
+                status_helper::set_status_timed(&state_clone, message);
+                refresh::refresh_pane(Pane::Images, &state_clone);
+            });
+        }
+        ImageConfirm::Prune => {
+            state.set_status("Pruning dangling images...");
+            spawn_local(async move {
+                let message = match api::prune_images().await {
+                    Ok(msg) => msg,
+                    Err(e) => format!("[ERROR pruning: {}]", utils::error::format_error(&e)),
+                };
+                status_helper::set_status_timed(&state_clone, message);
+                refresh::refresh_pane(Pane::Images, &state_clone);
+            });
+        }
+    }
+}
+
+fn load_details(state: &AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let Some(image) = state.images.selected() else {
+        return;
+    };
+    let image_id = image.id.clone();
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        match api::fetch_image_details(&image_id).await {
+            Ok(details) => {
+                state_clone.borrow_mut().images.details = Some(details);
+                status_helper::set_status_timed(&state_clone, "[loaded]");
+            }
+            Err(e) => {
+                status_helper::set_status_timed(
+                    &state_clone,
+                    format!(
+                        "[ERROR loading image details: {}]",
+                        utils::error::format_error(&e)
+                    ),
+                );
+            }
+        }
+    });
+}
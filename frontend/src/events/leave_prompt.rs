@@ -0,0 +1,91 @@
+use super::{file_list, menu};
+use crate::state::{AppState, LeaveAction, LeavePrompt, Pane};
+use ratzilla::event::{KeyCode, KeyEvent};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Ask whether to save or discard the current buffer's changes before
+/// `action`
+pub fn ask(state: &mut AppState, action: LeaveAction) {
+    if let Some(filename) = state.editor.current_file.clone() {
+        state.leave_prompt = Some(LeavePrompt { filename, action });
+        state.focus = Pane::Editor;
+    }
+}
+
+/// `s`, `d` and `c` answer the prompt; it takes all keys while open
+pub fn handle_keys(
+    state: &mut AppState,
+    state_rc: &Rc<RefCell<AppState>>,
+    key_event: &KeyEvent,
+) -> bool {
+    let Some(prompt) = state.leave_prompt.clone() else {
+        return false;
+    };
+
+    match key_event.code {
+        KeyCode::Char('s') => {
+            state.leave_prompt = None;
+            save_then(state, state_rc, prompt);
+        }
+        KeyCode::Char('d') => {
+            state.leave_prompt = None;
+            discard(state, state_rc, prompt.action);
+        }
+        KeyCode::Char('c') | KeyCode::Esc => {
+            state.leave_prompt = None;
+            state.set_status("Cancelled");
+        }
+        _ => {}
+    }
+    true
+}
+
+/// Drop the changes of the current buffer and go on
+fn discard(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, action: LeaveAction) {
+    match action {
+        LeaveAction::Quit => {
+            state.close_buffer();
+            state.focus = Pane::FileList;
+        }
+        LeaveAction::Close => state.close_buffer(),
+        LeaveAction::Reload => {
+            if let Some(filename) = state.editor.current_file.clone() {
+                file_list::load_file(state_rc, filename);
+            }
+        }
+    }
+}
+
+/// Write the buffer, then go on once it is saved (and wasn't changed again
+/// in the meantime)
+fn save_then(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, prompt: LeavePrompt) {
+    let content = state.editor.get_content();
+    let state_rc = Rc::clone(state_rc);
+    spawn_local(async move {
+        if !menu::write_file(&state_rc, prompt.filename.clone(), content).await {
+            return;
+        }
+        let mut st = state_rc.borrow_mut();
+        let saved = st
+            .open_buffers()
+            .iter()
+            .any(|(name, dirty)| *name == prompt.filename && !dirty);
+        if !saved {
+            return;
+        }
+        match prompt.action {
+            LeaveAction::Quit | LeaveAction::Close => {
+                if let Some(index) = st.editor.buffer_index(&prompt.filename) {
+                    st.switch_buffer(index);
+                    st.close_buffer();
+                }
+                if prompt.action == LeaveAction::Quit {
+                    st.focus = Pane::FileList;
+                }
+            }
+            // The file on disk is the buffer now
+            LeaveAction::Reload => {}
+        }
+    });
+}
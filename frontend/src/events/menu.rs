@@ -31,6 +31,18 @@ pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_e
                 state.focus = Pane::ContainerList;
                 refresh::refresh_pane(Pane::ContainerList, state_rc);
             }
+            "Images" => {
+                state.focus = Pane::Images;
+                refresh::refresh_pane(Pane::Images, state_rc);
+            }
+            "Volumes" => {
+                state.focus = Pane::Volumes;
+                refresh::refresh_pane(Pane::Volumes, state_rc);
+            }
+            "Networks" => {
+                state.focus = Pane::Networks;
+                refresh::refresh_pane(Pane::Networks, state_rc);
+            }
             _ => {}
         }
     }
@@ -38,21 +50,54 @@ pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_e
 
 pub fn save_file(state: Rc<RefCell<AppState>>, filename: String, content: String) {
     spawn_local(async move {
-        match api::save_file_content(&filename, content.clone()).await {
-            Ok(_) => {
-                {
-                    let mut st = state.borrow_mut();
-                    st.editor.original_content = content;
-                    st.dirty = false;
-                }
-                status_helper::set_status_timed(&state, format!("Saved: {}", filename));
-            }
-            Err(e) => {
-                status_helper::set_status_timed(
-                    &state,
-                    format!("[ERROR saving: {}]", utils::error::format_error(&e)),
-                );
+        write_file(&state, filename, content).await;
+    });
+}
+
+/// Write a file and report it in the status line; true when it was saved
+pub async fn write_file(state: &Rc<RefCell<AppState>>, filename: String, content: String) -> bool {
+    // Read inside the task so callers may still hold a borrow
+    let new_file = state.borrow().editor.new_file;
+    let result = if new_file {
+        api::create_file_content(&filename, content.clone()).await
+    } else {
+        api::save_file_content(&filename, content.clone()).await
+    };
+    match result {
+        Ok(_) => {
+            if new_file {
+                // List the created file
+                refresh::refresh_pane(Pane::FileList, state);
             }
+            let message = {
+                let mut st = state.borrow_mut();
+                st.mark_saved(&filename, content);
+                // Offer to apply saved compose files or restart containers using the file
+                let mut hints = Vec::new();
+                if st.current_compose_project().is_some() {
+                    hints.push(format!("{}: apply", st.keybinds.global.apply_compose));
+                }
+                if !st.current_file_users().is_empty() {
+                    hints.push(format!(
+                        "{}: restart users",
+                        st.keybinds.global.restart_users
+                    ));
+                }
+                if hints.is_empty() {
+                    format!("Saved: {}", filename)
+                } else {
+                    format!("Saved: {} ({})", filename, hints.join(", "))
+                }
+            };
+            status_helper::set_status_timed(state, message);
+            true
         }
-    });
+        Err(e) => {
+            status_helper::set_status_timed(
+                state,
+                format!("[ERROR saving: {}]", utils::error::format_error(&e)),
+            );
+            false
+        }
+    }
 }
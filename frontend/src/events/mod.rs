@@ -1,9 +1,17 @@
+mod buffers;
+mod compose_apply;
 mod container_list;
 mod editor;
 mod file_list;
+mod images;
+mod leave_prompt;
 mod menu;
+mod mount_users;
+mod resources;
+mod save_review;
+mod terminal;
 
-use crate::state::{AppState, Pane};
+use crate::state::{AppState, Pane, VimMode};
 use ratzilla::event::{KeyCode, KeyEvent};
 use std::{cell::RefCell, rc::Rc};
 
@@ -32,7 +40,10 @@ pub fn key_matches(event: &KeyEvent, binding: &str) -> bool {
         return match_key_without_mods(event, stripped);
     }
 
-    // No modifiers
+    // No modifiers: Alt/Ctrl + the key is a different binding (Alt-K is not k)
+    if event.ctrl || event.alt {
+        return false;
+    }
     match_key_without_mods(event, binding)
 }
 
@@ -44,6 +55,9 @@ pub fn match_key_without_mods(event: &KeyEvent, key_str: &str) -> bool {
         "Tab" => event.code == KeyCode::Tab,
         "Backspace" => event.code == KeyCode::Backspace,
         "Delete" => event.code == KeyCode::Delete,
+        "Space" => event.code == KeyCode::Char(' '),
+        "PageUp" => event.code == KeyCode::PageUp,
+        "PageDown" => event.code == KeyCode::PageDown,
         "Left" => event.code == KeyCode::Left,
         "Right" => event.code == KeyCode::Right,
         "Up" => event.code == KeyCode::Up,
@@ -68,17 +82,42 @@ pub fn match_key_without_mods(event: &KeyEvent, key_str: &str) -> bool {
 pub fn handle_key_event(state: Rc<RefCell<AppState>>, key_event: KeyEvent) {
     let mut state_mut = state.borrow_mut();
 
+    // The shell gets all keys (including global ones) until detached
+    if state_mut.focus == Pane::Terminal {
+        terminal::handle_keys(&mut state_mut, key_event);
+        return;
+    }
+
+    // So does the command line until it is run or cancelled
+    if state_mut.focus == Pane::Editor && state_mut.vim_mode == VimMode::Command {
+        editor::handle_keys(&mut state_mut, &state, key_event);
+        state_mut.save_to_storage();
+        return;
+    }
+
+    // So does the save/discard/cancel question about unsaved changes
+    if leave_prompt::handle_keys(&mut state_mut, &state, &key_event) {
+        state_mut.save_to_storage();
+        return;
+    }
+
+    // The diff review takes all keys but the save key while open
+    if save_review::handle_keys(&mut state_mut, &key_event) {
+        return;
+    }
+
+    // Switch between open files
+    if buffers::handle_keys(&mut state_mut, &key_event) {
+        state_mut.save_to_storage();
+        return;
+    }
+
     // Global keybindings (work in any pane/mode)
     let keybinds = &state_mut.keybinds.global;
 
-    // Save file
+    // Save file (or show the diff first with `reviewsave`)
     if key_matches(&key_event, &keybinds.save) {
-        if let Some(filename) = state_mut.editor.current_file.clone() {
-            let content = state_mut.editor.get_content();
-            drop(state_mut); // Release borrow before async
-
-            menu::save_file(state, filename, content);
-        }
+        save_review::save(&mut state_mut, &state);
         return;
     }
 
@@ -91,6 +130,22 @@ pub fn handle_key_event(state: Rc<RefCell<AppState>>, key_event: KeyEvent) {
         return;
     }
 
+    // Apply compose file changes (validate + plan, then compose up)
+    if key_matches(&key_event, &keybinds.apply_compose)
+        && matches!(state_mut.focus, Pane::FileList | Pane::Editor)
+    {
+        compose_apply::apply(&mut state_mut, &state);
+        return;
+    }
+
+    // Restart containers mounting the open file
+    if key_matches(&key_event, &keybinds.restart_users)
+        && matches!(state_mut.focus, Pane::FileList | Pane::Editor)
+    {
+        mount_users::restart_users(&mut state_mut, &state);
+        return;
+    }
+
     // Focus file list (only from FileList or Editor)
     if key_matches(&key_event, &keybinds.back_to_files)
         && matches!(state_mut.focus, Pane::FileList | Pane::Editor)
@@ -106,16 +161,24 @@ pub fn handle_key_event(state: Rc<RefCell<AppState>>, key_event: KeyEvent) {
         && matches!(state_mut.focus, Pane::FileList | Pane::Editor)
     {
         state_mut.focus = Pane::Editor;
-        state_mut.vim_mode = crate::state::VimMode::Normal;
+        state_mut.vim_mode = VimMode::Normal;
         state_mut.save_to_storage();
         return;
     }
 
+    // Esc closes the compose apply panel
+    if compose_apply::handle_keys(&mut state_mut, &key_event) {
+        return;
+    }
+
     match state_mut.focus {
         Pane::Menu => menu::handle_keys(&mut state_mut, &state, key_event),
         Pane::FileList => file_list::handle_keys(&mut state_mut, &state, key_event),
-        Pane::Editor => editor::handle_keys(&mut state_mut, key_event),
+        Pane::Editor => editor::handle_keys(&mut state_mut, &state, key_event),
         Pane::ContainerList => container_list::handle_keys(&mut state_mut, &state, key_event),
+        Pane::Images => images::handle_keys(&mut state_mut, &state, key_event),
+        Pane::Volumes | Pane::Networks => resources::handle_keys(&mut state_mut, &state, key_event),
+        Pane::Terminal => {}
     }
 
     // Save state after any key event
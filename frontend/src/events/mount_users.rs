@@ -0,0 +1,50 @@
+use crate::{
+    api,
+    state::{AppState, Pane, follow_job, refresh, status_helper},
+    utils,
+};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+/// Restart every container that bind-mounts the file open in the editor
+pub fn restart_users(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let users = state.current_file_users();
+    if users.is_empty() {
+        state.set_status("No containers mount this file");
+        return;
+    }
+
+    if state.dirty {
+        state.set_status("Save the file before restarting");
+        return;
+    }
+
+    state.set_status(format!("Restarting {} container(s)...", users.len()));
+
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let mut failed = Vec::new();
+        for user in &users {
+            let result = match api::restart_container(&user.id).await {
+                Ok(job) => follow_job(&state_clone, job).await,
+                Err(e) => Err(utils::error::format_error(&e)),
+            };
+            if let Err(error) = result {
+                failed.push(format!("{}: {}", user.name, error));
+            }
+        }
+
+        let message = if failed.is_empty() {
+            format!("Restarted {} container(s)", users.len())
+        } else {
+            format!(
+                "[ERROR restarting {}/{}: {}]",
+                failed.len(),
+                users.len(),
+                failed.join("; ")
+            )
+        };
+        status_helper::set_status_timed(&state_clone, message);
+        refresh::refresh_pane(Pane::ContainerList, &state_clone);
+    });
+}
@@ -0,0 +1,112 @@
+use crate::{
+    api,
+    keybinds::ResourceKeybinds,
+    state::{AppState, Pane, Resource, ResourceConfirm, ResourceListState, refresh, status_helper},
+    utils,
+};
+use ratzilla::event::{KeyCode, KeyEvent};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+
+/// Outcome of a key press in a volume or network list
+enum ListAction {
+    Back,
+    Confirmed(ResourceConfirm),
+    Status(String),
+}
+
+/// Keys for the volume and network panes
+pub fn handle_keys(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>, key_event: KeyEvent) {
+    let pane = state.focus;
+    let keybinds = &state.keybinds.resources;
+    let action = match pane {
+        Pane::Volumes => handle_list(&mut state.volumes, keybinds, &key_event),
+        Pane::Networks => handle_list(&mut state.networks, keybinds, &key_event),
+        _ => None,
+    };
+
+    match action {
+        Some(ListAction::Back) => state.focus = Pane::Menu,
+        Some(ListAction::Confirmed(pending)) => confirm(state, state_rc, pane, pending),
+        Some(ListAction::Status(message)) => state.set_status(message),
+        None => {}
+    }
+}
+
+fn handle_list<T: Resource>(
+    list: &mut ResourceListState<T>,
+    keybinds: &ResourceKeybinds,
+    key_event: &KeyEvent,
+) -> Option<ListAction> {
+    // A pending confirmation takes all keys
+    if list.pending.is_some() {
+        return match key_event.code {
+            KeyCode::Char('y') | KeyCode::Enter => list.pending.take().map(ListAction::Confirmed),
+            KeyCode::Char('n') | KeyCode::Esc => {
+                list.pending = None;
+                Some(ListAction::Status("Cancelled".to_string()))
+            }
+            _ => None,
+        };
+    }
+
+    if super::key_matches(key_event, &keybinds.navigate_down)
+        || super::key_matches(key_event, &keybinds.navigate_down_alt)
+    {
+        list.next();
+    } else if super::key_matches(key_event, &keybinds.navigate_up)
+        || super::key_matches(key_event, &keybinds.navigate_up_alt)
+    {
+        list.previous();
+    } else if super::key_matches(key_event, &keybinds.remove) {
+        if let Err(reason) = list.request_remove() {
+            return Some(ListAction::Status(reason));
+        }
+    } else if super::key_matches(key_event, &keybinds.prune) {
+        list.pending = Some(ResourceConfirm::Prune);
+    } else if super::key_matches(key_event, &keybinds.back_to_menu) {
+        return Some(ListAction::Back);
+    }
+
+    None
+}
+
+fn confirm(
+    state: &mut AppState,
+    state_rc: &Rc<RefCell<AppState>>,
+    pane: Pane,
+    pending: ResourceConfirm,
+) {
+    let (progress, label) = match (&pending, pane) {
+        (ResourceConfirm::Remove { label, .. }, _) => {
+            (format!("Removing {}...", label), Some(label.clone()))
+        }
+        (ResourceConfirm::Prune, Pane::Volumes) => ("Pruning volumes...".to_string(), None),
+        (ResourceConfirm::Prune, _) => ("Pruning networks...".to_string(), None),
+    };
+    state.set_status(progress);
+
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let result: Result<String, JsValue> = match (pending, pane) {
+            (ResourceConfirm::Remove { key, .. }, Pane::Volumes) => api::remove_volume(&key).await,
+            (ResourceConfirm::Remove { key, .. }, _) => api::remove_network(&key).await,
+            (ResourceConfirm::Prune, Pane::Volumes) => api::prune_volumes().await,
+            (ResourceConfirm::Prune, _) => api::prune_networks().await,
+        };
+
+        let message = match (result, label) {
+            (Ok(msg), Some(label)) => format!("{}: {}", label, msg),
+            (Ok(msg), None) => msg,
+            (Err(e), Some(label)) => format!(
+                "[ERROR removing {}: {}]",
+                label,
+                utils::error::format_error(&e)
+            ),
+            (Err(e), None) => format!("[ERROR pruning: {}]", utils::error::format_error(&e)),
+        };
+        status_helper::set_status_timed(&state_clone, message);
+        refresh::refresh_pane(pane, &state_clone);
+    });
+}
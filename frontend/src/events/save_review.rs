@@ -0,0 +1,140 @@
+use super::{key_matches, menu};
+use crate::state::{AppState, Pane, SaveReview, VimMode};
+use ratzilla::event::KeyEvent;
+use std::{cell::RefCell, rc::Rc};
+use tui_textarea::{CursorMove, TextArea};
+
+/// Save key: write the file, or show the diff first when `reviewsave` is on
+/// (pressing it again in the review writes)
+pub fn save(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let Some(filename) = state.editor.current_file.clone() else {
+        return;
+    };
+    if state.save_review.is_none() && state.editor.options.review_save && state.dirty {
+        let content = state.editor.get_content();
+        state.save_review = Some(SaveReview::new(
+            filename,
+            &state.editor.original_content,
+            &content,
+            false,
+        ));
+        state.focus = Pane::Editor;
+        state.vim_mode = VimMode::Normal;
+        return;
+    }
+
+    state.save_review = None;
+    menu::save_file(Rc::clone(state_rc), filename, state.editor.get_content());
+}
+
+/// Hunk navigation, scrolling, reverting and layout while the review is open
+/// (the save key is left to `save`)
+pub fn handle_keys(state: &mut AppState, key_event: &KeyEvent) -> bool {
+    if key_matches(key_event, &state.keybinds.global.save) {
+        return false;
+    }
+    let Some(review) = state.save_review.as_mut() else {
+        return false;
+    };
+    let keys = &state.keybinds.review;
+    let last_hunk = review.hunks.len().saturating_sub(1);
+
+    if key_matches(key_event, &keys.next_hunk) {
+        review.current = (review.current + 1).min(last_hunk);
+        review.scroll = review.hunk_row(review.current);
+    } else if key_matches(key_event, &keys.previous_hunk) {
+        review.current = review.current.saturating_sub(1);
+        review.scroll = review.hunk_row(review.current);
+    } else if key_matches(key_event, &keys.scroll_down) {
+        review.scroll = (review.scroll + 1).min(review.rows.len().saturating_sub(1));
+    } else if key_matches(key_event, &keys.scroll_up) {
+        review.scroll = review.scroll.saturating_sub(1);
+    } else if key_matches(key_event, &keys.toggle_layout) {
+        review.side_by_side = !review.side_by_side;
+    } else if key_matches(key_event, &keys.revert_hunk) {
+        revert_hunk(state);
+    } else if key_matches(key_event, &keys.cancel) {
+        state.save_review = None;
+    }
+    true
+}
+
+/// Put the saved lines of the current hunk back into the buffer
+fn revert_hunk(state: &mut AppState) {
+    let Some(review) = &state.save_review else {
+        return;
+    };
+    let Some(hunk) = review.hunks.get(review.current).cloned() else {
+        return;
+    };
+    let header = review.header(review.current);
+
+    replace_lines(
+        &mut state.editor.textarea,
+        hunk.new_start,
+        hunk.new_lines.len(),
+        &hunk.old_lines,
+    );
+    state.check_dirty();
+    state.editor.validate();
+
+    if !state.dirty {
+        state.save_review = None;
+        state.set_status("All changes reverted, nothing to save");
+        return;
+    }
+    let content = state.editor.get_content();
+    if let Some(review) = state.save_review.as_mut() {
+        review.update(&state.editor.original_content, &content);
+    }
+    state.set_status(format!("Reverted {}", header));
+}
+
+/// Replace `count` lines from `start` with `lines` as undoable edits
+fn replace_lines(textarea: &mut TextArea, start: usize, count: usize, lines: &[String]) {
+    let total = textarea.lines().len();
+    let line_len = |textarea: &TextArea, row: usize| textarea.lines()[row].chars().count();
+    let jump = |textarea: &mut TextArea, (row, col): (usize, usize)| {
+        textarea.move_cursor(CursorMove::Jump(row as u16, col as u16));
+    };
+    let select = |textarea: &mut TextArea, from: (usize, usize), to: (usize, usize)| {
+        textarea.cancel_selection();
+        jump(textarea, from);
+        textarea.start_selection();
+        jump(textarea, to);
+    };
+    let text = lines.join("\n");
+
+    if count == 0 {
+        // Lines were deleted: insert them before `start` (or after the last line)
+        textarea.cancel_selection();
+        if start < total {
+            jump(textarea, (start, 0));
+            textarea.insert_str(text + "\n");
+        } else {
+            let last = total - 1;
+            jump(textarea, (last, line_len(textarea, last)));
+            textarea.insert_str(format!("\n{}", text));
+        }
+    } else {
+        let end = start + count - 1;
+        let end_len = line_len(textarea, end);
+        if !lines.is_empty() {
+            select(textarea, (start, 0), (end, end_len));
+            textarea.cut();
+            textarea.insert_str(text);
+        } else if end + 1 < total {
+            // Lines were added: remove them with their line breaks
+            select(textarea, (start, 0), (end + 1, 0));
+            textarea.cut();
+        } else if start > 0 {
+            let before = line_len(textarea, start - 1);
+            select(textarea, (start - 1, before), (end, end_len));
+            textarea.cut();
+        } else {
+            select(textarea, (0, 0), (end, end_len));
+            textarea.cut();
+        }
+    }
+    jump(textarea, (start.min(textarea.lines().len() - 1), 0));
+}
@@ -0,0 +1,120 @@
+use crate::{
+    api,
+    state::{AppState, Pane, TerminalState},
+};
+use ratzilla::event::{KeyCode, KeyEvent};
+use std::{cell::RefCell, rc::Rc};
+
+/// Initial PTY size until the terminal pane has been rendered
+const INITIAL_SIZE: (u16, u16) = (80, 24);
+
+/// Open a shell in the selected container
+pub fn open(state: &mut AppState, state_rc: &Rc<RefCell<AppState>>) {
+    let Some(container) = state.container_list._selected() else {
+        return;
+    };
+    if container.state != "running" {
+        state.set_status(format!("{} is not running", container.name));
+        return;
+    }
+
+    let (cols, rows) = state
+        .terminal
+        .as_ref()
+        .map(|t| t.size())
+        .unwrap_or(INITIAL_SIZE);
+    let mut terminal = TerminalState::new(container.id.clone(), container.name.clone(), cols, rows);
+
+    let output_state = Rc::clone(state_rc);
+    let close_state = Rc::clone(state_rc);
+    let session = api::open_exec(
+        &terminal.container_id,
+        cols,
+        rows,
+        move |data| {
+            if let Some(terminal) = &output_state.borrow().terminal {
+                terminal.process(data);
+            }
+        },
+        move |reason| {
+            let mut st = close_state.borrow_mut();
+            if let Some(terminal) = st.terminal.as_mut() {
+                terminal.closed = Some(reason.clone());
+            }
+            st.set_status(format!("Shell closed: {}", reason));
+        },
+    );
+
+    match session {
+        Ok(session) => {
+            terminal.session = Some(session);
+            state.terminal = Some(terminal);
+            state.focus = Pane::Terminal;
+            state.set_status(format!(
+                "Shell in {} ({}: detach)",
+                container.name, state.keybinds.terminal.detach
+            ));
+        }
+        Err(e) => {
+            state.set_status(format!(
+                "[ERROR opening shell: {}]",
+                crate::utils::error::format_error(&e)
+            ));
+        }
+    }
+}
+
+/// Forward keys to the shell; the detach key closes the session
+pub fn handle_keys(state: &mut AppState, key_event: KeyEvent) {
+    let ended = state.terminal.as_ref().is_none_or(|t| t.closed.is_some());
+
+    if super::key_matches(&key_event, &state.keybinds.terminal.detach)
+        || (ended && key_event.code == KeyCode::Esc)
+    {
+        // Dropping the session closes the socket and ends the exec
+        state.terminal = None;
+        state.focus = Pane::ContainerList;
+        return;
+    }
+
+    if let Some(session) = state.terminal.as_ref().and_then(|t| t.session.as_ref())
+        && let Some(bytes) = key_bytes(&key_event)
+    {
+        session.send_input(&bytes);
+    }
+}
+
+/// Translate a key event into the bytes a terminal would send
+fn key_bytes(event: &KeyEvent) -> Option<Vec<u8>> {
+    let bytes: Vec<u8> = match event.code {
+        KeyCode::Char(c) if event.ctrl && c.is_ascii_alphabetic() => {
+            vec![(c.to_ascii_lowercase() as u8) & 0x1f]
+        }
+        KeyCode::Char(c) => {
+            let mut buf = [0u8; 4];
+            c.encode_utf8(&mut buf).as_bytes().to_vec()
+        }
+        KeyCode::Enter => b"\r".to_vec(),
+        KeyCode::Tab => b"\t".to_vec(),
+        KeyCode::Backspace => vec![0x7f],
+        KeyCode::Esc => vec![0x1b],
+        KeyCode::Up => b"\x1b[A".to_vec(),
+        KeyCode::Down => b"\x1b[B".to_vec(),
+        KeyCode::Right => b"\x1b[C".to_vec(),
+        KeyCode::Left => b"\x1b[D".to_vec(),
+        KeyCode::Home => b"\x1b[H".to_vec(),
+        KeyCode::End => b"\x1b[F".to_vec(),
+        KeyCode::PageUp => b"\x1b[5~".to_vec(),
+        KeyCode::PageDown => b"\x1b[6~".to_vec(),
+        KeyCode::Delete => b"\x1b[3~".to_vec(),
+        KeyCode::F(_) | KeyCode::Unidentified => return None,
+    };
+
+    // Alt sends an ESC prefix
+    if event.alt {
+        let mut prefixed = vec![0x1b];
+        prefixed.extend(bytes);
+        return Some(prefixed);
+    }
+    Some(bytes)
+}
@@ -6,7 +6,7 @@ use crate::{
 use std::{cell::RefCell, rc::Rc};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
-use web_sys::window;
+use web_sys::{BeforeUnloadEvent, window};
 
 /// Initialize theme in DOM (background color + font)
 pub fn setup_theme(app_state: &Rc<RefCell<AppState>>) {
@@ -34,6 +34,29 @@ pub fn setup_theme(app_state: &Rc<RefCell<AppState>>) {
     }
 }
 
+/// Ask before leaving the page while open files have unsaved changes (they
+/// are also kept in localStorage and reopened on the next load)
+pub fn guard_unload(app_state: &Rc<RefCell<AppState>>) {
+    let state = Rc::clone(app_state);
+    let on_unload =
+        Closure::<dyn FnMut(BeforeUnloadEvent)>::new(move |event: BeforeUnloadEvent| {
+            if let Ok(st) = state.try_borrow()
+                && st.has_unsaved_changes()
+            {
+                st.save_unsaved_buffers();
+                event.prevent_default();
+                // Older browsers only ask when a return value is set
+                event.set_return_value("unsaved");
+            }
+        });
+    if let Some(win) = window() {
+        let _ = win
+            .add_event_listener_with_callback("beforeunload", on_unload.as_ref().unchecked_ref());
+    }
+    // The listener lives as long as the page
+    on_unload.forget();
+}
+
 /// Load cached data from browser storage
 pub fn load_cache(app_state: &mut AppState) {
     crate::state::refresh::load_pane_cache(Pane::FileList, app_state);
@@ -52,7 +75,7 @@ pub fn load_pane_data(app_state: &Rc<RefCell<AppState>>) {
             let state_clone = Rc::clone(app_state);
             spawn_local(async move {
                 match api::fetch_file_list().await {
-                    Ok(files) => {
+                    Ok((files, containers)) => {
                         {
                             let mut st = state_clone.borrow_mut();
                             // Only save to cache if data changed
@@ -60,11 +83,10 @@ pub fn load_pane_data(app_state: &Rc<RefCell<AppState>>) {
                                 storage::generic::save("file-list", &files);
                             }
                             st.file_list.set_files(files);
+                            st.file_list.set_containers(containers);
                         }
-                        crate::state::status_helper::set_status_timed(
-                            &state_clone,
-                            "Restored session",
-                        );
+                        let message = restored_message(&state_clone.borrow(), "Restored session");
+                        crate::state::status_helper::set_status_timed(&state_clone, message);
                     }
                     Err(e) => {
                         storage::generic::clear("file-list");
@@ -76,14 +98,33 @@ pub fn load_pane_data(app_state: &Rc<RefCell<AppState>>) {
                 }
             });
         }
-        Pane::ContainerList => {
+        Pane::ContainerList | Pane::Terminal => {
             // Load container list if we restored to ContainerList
             crate::state::refresh::refresh_pane(Pane::ContainerList, app_state);
-            crate::state::status_helper::set_status_timed(app_state, "Restored session");
+            let message = restored_message(&app_state.borrow(), "Restored session");
+            crate::state::status_helper::set_status_timed(app_state, message);
+        }
+        Pane::Images | Pane::Volumes | Pane::Networks => {
+            crate::state::refresh::refresh_pane(current_pane, app_state);
         }
         Pane::Menu => {
             let mut state = app_state.borrow_mut();
-            state.set_status("Welcome to Config Manager");
+            let message = restored_message(&state, "Welcome to Config Manager");
+            state.set_status(message);
         }
     }
 }
+
+/// Startup message, mentioning files reopened with unsaved changes
+fn restored_message(state: &AppState, message: &str) -> String {
+    let unsaved = state
+        .open_buffers()
+        .iter()
+        .filter(|(_, dirty)| *dirty)
+        .count();
+    match unsaved {
+        0 => message.to_string(),
+        1 => format!("{} (recovered 1 file with unsaved changes)", message),
+        n => format!("{} (recovered {} files with unsaved changes)", message, n),
+    }
+}
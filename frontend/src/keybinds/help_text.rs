@@ -18,22 +18,99 @@ impl FileListKeybinds {
 impl ContainerListKeybinds {
     pub fn help_text(&self, _global: &GlobalKeybinds) -> String {
         format!(
-            "{},{}/{},{}:navigate {}:start {}:stop {}:restart {}:menu",
+            "{},{}/{},{}:navigate {}:filter {}:sort {}:running only {}:start {}:stop {}:restart {}:mark {}:mark all {}:mark same state {}:clear marks {}:pause {}:kill {}:remove {}:recreate (compose) {}:settings {}:to compose {}:group {}/{}:up/down {}:raw {}:menu",
             self.navigate_down,
             self.navigate_down_alt,
             self.navigate_up,
             self.navigate_up_alt,
+            self.filter,
+            self.cycle_sort,
+            self.toggle_running_only,
             self.start_container,
             self.stop_container,
             self.restart_container,
+            self.toggle_mark,
+            self.mark_all,
+            self.mark_same_state,
+            self.clear_marks,
+            self.pause_container,
+            self.kill_container,
+            self.remove_container,
+            self.recreate_container,
+            self.edit_settings,
+            self.export_compose,
+            self.toggle_group,
+            self.compose_up,
+            self.compose_down,
+            self.toggle_raw_inspect,
             self.back_to_menu
         )
     }
 }
 
+impl ImageKeybinds {
+    pub fn help_text(&self) -> String {
+        format!(
+            "{},{}/{},{}:navigate {}:inspect {}:remove {}:prune dangling {}:menu",
+            self.navigate_down,
+            self.navigate_down_alt,
+            self.navigate_up,
+            self.navigate_up_alt,
+            self.inspect,
+            self.remove_image,
+            self.prune_images,
+            self.back_to_menu
+        )
+    }
+}
+
+impl ResourceKeybinds {
+    pub fn help_text(&self) -> String {
+        format!(
+            "{},{}/{},{}:navigate {}:remove {}:prune unused {}:menu",
+            self.navigate_down,
+            self.navigate_down_alt,
+            self.navigate_up,
+            self.navigate_up_alt,
+            self.remove,
+            self.prune,
+            self.back_to_menu
+        )
+    }
+}
+
+impl TerminalKeybinds {
+    pub fn help_text(&self) -> String {
+        format!("{}:detach (all other keys go to the shell)", self.detach)
+    }
+}
+
+impl ReviewKeybinds {
+    pub fn help_text(&self, global: &GlobalKeybinds) -> String {
+        format!(
+            "{}/{}:next/previous hunk {}/{}:scroll {}:revert hunk {}:unified/side by side {}:write {}:back to editor",
+            self.next_hunk,
+            self.previous_hunk,
+            self.scroll_down,
+            self.scroll_up,
+            self.revert_hunk,
+            self.toggle_layout,
+            global.save,
+            self.cancel
+        )
+    }
+}
+
 impl GlobalKeybinds {
     pub fn editor_normal_help_text(&self) -> String {
-        format!("i:insert {}:save {}:files", self.save, self.back_to_files)
+        format!(
+            "i:insert v/V:visual d/c/y{{motion}} p:paste .:repeat /:search :cmd {}:save {}/{}:buffers {}:files",
+            self.save, self.next_buffer, self.previous_buffer, self.back_to_files
+        )
+    }
+
+    pub fn editor_visual_help_text(&self) -> String {
+        "y:yank d:delete c:change p:paste \"x:register :cmd ESC:normal".to_string()
     }
 
     pub fn editor_insert_help_text(&self) -> String {
@@ -5,6 +5,10 @@ pub struct Keybinds {
     pub menu: MenuKeybinds,
     pub file_list: FileListKeybinds,
     pub container_list: ContainerListKeybinds,
+    pub images: ImageKeybinds,
+    pub resources: ResourceKeybinds,
+    pub terminal: TerminalKeybinds,
+    pub review: ReviewKeybinds,
     pub global: GlobalKeybinds,
 }
 
@@ -34,15 +38,81 @@ pub struct ContainerListKeybinds {
     pub navigate_down_alt: String,
     pub navigate_up: String,
     pub navigate_up_alt: String,
+    pub filter: String,
+    pub cycle_sort: String,
+    pub toggle_running_only: String,
     pub start_container: String,
     pub stop_container: String,
     pub restart_container: String,
+    pub toggle_mark: String,
+    pub mark_all: String,
+    pub mark_same_state: String,
+    pub clear_marks: String,
+    pub toggle_group: String,
+    pub compose_up: String,
+    pub compose_down: String,
+    pub pause_container: String,
+    pub kill_container: String,
+    pub remove_container: String,
+    pub recreate_container: String,
+    pub edit_settings: String,
+    pub export_compose: String,
+    pub exec_shell: String,
+    pub toggle_raw_inspect: String,
+    pub scroll_details_down: String,
+    pub scroll_details_up: String,
     pub back_to_menu: String,
 }
 
+#[derive(Deserialize)]
+pub struct ImageKeybinds {
+    pub navigate_down: String,
+    pub navigate_down_alt: String,
+    pub navigate_up: String,
+    pub navigate_up_alt: String,
+    pub inspect: String,
+    pub remove_image: String,
+    pub prune_images: String,
+    pub back_to_menu: String,
+}
+
+/// Volume and network panes
+#[derive(Deserialize)]
+pub struct ResourceKeybinds {
+    pub navigate_down: String,
+    pub navigate_down_alt: String,
+    pub navigate_up: String,
+    pub navigate_up_alt: String,
+    pub remove: String,
+    pub prune: String,
+    pub back_to_menu: String,
+}
+
+#[derive(Deserialize)]
+pub struct TerminalKeybinds {
+    pub detach: String,
+}
+
+/// Diff review before saving (the save key writes the file)
+#[derive(Deserialize)]
+pub struct ReviewKeybinds {
+    pub next_hunk: String,
+    pub previous_hunk: String,
+    pub scroll_down: String,
+    pub scroll_up: String,
+    pub revert_hunk: String,
+    pub toggle_layout: String,
+    pub cancel: String,
+}
+
 #[derive(Deserialize)]
 pub struct GlobalKeybinds {
     pub save: String,
     pub back_to_files: String,
     pub cycle_theme: String,
+    pub apply_compose: String,
+    pub restart_users: String,
+    pub next_buffer: String,
+    pub previous_buffer: String,
+    pub switch_split: String,
 }
@@ -5,9 +5,11 @@ mod init;
 mod keybinds;
 mod state;
 mod storage;
+mod syntax;
 mod theme;
 mod ui;
 mod utils;
+mod validate;
 
 use ratzilla::{DomBackend, WebRenderer};
 use state::AppState;
@@ -31,6 +33,9 @@ pub fn main() -> Result<(), JsValue> {
     // Load cached lists from storage
     init::load_cache(&mut app_state.borrow_mut());
 
+    // Ask before closing the page with unsaved changes
+    init::guard_unload(&app_state);
+
     // Initialize Ratzilla backend and terminal
     let backend = DomBackend::new().map_err(|e| JsValue::from_str(&e.to_string()))?;
     let terminal =
@@ -1,11 +1,18 @@
-use super::{ContainerListState, EditorState, FileListState, MenuState, Pane, VimMode, refresh};
+use super::{
+    CommandLine, ComposeApplyState, ContainerListState, EditorState, FileListState, ImageListState,
+    JobsState, LeavePrompt, MenuState, Pane, Registers, ResourceListState, SaveReview,
+    TerminalState, UnsavedBuffer, VimMode, refresh,
+};
 use crate::{
-    api::ContainerDetails,
+    api::{ContainerDetails, FileInfo, MountUser, NetworkInfo, VolumeInfo},
     keybinds::Keybinds,
     storage,
     theme::{ThemeConfig, load_current_theme},
 };
 
+/// localStorage key of the buffers with unsaved changes
+const UNSAVED_BUFFERS_KEY: &str = "unsaved-buffers";
+
 pub struct AppState {
     pub focus: Pane,
     pub vim_mode: VimMode,
@@ -14,6 +21,22 @@ pub struct AppState {
     pub container_list: ContainerListState,
     pub container_details: Option<ContainerDetails>,
     pub editor: EditorState,
+    /// Yank/delete registers (shared by all files)
+    pub registers: Registers,
+    /// `:` command line (input and history)
+    pub command_line: CommandLine,
+    pub compose_apply: Option<ComposeApplyState>,
+    /// Diff shown before saving (replaces the editor while open)
+    pub save_review: Option<SaveReview>,
+    /// Question before closing or reloading a buffer with unsaved changes
+    pub leave_prompt: Option<LeavePrompt>,
+    /// Shell session opened from the container list
+    pub terminal: Option<TerminalState>,
+    pub images: ImageListState,
+    pub volumes: ResourceListState<VolumeInfo>,
+    pub networks: ResourceListState<NetworkInfo>,
+    /// Background container operations being followed
+    pub jobs: JobsState,
     pub dirty: bool,
     pub status_message: Option<String>,
     pub keybinds: Keybinds,
@@ -30,12 +53,33 @@ impl AppState {
             container_list: ContainerListState::new(),
             container_details: None,
             editor: EditorState::new(),
+            registers: Registers::new(),
+            command_line: CommandLine::new(),
+            compose_apply: None,
+            save_review: None,
+            leave_prompt: None,
+            terminal: None,
+            images: ImageListState::new(),
+            volumes: ResourceListState::new(),
+            networks: ResourceListState::new(),
+            jobs: JobsState::new(),
             dirty: false,
             status_message: None,
             keybinds: Keybinds::load(),
             current_theme: load_current_theme(),
         };
 
+        // Buffers with unsaved changes come back as they were left
+        let unsaved: Vec<UnsavedBuffer> =
+            storage::generic::load(UNSAVED_BUFFERS_KEY).unwrap_or_default();
+        for buffer in unsaved {
+            state.add_buffer(&buffer.name);
+            state.editor.load_content(buffer.name, buffer.content);
+            state.editor.original_content = buffer.original_content;
+            state.editor.new_file = buffer.new_file;
+            state.check_dirty();
+        }
+
         // Try to restore from localStorage
         if let Some(saved) = storage::load_state()
             && let Some(pane) = Pane::from_str(&saved.pane)
@@ -46,8 +90,14 @@ impl AppState {
             if pane == Pane::Editor
                 && let (Some(filename), Some(content)) = (saved.filename, saved.content)
             {
-                state.editor.load_content(filename, content);
-                state.dirty = false;
+                match state.editor.buffer_index(&filename) {
+                    Some(index) => state.switch_buffer(index),
+                    None => {
+                        state.add_buffer(&filename);
+                        state.editor.load_content(filename, content);
+                        state.dirty = false;
+                    }
+                }
             }
         }
 
@@ -63,11 +113,30 @@ impl AppState {
         };
 
         storage::save_state(self.focus.as_str(), filename, content.as_deref());
+        self.save_unsaved_buffers();
 
         // Also save current selection for lists
         refresh::save_selection(self.focus, self);
     }
 
+    /// Metadata of the file open in the editor
+    fn current_file_info(&self) -> Option<&FileInfo> {
+        let current = self.editor.current_file.as_ref()?;
+        self.file_list.files.iter().find(|f| &f.name == current)
+    }
+
+    /// Compose project of the file open in the editor (if it is a compose file)
+    pub fn current_compose_project(&self) -> Option<String> {
+        self.current_file_info()?.compose_project.clone()
+    }
+
+    /// Containers that bind-mount the file open in the editor
+    pub fn current_file_users(&self) -> Vec<MountUser> {
+        self.current_file_info()
+            .map(|f| f.used_by.clone())
+            .unwrap_or_default()
+    }
+
     pub fn set_status(&mut self, message: impl Into<String>) {
         self.status_message = Some(message.into());
     }
@@ -82,6 +151,130 @@ impl AppState {
         self.dirty = current_content != self.editor.original_content;
     }
 
+    /// Some open file has changes that aren't saved
+    pub fn has_unsaved_changes(&self) -> bool {
+        self.dirty || self.editor.buffers.iter().any(|b| b.dirty)
+    }
+
+    /// Keep the buffers with unsaved changes in localStorage for recovery
+    pub fn save_unsaved_buffers(&self) {
+        let mut unsaved: Vec<UnsavedBuffer> = self
+            .editor
+            .buffers
+            .iter()
+            .filter(|b| b.dirty)
+            .map(|b| UnsavedBuffer {
+                name: b.name.clone(),
+                content: b.textarea.lines().join("\n"),
+                original_content: b.original_content.clone(),
+                new_file: b.new_file,
+            })
+            .collect();
+        if let Some(current) = self.editor.current_file.as_ref().filter(|_| self.dirty) {
+            unsaved.insert(
+                self.editor.active.min(unsaved.len()),
+                UnsavedBuffer {
+                    name: current.clone(),
+                    content: self.editor.get_content(),
+                    original_content: self.editor.original_content.clone(),
+                    new_file: self.editor.new_file,
+                },
+            );
+        }
+
+        if unsaved.is_empty() {
+            storage::generic::clear(UNSAVED_BUFFERS_KEY);
+        } else {
+            storage::generic::save(UNSAVED_BUFFERS_KEY, &unsaved);
+        }
+    }
+
+    /// Open files in buffer order with their unsaved-changes flag
+    pub fn open_buffers(&self) -> Vec<(&str, bool)> {
+        let mut buffers: Vec<(&str, bool)> = self
+            .editor
+            .buffers
+            .iter()
+            .map(|b| (b.name.as_str(), b.dirty))
+            .collect();
+        if let Some(current) = &self.editor.current_file {
+            buffers.insert(self.editor.active, (current, self.dirty));
+        }
+        buffers
+    }
+
+    /// Make the open file at `index` current
+    pub fn switch_buffer(&mut self, index: usize) {
+        let active = self.editor.active;
+        if index == active || index >= self.editor.buffer_count() {
+            return;
+        }
+        // Taking the target out shifts the buffers after it
+        let target = if index < active { index } else { index - 1 };
+        let buffer = self.editor.buffers.remove(target);
+        // Switching to the other side of the split swaps the sides
+        if self.editor.split.as_ref() == Some(&buffer.name) {
+            self.editor.split = self.editor.current_file.clone();
+        }
+        if let Some(current) = self.editor.stash(self.dirty) {
+            let position = if index < active { active - 1 } else { active };
+            self.editor.buffers.insert(position, current);
+        }
+        self.editor.active = index;
+        self.dirty = self.editor.restore(buffer);
+        if self.vim_mode != VimMode::Command {
+            self.vim_mode = VimMode::Normal;
+        }
+    }
+
+    /// Make room for loading `filename` as a new buffer after the open ones;
+    /// loading the current file again replaces it
+    pub fn add_buffer(&mut self, filename: &str) {
+        if self.editor.current_file.as_deref() == Some(filename) {
+            return;
+        }
+        if let Some(current) = self.editor.stash(self.dirty) {
+            self.editor.buffers.insert(self.editor.active, current);
+        }
+        self.editor.buffers.retain(|b| b.name != filename);
+        self.editor.active = self.editor.buffers.len();
+        self.dirty = false;
+    }
+
+    /// Close the current buffer (dropping unsaved changes) and show the next
+    /// one
+    pub fn close_buffer(&mut self) {
+        let closed = self.editor.current_file.clone();
+        if self.editor.buffers.is_empty() {
+            self.editor.clear();
+            self.dirty = false;
+        } else {
+            let next = self.editor.active.min(self.editor.buffers.len() - 1);
+            let buffer = self.editor.buffers.remove(next);
+            self.editor.stash(false);
+            self.editor.active = next;
+            self.dirty = self.editor.restore(buffer);
+        }
+        if self.editor.split == closed || self.editor.split == self.editor.current_file {
+            self.editor.split = None;
+        }
+    }
+
+    /// Record that `filename` was written with `content` (it may no longer be
+    /// the current buffer)
+    pub fn mark_saved(&mut self, filename: &str, content: String) {
+        if self.editor.current_file.as_deref() == Some(filename) {
+            self.editor.original_content = content;
+            self.editor.new_file = false;
+            self.check_dirty();
+        } else if let Some(buffer) = self.editor.buffers.iter_mut().find(|b| b.name == filename) {
+            buffer.dirty = buffer.textarea.lines().join("\n") != content;
+            buffer.original_content = content;
+            buffer.new_file = false;
+        }
+        self.save_unsaved_buffers();
+    }
+
     pub fn set_theme(&mut self, theme_name: &str) {
         // [DEBUG_START] set_theme diagnostics
         // web_sys::console::log_1(&wasm_bindgen::JsValue::from_str(&format!(
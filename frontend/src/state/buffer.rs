@@ -0,0 +1,55 @@
+use crate::validate::Diagnostic;
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use tui_textarea::TextArea;
+
+/// An open file other than the current one; switching moves its state in and
+/// out of `EditorState`
+pub struct Buffer {
+    pub name: String,
+    /// Keeps the cursor and undo history of the file
+    pub textarea: TextArea<'static>,
+    pub original_content: String,
+    pub new_file: bool,
+    pub viewport: Cell<(usize, usize)>,
+    pub visual_marks: Option<(usize, usize)>,
+    pub diagnostics: Vec<Diagnostic>,
+    pub dirty: bool,
+}
+
+/// What closing or reloading a buffer with unsaved changes was about to do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeaveAction {
+    /// `:q`: close the buffer and return to the file list
+    Quit,
+    /// `:bd`: close the buffer
+    Close,
+    /// `:e`: load the file again
+    Reload,
+}
+
+/// Save/discard/cancel question asked before unsaved changes are lost
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeavePrompt {
+    pub filename: String,
+    pub action: LeaveAction,
+}
+
+impl LeavePrompt {
+    pub fn prompt(&self) -> String {
+        format!(
+            "{} has unsaved changes: [s]ave, [d]iscard or [c]ancel?",
+            self.filename
+        )
+    }
+}
+
+/// Buffer with unsaved changes kept in localStorage so it survives a reload
+/// of the page
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnsavedBuffer {
+    pub name: String,
+    pub content: String,
+    pub original_content: String,
+    pub new_file: bool,
+}
@@ -0,0 +1,198 @@
+use crate::storage;
+
+const COMMAND_HISTORY_KEY: &str = "command-history";
+const SEARCH_HISTORY_KEY: &str = "search-history";
+const HISTORY_LIMIT: usize = 100;
+
+/// Candidates offered by Tab for the word being completed
+#[derive(Debug, Clone)]
+pub struct Completion {
+    pub candidates: Vec<String>,
+    pub index: usize,
+    /// Byte offset where the completed word starts
+    pub start: usize,
+}
+
+/// Lines entered at one kind of prompt, oldest first (persisted in localStorage)
+struct History {
+    key: &'static str,
+    entries: Vec<String>,
+}
+
+impl History {
+    fn load(key: &'static str) -> Self {
+        Self {
+            key,
+            entries: storage::generic::load(key).unwrap_or_default(),
+        }
+    }
+}
+
+/// The `:` command line (or `/` and `?` search prompt) shown in the status area
+pub struct CommandLine {
+    /// `:` for commands, `/` or `?` for searches
+    pub prompt: char,
+    /// Count typed before `/` or `?`
+    pub count: Option<usize>,
+    pub input: String,
+    /// Cursor position in characters
+    pub cursor: usize,
+    pub completion: Option<Completion>,
+    commands: History,
+    searches: History,
+    /// History entry being shown (None while editing new input)
+    history_index: Option<usize>,
+    /// Input typed before browsing history; only entries starting with it are shown
+    draft: String,
+}
+
+impl CommandLine {
+    pub fn new() -> Self {
+        Self {
+            prompt: ':',
+            count: None,
+            input: String::new(),
+            cursor: 0,
+            completion: None,
+            commands: History::load(COMMAND_HISTORY_KEY),
+            searches: History::load(SEARCH_HISTORY_KEY),
+            history_index: None,
+            draft: String::new(),
+        }
+    }
+
+    /// Start a new command or search, optionally with some text already typed
+    pub fn open(&mut self, prompt: char, initial: &str) {
+        self.prompt = prompt;
+        self.count = None;
+        self.set_input(initial.to_string());
+        self.history_index = None;
+    }
+
+    /// History of the current prompt
+    fn history(&self) -> &History {
+        if self.prompt == ':' {
+            &self.commands
+        } else {
+            &self.searches
+        }
+    }
+
+    fn set_input(&mut self, input: String) {
+        self.cursor = input.chars().count();
+        self.input = input;
+        self.completion = None;
+    }
+
+    fn byte_index(&self, cursor: usize) -> usize {
+        self.input
+            .char_indices()
+            .nth(cursor)
+            .map_or(self.input.len(), |(i, _)| i)
+    }
+
+    pub fn insert(&mut self, c: char) {
+        let at = self.byte_index(self.cursor);
+        self.input.insert(at, c);
+        self.cursor += 1;
+        self.completion = None;
+    }
+
+    pub fn backspace(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            let at = self.byte_index(self.cursor);
+            self.input.remove(at);
+            self.completion = None;
+        }
+    }
+
+    pub fn delete(&mut self) {
+        if self.cursor < self.input.chars().count() {
+            let at = self.byte_index(self.cursor);
+            self.input.remove(at);
+            self.completion = None;
+        }
+    }
+
+    pub fn move_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_right(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.input.chars().count());
+    }
+
+    pub fn move_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn move_end(&mut self) {
+        self.cursor = self.input.chars().count();
+    }
+
+    /// Show the previous history entry matching what was typed
+    pub fn history_previous(&mut self) {
+        if self.history_index.is_none() {
+            self.draft = self.input.clone();
+        }
+        let entries = &self.history().entries;
+        let end = self.history_index.unwrap_or(entries.len());
+        if let Some(index) = (0..end)
+            .rev()
+            .find(|&i| entries[i].starts_with(&self.draft))
+        {
+            let entry = entries[index].clone();
+            self.history_index = Some(index);
+            self.set_input(entry);
+        }
+    }
+
+    /// Show the next history entry, or the typed input after the newest one
+    pub fn history_next(&mut self) {
+        let Some(current) = self.history_index else {
+            return;
+        };
+        let entries = &self.history().entries;
+        match (current + 1..entries.len()).find(|&i| entries[i].starts_with(&self.draft)) {
+            Some(index) => {
+                let entry = entries[index].clone();
+                self.history_index = Some(index);
+                self.set_input(entry);
+            }
+            None => {
+                self.history_index = None;
+                self.set_input(self.draft.clone());
+            }
+        }
+    }
+
+    /// Remember an executed command or search (moving repeated ones to the end)
+    pub fn push_history(&mut self, line: &str) {
+        if line.trim().is_empty() {
+            return;
+        }
+        let history = if self.prompt == ':' {
+            &mut self.commands
+        } else {
+            &mut self.searches
+        };
+        history.entries.retain(|entry| entry != line);
+        history.entries.push(line.to_string());
+        if history.entries.len() > HISTORY_LIMIT {
+            history.entries.remove(0);
+        }
+        storage::generic::save(history.key, &history.entries);
+    }
+
+    /// Replace the word being completed with the current candidate
+    pub fn apply_completion(&mut self) {
+        let Some(completion) = self.completion.take() else {
+            return;
+        };
+        let mut input = self.input[..completion.start].to_string();
+        input.push_str(&completion.candidates[completion.index]);
+        self.set_input(input);
+        self.completion = Some(completion);
+    }
+}
@@ -0,0 +1,50 @@
+use crate::api::ServicePlan;
+
+/// Maximum number of output lines kept in the apply log
+const MAX_LOG_LINES: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApplyStage {
+    /// Validating compose files and computing the plan
+    Planning,
+    /// Plan shown, waiting for confirmation
+    Review,
+    /// Compose files failed validation
+    Invalid,
+    /// `compose up` is running
+    Running,
+    /// `compose up` finished (true on success)
+    Done(bool),
+}
+
+/// State of the compose "apply" flow for the file in the editor
+pub struct ComposeApplyState {
+    pub project: String,
+    pub stage: ApplyStage,
+    pub services: Vec<ServicePlan>,
+    pub log: Vec<String>,
+}
+
+impl ComposeApplyState {
+    pub fn new(project: String) -> Self {
+        Self {
+            project,
+            stage: ApplyStage::Planning,
+            services: Vec::new(),
+            log: Vec::new(),
+        }
+    }
+
+    pub fn push_log(&mut self, line: impl Into<String>) {
+        self.log.push(line.into());
+        if self.log.len() > MAX_LOG_LINES {
+            let overflow = self.log.len() - MAX_LOG_LINES;
+            self.log.drain(..overflow);
+        }
+    }
+
+    /// Whether a request is in flight (panel must stay open)
+    pub fn is_busy(&self) -> bool {
+        matches!(self.stage, ApplyStage::Planning | ApplyStage::Running)
+    }
+}
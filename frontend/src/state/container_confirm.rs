@@ -0,0 +1,79 @@
+/// Signals offered when killing a container (first is the default)
+pub const KILL_SIGNALS: &[&str] = &[
+    "SIGKILL", "SIGTERM", "SIGHUP", "SIGINT", "SIGQUIT", "SIGUSR1", "SIGUSR2",
+];
+
+/// Destructive container actions that need confirmation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConfirmKind {
+    /// Index into `KILL_SIGNALS`
+    Kill {
+        signal: usize,
+    },
+    Remove {
+        volumes: bool,
+    },
+    Recreate,
+    /// `docker compose down` of the project named by `container_name`
+    ComposeDown,
+}
+
+/// A destructive action waiting for confirmation
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingAction {
+    pub container_id: String,
+    pub container_name: String,
+    pub running: bool,
+    pub kind: ConfirmKind,
+}
+
+impl PendingAction {
+    pub fn signal(&self) -> &'static str {
+        match self.kind {
+            ConfirmKind::Kill { signal } => KILL_SIGNALS[signal % KILL_SIGNALS.len()],
+            _ => KILL_SIGNALS[0],
+        }
+    }
+
+    /// Cycle the kill signal forward or backward
+    pub fn cycle_signal(&mut self, forward: bool) {
+        if let ConfirmKind::Kill { signal } = &mut self.kind {
+            let len = KILL_SIGNALS.len();
+            *signal = if forward {
+                (*signal + 1) % len
+            } else {
+                (*signal + len - 1) % len
+            };
+        }
+    }
+
+    pub fn toggle_volumes(&mut self) {
+        if let ConfirmKind::Remove { volumes } = &mut self.kind {
+            *volumes = !*volumes;
+        }
+    }
+
+    /// Question shown while waiting for confirmation
+    pub fn prompt(&self) -> String {
+        match self.kind {
+            ConfirmKind::Kill { .. } => format!(
+                "Send {} to {}? [y/n, ←/→: signal]",
+                self.signal(),
+                self.container_name
+            ),
+            ConfirmKind::Remove { volumes } => format!(
+                "Remove {}{}{}? [y/n, v: volumes]",
+                if self.running { "running " } else { "" },
+                self.container_name,
+                if volumes { " and its volumes" } else { "" }
+            ),
+            ConfirmKind::Recreate => {
+                format!("Pull image and recreate {}? [y/n]", self.container_name)
+            }
+            ConfirmKind::ComposeDown => format!(
+                "Stop and remove all containers of {}? [y/n]",
+                self.container_name
+            ),
+        }
+    }
+}
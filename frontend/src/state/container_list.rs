@@ -1,55 +1,259 @@
-use crate::api::ContainerInfo;
+use super::{ContainerView, PendingAction, SettingsForm};
+use crate::api::{ContainerInfo, ProjectInfo};
+use std::collections::HashSet;
+
+/// A visible row in the grouped container list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerRow {
+    /// Compose project header (index into `projects`)
+    Project(usize),
+    /// Container (index into `containers`)
+    Container(usize),
+}
 
 pub struct ContainerListState {
     pub containers: Vec<ContainerInfo>,
+    pub projects: Vec<ProjectInfo>,
+    /// Names of compose projects whose containers are hidden
+    pub collapsed: HashSet<String>,
     pub selected_index: usize,
+    /// Filter and sort settings
+    pub view: ContainerView,
+    /// Whether keys are currently typed into the filter query
+    pub filter_input: bool,
+    /// IDs of containers marked for bulk start/stop/restart
+    pub marked: HashSet<String>,
+    /// Destructive action waiting for confirmation
+    pub pending: Option<PendingAction>,
+    /// Restart policy/limits form shown in the details pane
+    pub settings_form: Option<SettingsForm>,
+    /// Show the raw inspect JSON instead of the details summary
+    pub raw_inspect: bool,
+    pub details_scroll: u16,
 }
 
 impl ContainerListState {
     pub fn new() -> Self {
         Self {
             containers: Vec::new(),
+            projects: Vec::new(),
+            collapsed: HashSet::new(),
             selected_index: 0,
+            view: ContainerView::default(),
+            filter_input: false,
+            marked: HashSet::new(),
+            pending: None,
+            settings_form: None,
+            raw_inspect: false,
+            details_scroll: 0,
+        }
+    }
+
+    /// Build the visible rows: project groups first, then standalone containers
+    ///
+    /// Containers are filtered and sorted by the view; while filtering,
+    /// projects without a matching container are hidden too.
+    pub fn rows(&self) -> Vec<ContainerRow> {
+        let mut order: Vec<usize> = (0..self.containers.len())
+            .filter(|&ci| self.view.matches(&self.containers[ci]))
+            .collect();
+        order.sort_by(|&a, &b| self.view.compare(&self.containers[a], &self.containers[b]));
+
+        let mut rows = Vec::new();
+
+        for (pi, project) in self.projects.iter().enumerate() {
+            let members: Vec<usize> = order
+                .iter()
+                .copied()
+                .filter(|&ci| self.containers[ci].project.as_deref() == Some(project.name.as_str()))
+                .collect();
+            if members.is_empty() && self.view.is_filtering() {
+                continue;
+            }
+
+            rows.push(ContainerRow::Project(pi));
+            if !self.collapsed.contains(&project.name) {
+                rows.extend(members.into_iter().map(ContainerRow::Container));
+            }
+        }
+
+        for ci in order {
+            let grouped = self.containers[ci]
+                .project
+                .as_ref()
+                .is_some_and(|name| self.projects.iter().any(|p| &p.name == name));
+            if !grouped {
+                rows.push(ContainerRow::Container(ci));
+            }
+        }
+
+        rows
+    }
+
+    /// Keep the cursor on an existing row after the view changed
+    pub fn clamp_selection(&mut self) {
+        let len = self.rows().len();
+        if self.selected_index >= len {
+            self.selected_index = len.saturating_sub(1);
         }
     }
 
     pub fn next(&mut self) {
-        if !self.containers.is_empty() {
-            self.selected_index = (self.selected_index + 1) % self.containers.len();
+        let len = self.rows().len();
+        if len > 0 {
+            self.selected_index = (self.selected_index + 1) % len;
         }
     }
 
     pub fn previous(&mut self) {
-        if !self.containers.is_empty() {
+        let len = self.rows().len();
+        if len > 0 {
             self.selected_index = if self.selected_index == 0 {
-                self.containers.len() - 1
+                len - 1
             } else {
                 self.selected_index - 1
             };
         }
     }
 
+    pub fn selected_row(&self) -> Option<ContainerRow> {
+        self.rows().get(self.selected_index).copied()
+    }
+
+    /// Selected container (None if a project header is selected)
     pub fn _selected(&self) -> Option<&ContainerInfo> {
-        self.containers.get(self.selected_index)
+        match self.selected_row()? {
+            ContainerRow::Container(ci) => self.containers.get(ci),
+            ContainerRow::Project(_) => None,
+        }
+    }
+
+    /// Project of the selected row (header itself or the container's project)
+    pub fn selected_project(&self) -> Option<&ProjectInfo> {
+        match self.selected_row()? {
+            ContainerRow::Project(pi) => self.projects.get(pi),
+            ContainerRow::Container(ci) => {
+                let name = self.containers.get(ci)?.project.as_ref()?;
+                self.projects.iter().find(|p| &p.name == name)
+            }
+        }
+    }
+
+    /// Collapse or expand the project of the selected row
+    pub fn toggle_group(&mut self) {
+        let Some(name) = self.selected_project().map(|p| p.name.clone()) else {
+            return;
+        };
+
+        if !self.collapsed.remove(&name) {
+            self.collapsed.insert(name.clone());
+        }
+
+        // Keep the cursor on the project header
+        if let Some(pos) = self.rows().iter().position(
+            |row| matches!(row, ContainerRow::Project(pi) if self.projects[*pi].name == name),
+        ) {
+            self.selected_index = pos;
+        }
+    }
+
+    /// Containers on visible rows, in display order
+    fn visible_containers(&self) -> Vec<&ContainerInfo> {
+        self.rows()
+            .into_iter()
+            .filter_map(|row| match row {
+                ContainerRow::Container(ci) => self.containers.get(ci),
+                ContainerRow::Project(_) => None,
+            })
+            .collect()
+    }
+
+    /// Marked containers in display order (collapsed groups included)
+    pub fn marked_containers(&self) -> Vec<&ContainerInfo> {
+        self.containers
+            .iter()
+            .filter(|c| self.marked.contains(&c.id))
+            .collect()
     }
 
-    pub fn set_containers(&mut self, containers: Vec<ContainerInfo>) {
-        // Preserve selection by container ID
+    /// Mark or unmark the selected container; on a project header mark the
+    /// whole project, or unmark it if all of its containers are marked
+    pub fn toggle_mark(&mut self) {
+        let ids: Vec<String> = match self.selected_row() {
+            Some(ContainerRow::Container(ci)) => vec![self.containers[ci].id.clone()],
+            Some(ContainerRow::Project(pi)) => self.projects[pi].containers.clone(),
+            None => return,
+        };
+        self.toggle_marks(ids);
+    }
+
+    /// Mark every visible container, or unmark them if all are marked
+    pub fn toggle_mark_visible(&mut self) {
+        let ids = self
+            .visible_containers()
+            .into_iter()
+            .map(|c| c.id.clone())
+            .collect();
+        self.toggle_marks(ids);
+    }
+
+    /// Mark every visible container in the same state as the selected one
+    ///
+    /// Returns the state that was matched.
+    pub fn mark_same_state(&mut self) -> Option<String> {
+        let state = self._selected()?.state.clone();
+        let ids: Vec<String> = self
+            .visible_containers()
+            .into_iter()
+            .filter(|c| c.state == state)
+            .map(|c| c.id.clone())
+            .collect();
+        self.marked.extend(ids);
+        Some(state)
+    }
+
+    fn toggle_marks(&mut self, ids: Vec<String>) {
+        if ids.iter().all(|id| self.marked.contains(id)) {
+            for id in &ids {
+                self.marked.remove(id);
+            }
+        } else {
+            self.marked.extend(ids);
+        }
+    }
+
+    pub fn set_containers(&mut self, containers: Vec<ContainerInfo>, projects: Vec<ProjectInfo>) {
+        // Preserve selection by container ID or project name
         let selected_id = self._selected().map(|c| c.id.clone());
+        let selected_project = match self.selected_row() {
+            Some(ContainerRow::Project(pi)) => self.projects.get(pi).map(|p| p.name.clone()),
+            _ => None,
+        };
 
         self.containers = containers;
+        self.projects = projects;
+
+        // Forget marks on containers that no longer exist
+        let containers = &self.containers;
+        self.marked
+            .retain(|id| containers.iter().any(|c| &c.id == id));
 
         // Try to restore previous selection
-        if let Some(id) = selected_id
-            && let Some(pos) = self.containers.iter().position(|c| c.id == id)
-        {
+        let rows = self.rows();
+        let restored = rows.iter().position(|row| match row {
+            ContainerRow::Container(ci) => selected_id.as_ref() == Some(&self.containers[*ci].id),
+            ContainerRow::Project(pi) => {
+                selected_project.as_ref() == Some(&self.projects[*pi].name)
+            }
+        });
+        if let Some(pos) = restored {
             self.selected_index = pos;
             return;
         }
 
         // Fallback: Keep index within bounds
-        if self.selected_index >= self.containers.len() && !self.containers.is_empty() {
-            self.selected_index = self.containers.len() - 1;
+        if self.selected_index >= rows.len() && !rows.is_empty() {
+            self.selected_index = rows.len() - 1;
         }
     }
 }
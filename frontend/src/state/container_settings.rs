@@ -0,0 +1,151 @@
+use crate::api::{ContainerDetails, UpdateSettingsRequest};
+
+/// Restart policies offered with Left/Right in the form
+const RESTART_POLICIES: &[&str] = &["no", "always", "unless-stopped", "on-failure"];
+
+const KIB: u64 = 1024;
+const MIB: u64 = 1024 * KIB;
+const GIB: u64 = 1024 * MIB;
+
+/// One editable value of the settings form
+pub struct SettingsField {
+    pub label: &'static str,
+    pub hint: &'static str,
+    pub value: String,
+    /// Value when the form was opened
+    pub original: String,
+}
+
+impl SettingsField {
+    fn new(label: &'static str, hint: &'static str, value: String) -> Self {
+        Self {
+            label,
+            hint,
+            original: value.clone(),
+            value,
+        }
+    }
+
+    pub fn changed(&self) -> bool {
+        self.value.trim() != self.original
+    }
+
+    /// Value to send, None if unchanged
+    fn change(&self) -> Option<String> {
+        self.changed().then(|| self.value.trim().to_string())
+    }
+}
+
+/// Restart policy and resource limits being edited in the details pane
+pub struct SettingsForm {
+    pub container_id: String,
+    pub container_name: String,
+    /// Restart policy, memory limit, CPU limit
+    pub fields: [SettingsField; 3],
+    pub focused: usize,
+    /// Rejection from the server, shown until the next edit
+    pub error: Option<String>,
+    /// Update request sent, waiting for the job to start
+    pub submitting: bool,
+}
+
+impl SettingsForm {
+    pub fn from_details(details: &ContainerDetails) -> Self {
+        Self {
+            container_id: details.id.clone(),
+            container_name: details.name.clone(),
+            fields: [
+                SettingsField::new(
+                    "Restart policy",
+                    "no, always, unless-stopped, on-failure[:N] (←/→ to cycle)",
+                    details.restart_policy.clone(),
+                ),
+                SettingsField::new(
+                    "Memory limit",
+                    "e.g. 512m, 2g (0 = unlimited)",
+                    format_memory(details.limits.memory),
+                ),
+                SettingsField::new(
+                    "CPU limit",
+                    "number of CPUs, e.g. 0.5, 2 (0 = unlimited)",
+                    format_cpus(details.limits.nano_cpus),
+                ),
+            ],
+            focused: 0,
+            error: None,
+            submitting: false,
+        }
+    }
+
+    pub fn next_field(&mut self) {
+        self.focused = (self.focused + 1) % self.fields.len();
+    }
+
+    pub fn previous_field(&mut self) {
+        let len = self.fields.len();
+        self.focused = (self.focused + len - 1) % len;
+    }
+
+    pub fn push_char(&mut self, c: char) {
+        self.fields[self.focused].value.push(c);
+        self.error = None;
+    }
+
+    pub fn pop_char(&mut self) {
+        self.fields[self.focused].value.pop();
+        self.error = None;
+    }
+
+    /// Step through the restart policy presets (only on the policy field)
+    pub fn cycle_policy(&mut self, forward: bool) {
+        if self.focused != 0 {
+            return;
+        }
+        let field = &mut self.fields[0];
+        let len = RESTART_POLICIES.len();
+        let current = RESTART_POLICIES
+            .iter()
+            .position(|p| field.value.trim() == *p || field.value.starts_with(&format!("{}:", p)))
+            .unwrap_or(0);
+        let next = if forward {
+            (current + 1) % len
+        } else {
+            (current + len - 1) % len
+        };
+        field.value = RESTART_POLICIES[next].to_string();
+        self.error = None;
+    }
+
+    /// Changed settings, None if nothing was edited
+    pub fn request(&self) -> Option<UpdateSettingsRequest> {
+        let [restart, memory, cpus] = &self.fields;
+        let request = UpdateSettingsRequest {
+            restart_policy: restart.change(),
+            memory: memory.change(),
+            cpus: cpus.change(),
+        };
+        let any =
+            request.restart_policy.is_some() || request.memory.is_some() || request.cpus.is_some();
+        any.then_some(request)
+    }
+}
+
+/// Bytes as a `--memory` value ("512m"), "0" for no limit
+fn format_memory(bytes: u64) -> String {
+    match bytes {
+        0 => "0".to_string(),
+        b if b % GIB == 0 => format!("{}g", b / GIB),
+        b if b % MIB == 0 => format!("{}m", b / MIB),
+        b if b % KIB == 0 => format!("{}k", b / KIB),
+        b => b.to_string(),
+    }
+}
+
+/// Billionths of a CPU as a `--cpus` value ("1.5"), "0" for no limit
+fn format_cpus(nano_cpus: u64) -> String {
+    if nano_cpus == 0 {
+        return "0".to_string();
+    }
+    let cpus = format!("{:.3}", nano_cpus as f64 / 1e9);
+    cpus.trim_end_matches('0').trim_end_matches('.').to_string()
+}
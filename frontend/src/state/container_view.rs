@@ -0,0 +1,118 @@
+use crate::api::ContainerInfo;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Order of containers within each group of the container list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContainerSort {
+    #[default]
+    Name,
+    /// Running first, then paused, restarting, created, exited, dead
+    State,
+    /// Longest running first
+    Uptime,
+    /// Newest first
+    Created,
+}
+
+impl ContainerSort {
+    pub fn next(self) -> Self {
+        match self {
+            ContainerSort::Name => ContainerSort::State,
+            ContainerSort::State => ContainerSort::Uptime,
+            ContainerSort::Uptime => ContainerSort::Created,
+            ContainerSort::Created => ContainerSort::Name,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ContainerSort::Name => "name",
+            ContainerSort::State => "state",
+            ContainerSort::Uptime => "uptime",
+            ContainerSort::Created => "created",
+        }
+    }
+}
+
+/// Filter and sort settings of the container list (persisted in localStorage)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContainerView {
+    /// Case-insensitive substring matched against name, image and state
+    pub query: String,
+    pub sort: ContainerSort,
+    pub running_only: bool,
+}
+
+impl ContainerView {
+    /// Whether any container can be hidden by this view
+    pub fn is_filtering(&self) -> bool {
+        !self.query.is_empty() || self.running_only
+    }
+
+    pub fn matches(&self, container: &ContainerInfo) -> bool {
+        if self.running_only && container.state != "running" {
+            return false;
+        }
+        if self.query.is_empty() {
+            return true;
+        }
+
+        let query = self.query.to_lowercase();
+        [&container.name, &container.image, &container.state]
+            .iter()
+            .any(|field| field.to_lowercase().contains(&query))
+    }
+
+    pub fn compare(&self, a: &ContainerInfo, b: &ContainerInfo) -> Ordering {
+        let primary = match self.sort {
+            ContainerSort::Name => Ordering::Equal,
+            ContainerSort::State => state_rank(&a.state).cmp(&state_rank(&b.state)),
+            ContainerSort::Uptime => uptime_seconds(&b.status).cmp(&uptime_seconds(&a.status)),
+            // `docker ps` timestamps share one format, so they sort as text
+            ContainerSort::Created => b.created.cmp(&a.created),
+        };
+        primary.then_with(|| a.name.cmp(&b.name))
+    }
+}
+
+fn state_rank(state: &str) -> u8 {
+    match state {
+        "running" => 0,
+        "paused" => 1,
+        "restarting" => 2,
+        "created" => 3,
+        "exited" => 4,
+        _ => 5,
+    }
+}
+
+/// Approximate uptime from a `docker ps` status such as "Up 2 hours (healthy)"
+/// or "Up About a minute"; containers that are not up count as zero
+fn uptime_seconds(status: &str) -> u64 {
+    let Some(rest) = status.strip_prefix("Up ") else {
+        return 0;
+    };
+
+    let mut count = 1;
+    for word in rest.split_whitespace() {
+        if let Ok(n) = word.parse() {
+            count = n;
+            continue;
+        }
+        let unit = match word.trim_end_matches('s') {
+            "second" => 1,
+            "minute" => 60,
+            "hour" => 3_600,
+            "day" => 86_400,
+            "week" => 604_800,
+            "month" => 2_592_000,
+            "year" => 31_536_000,
+            _ => continue,
+        };
+        return count * unit;
+    }
+    0
+}
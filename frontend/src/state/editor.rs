@@ -1,22 +1,119 @@
+use super::{Buffer, EditorConfig, EditorOptions, OptionValues, VimMode};
+use crate::{
+    syntax::Language,
+    validate::{self, Diagnostic},
+};
+use gloo_timers::callback::Timeout;
+use ratzilla::event::KeyEvent;
+use regex_lite::Regex;
+use std::cell::Cell;
 use tui_textarea::TextArea;
 
+/// Part of the buffer an operator works on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextRange {
+    /// Characters from `start` to `end` (both inclusive, as `(row, col)`)
+    Chars {
+        start: (usize, usize),
+        end: (usize, usize),
+    },
+    /// Whole lines from `start` to `end` (inclusive)
+    Lines { start: usize, end: usize },
+}
+
+/// A change that `.` repeats
+#[derive(Debug, Clone)]
+pub struct LastChange {
+    /// Normal mode keys of the command (with register and count)
+    pub keys: String,
+    /// Keys typed in insert mode afterwards (for `c`, `i`, `o`, ...)
+    pub inserted: Vec<KeyEvent>,
+}
+
+/// Last `/`, `?`, `*` or `#` search, repeated by `n` and `N`
+#[derive(Debug, Clone)]
+pub struct Search {
+    /// Pattern as typed, for messages
+    pub pattern: String,
+    pub regex: Regex,
+    pub forward: bool,
+}
+
 pub struct EditorState {
+    /// Open files besides the current one, in buffer order
+    pub buffers: Vec<Buffer>,
+    /// Position of the current file among all open files
+    pub active: usize,
+    /// Open file shown next to the current one
+    pub split: Option<String>,
     pub textarea: TextArea<'static>,
     pub current_file: Option<String>,
     pub original_content: String,
+    /// Buffer is not on disk yet; saving creates the file
+    pub new_file: bool,
+    /// First visible row and column, kept around the cursor while rendering
+    pub viewport: Cell<(usize, usize)>,
+    /// Normal/visual mode keys typed so far of an unfinished command
+    pub pending: String,
+    /// Last `f`/`F`/`t`/`T` and its character, repeated by `;` and `,`
+    pub last_find: Option<(char, char)>,
+    pub last_change: Option<LastChange>,
+    /// Insert mode keys are added to `last_change`
+    pub recording_insert: bool,
+    /// Where visual mode started (the other end is the cursor)
+    pub visual_anchor: (usize, usize),
+    /// First and last line of the last visual selection (`'<` and `'>`)
+    pub visual_marks: Option<(usize, usize)>,
+    /// Last search or `:s` pattern as a regex (an empty `:s` pattern reuses it)
+    pub last_pattern: Option<String>,
+    pub search: Option<Search>,
+    /// Matches of `search` are highlighted (until `:nohlsearch`)
+    pub highlight_search: bool,
+    /// Options of the current file: `config`, then `session` on top
+    pub options: EditorOptions,
+    pub config: EditorConfig,
+    /// Options changed with `:set`, for all files
+    pub session: OptionValues,
+    /// Problems found by the last check of the buffer
+    pub diagnostics: Vec<Diagnostic>,
+    /// Content the diagnostics are (or are about to be) for
+    pub checked_content: String,
+    /// Pending check, cancelled when dropped
+    pub check_timer: Option<Timeout>,
 }
 
 impl EditorState {
     pub fn new() -> Self {
         Self {
+            buffers: Vec::new(),
+            active: 0,
+            split: None,
             textarea: TextArea::default(),
             current_file: None,
             original_content: String::new(),
+            new_file: false,
+            viewport: Cell::new((0, 0)),
+            pending: String::new(),
+            last_find: None,
+            last_change: None,
+            recording_insert: false,
+            visual_anchor: (0, 0),
+            visual_marks: None,
+            last_pattern: None,
+            search: None,
+            highlight_search: false,
+            options: EditorOptions::default(),
+            config: EditorConfig::load(),
+            session: OptionValues::default(),
+            diagnostics: Vec::new(),
+            checked_content: String::new(),
+            check_timer: None,
         }
     }
 
     pub fn load_content(&mut self, filename: String, content: String) {
         self.current_file = Some(filename);
+        self.new_file = false;
 
         // Normalize content: split into lines and rejoin
         // This ensures original_content matches what textarea.lines().join("\n") produces
@@ -24,6 +121,36 @@ impl EditorState {
         self.original_content = lines.join("\n");
 
         self.textarea = TextArea::new(lines);
+        self.update_options();
+        self.viewport.set((0, 0));
+        self.pending.clear();
+        self.visual_marks = None;
+        self.validate();
+    }
+
+    /// Open generated content as a file that doesn't exist yet
+    pub fn load_new(&mut self, filename: String, content: String) {
+        self.load_content(filename, content);
+        self.original_content.clear();
+        self.new_file = true;
+    }
+
+    /// Selected range in visual modes
+    pub fn visual_range(&self, mode: VimMode) -> Option<TextRange> {
+        let cursor = self.textarea.cursor();
+        let (start, end) = if self.visual_anchor <= cursor {
+            (self.visual_anchor, cursor)
+        } else {
+            (cursor, self.visual_anchor)
+        };
+        match mode {
+            VimMode::Visual => Some(TextRange::Chars { start, end }),
+            VimMode::VisualLine => Some(TextRange::Lines {
+                start: start.0,
+                end: end.0,
+            }),
+            VimMode::Normal | VimMode::Insert | VimMode::Command => None,
+        }
     }
 
     pub fn get_content(&self) -> String {
@@ -32,7 +159,115 @@ impl EditorState {
 
     pub fn clear(&mut self) {
         self.current_file = None;
+        self.new_file = false;
         self.original_content = String::new();
         self.textarea = TextArea::default();
+        self.update_options();
+        self.viewport.set((0, 0));
+        self.pending.clear();
+        self.visual_marks = None;
+        self.validate();
+    }
+
+    /// Options for a file, with `:set` changes on top of its file type's
+    pub fn options_for(&self, filename: Option<&str>) -> EditorOptions {
+        let mut options = self
+            .config
+            .options(filename.and_then(Language::from_filename));
+        self.session.apply_to(&mut options);
+        options
+    }
+
+    /// Work out the options of the current file again
+    pub fn update_options(&mut self) {
+        self.options = self.options_for(self.current_file.as_deref());
+        self.options.apply(&mut self.textarea);
+    }
+
+    /// Check the buffer against the syntax of its file type
+    pub fn validate(&mut self) {
+        self.check_timer = None;
+        self.checked_content = self.get_content();
+        self.diagnostics = self
+            .current_file
+            .as_deref()
+            .and_then(Language::from_filename)
+            .map(|language| validate::check(language, &self.checked_content))
+            .unwrap_or_default();
+    }
+
+    /// Diagnostics are shown for this buffer
+    pub fn is_validated(&self) -> bool {
+        self.current_file
+            .as_deref()
+            .and_then(Language::from_filename)
+            .is_some_and(validate::supports)
+    }
+
+    /// Number of open files
+    pub fn buffer_count(&self) -> usize {
+        self.buffers.len() + usize::from(self.current_file.is_some())
+    }
+
+    /// Position of an open file
+    pub fn buffer_index(&self, name: &str) -> Option<usize> {
+        if self.current_file.as_deref() == Some(name) {
+            return Some(self.active);
+        }
+        let index = self.buffers.iter().position(|b| b.name == name)?;
+        Some(if index < self.active {
+            index
+        } else {
+            index + 1
+        })
+    }
+
+    /// Background buffer at a position among all open files
+    pub fn buffer_at(&self, index: usize) -> Option<&Buffer> {
+        match index.cmp(&self.active) {
+            std::cmp::Ordering::Less => self.buffers.get(index),
+            std::cmp::Ordering::Equal => None,
+            std::cmp::Ordering::Greater => self.buffers.get(index - 1),
+        }
+    }
+
+    /// Buffer shown next to the current one, if it is still open
+    pub fn split_buffer(&self) -> Option<&Buffer> {
+        let name = self.split.as_deref()?;
+        self.buffers.iter().find(|b| b.name == name)
+    }
+
+    /// Move the current file out into a buffer, leaving the editor empty
+    pub fn stash(&mut self, dirty: bool) -> Option<Buffer> {
+        let name = self.current_file.take()?;
+        self.check_timer = None;
+        self.pending.clear();
+        self.checked_content.clear();
+        Some(Buffer {
+            name,
+            textarea: std::mem::take(&mut self.textarea),
+            original_content: std::mem::take(&mut self.original_content),
+            new_file: std::mem::take(&mut self.new_file),
+            viewport: Cell::new(self.viewport.replace((0, 0))),
+            visual_marks: self.visual_marks.take(),
+            diagnostics: std::mem::take(&mut self.diagnostics),
+            dirty,
+        })
+    }
+
+    /// Make a buffer current; returns whether it has unsaved changes
+    pub fn restore(&mut self, buffer: Buffer) -> bool {
+        self.current_file = Some(buffer.name);
+        self.textarea = buffer.textarea;
+        // Options may have changed while the buffer was in the background
+        self.update_options();
+        self.original_content = buffer.original_content;
+        self.new_file = buffer.new_file;
+        self.viewport.set(buffer.viewport.get());
+        self.visual_marks = buffer.visual_marks;
+        self.diagnostics = buffer.diagnostics;
+        self.checked_content = self.get_content();
+        self.pending.clear();
+        buffer.dirty
     }
 }
@@ -0,0 +1,155 @@
+use crate::syntax::Language;
+use serde::Deserialize;
+use std::collections::HashMap;
+use tui_textarea::TextArea;
+
+/// Editor settings, from `editor.toml` and changed with `:set`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EditorOptions {
+    /// Width of a tab stop (`tabstop`)
+    pub tab_width: u8,
+    /// Tab inserts spaces (`expandtab`)
+    pub expand_tab: bool,
+    /// Patterns match regardless of case (`ignorecase`)
+    pub ignore_case: bool,
+    /// Searches are regular expressions rather than plain text (`regex`)
+    pub regex: bool,
+    /// The save key shows a diff to confirm first (`reviewsave`)
+    pub review_save: bool,
+    /// Line numbers in the gutter (`number`)
+    pub number: bool,
+    /// Line numbers relative to the cursor line (`relativenumber`)
+    pub relative_number: bool,
+    /// Long lines continue on the next screen row (`wrap`)
+    pub wrap: bool,
+    /// Tabs and trailing spaces are shown (`list`)
+    pub list: bool,
+    /// The cursor line is highlighted (`cursorline`)
+    pub cursor_line: bool,
+}
+
+impl Default for EditorOptions {
+    fn default() -> Self {
+        Self {
+            tab_width: 4,
+            expand_tab: true,
+            ignore_case: false,
+            regex: true,
+            review_save: false,
+            number: false,
+            relative_number: false,
+            wrap: false,
+            list: false,
+            cursor_line: false,
+        }
+    }
+}
+
+impl EditorOptions {
+    /// Configure a textarea (options are lost whenever the textarea is replaced)
+    pub fn apply(&self, textarea: &mut TextArea) {
+        textarea.set_tab_length(self.tab_width);
+        textarea.set_hard_tab_indent(!self.expand_tab);
+    }
+
+    /// Value of an on/off option by name
+    pub fn flag(&self, name: &str) -> Option<bool> {
+        match name {
+            "cursorline" => Some(self.cursor_line),
+            "expandtab" => Some(self.expand_tab),
+            "ignorecase" => Some(self.ignore_case),
+            "list" => Some(self.list),
+            "number" => Some(self.number),
+            "regex" => Some(self.regex),
+            "relativenumber" => Some(self.relative_number),
+            "reviewsave" => Some(self.review_save),
+            "wrap" => Some(self.wrap),
+            _ => None,
+        }
+    }
+}
+
+/// Options set by one layer of settings; unset ones come from the layer below
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OptionValues {
+    pub tabstop: Option<u8>,
+    pub expandtab: Option<bool>,
+    pub ignorecase: Option<bool>,
+    pub regex: Option<bool>,
+    pub reviewsave: Option<bool>,
+    pub number: Option<bool>,
+    pub relativenumber: Option<bool>,
+    pub wrap: Option<bool>,
+    pub list: Option<bool>,
+    pub cursorline: Option<bool>,
+}
+
+impl OptionValues {
+    pub fn apply_to(&self, options: &mut EditorOptions) {
+        let flags = [
+            (self.expandtab, &mut options.expand_tab),
+            (self.ignorecase, &mut options.ignore_case),
+            (self.regex, &mut options.regex),
+            (self.reviewsave, &mut options.review_save),
+            (self.number, &mut options.number),
+            (self.relativenumber, &mut options.relative_number),
+            (self.wrap, &mut options.wrap),
+            (self.list, &mut options.list),
+            (self.cursorline, &mut options.cursor_line),
+        ];
+        for (value, option) in flags {
+            if let Some(value) = value {
+                *option = value;
+            }
+        }
+        if let Some(tab_width) = self.tabstop {
+            options.tab_width = tab_width;
+        }
+    }
+
+    /// Setting of an on/off option by name
+    pub fn flag_mut(&mut self, name: &str) -> Option<&mut Option<bool>> {
+        match name {
+            "cursorline" => Some(&mut self.cursorline),
+            "expandtab" => Some(&mut self.expandtab),
+            "ignorecase" => Some(&mut self.ignorecase),
+            "list" => Some(&mut self.list),
+            "number" => Some(&mut self.number),
+            "regex" => Some(&mut self.regex),
+            "relativenumber" => Some(&mut self.relativenumber),
+            "reviewsave" => Some(&mut self.reviewsave),
+            "wrap" => Some(&mut self.wrap),
+            _ => None,
+        }
+    }
+}
+
+/// Default options and per file type overrides from `editor.toml`
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EditorConfig {
+    pub options: OptionValues,
+    /// Keyed by language name (`yaml`, `json`, `toml`, `ini`, `env`)
+    pub filetype: HashMap<String, OptionValues>,
+}
+
+impl EditorConfig {
+    pub fn load() -> Self {
+        // Editor config file is selected at build time from:
+        // 1. User config: ~/.config/sysrat/editor.toml
+        // 2. Default: frontend/editor.toml
+        const EDITOR_TOML: &str = include_str!(env!("EDITOR_CONFIG_FILE"));
+        toml::from_str(EDITOR_TOML).expect("Failed to parse editor.toml")
+    }
+
+    /// Options for a file of `language`, before any `:set`
+    pub fn options(&self, language: Option<Language>) -> EditorOptions {
+        let mut options = EditorOptions::default();
+        self.options.apply_to(&mut options);
+        if let Some(values) = language.and_then(|language| self.filetype.get(language.name())) {
+            values.apply_to(&mut options);
+        }
+        options
+    }
+}
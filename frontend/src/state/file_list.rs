@@ -1,7 +1,56 @@
-use crate::api::FileInfo;
+use crate::api::{ContainerDirEntry, FileInfo};
+
+/// Prefix of file names inside containers ("container:web/etc/app.conf")
+pub const CONTAINER_FILE_PREFIX: &str = "container:";
+
+/// Directory inside a container being browsed in place of the file list
+pub struct ContainerBrowse {
+    pub container: String,
+    pub path: String,
+    pub entries: Vec<ContainerDirEntry>,
+}
+
+impl ContainerBrowse {
+    /// Absolute path of an entry in the current directory
+    pub fn entry_path(&self, name: &str) -> String {
+        format!("{}/{}", self.path.trim_end_matches('/'), name)
+    }
+
+    /// Parent directory, None at the container root
+    pub fn parent_path(&self) -> Option<String> {
+        if self.path == "/" {
+            return None;
+        }
+        let (parent, _) = self.path.trim_end_matches('/').rsplit_once('/')?;
+        Some(if parent.is_empty() { "/" } else { parent }.to_string())
+    }
+
+    /// Editor file name of an entry ("container:web/etc/app.conf")
+    pub fn file_name(&self, name: &str) -> String {
+        format!(
+            "{}{}{}",
+            CONTAINER_FILE_PREFIX,
+            self.container,
+            self.entry_path(name)
+        )
+    }
+}
+
+/// A row of the file list
+pub enum FileRow<'a> {
+    File(&'a FileInfo),
+    /// Root of a container's virtual tree
+    Container(&'a str),
+    /// ".." while browsing a container
+    Parent,
+    Entry(&'a ContainerDirEntry),
+}
 
 pub struct FileListState {
     pub files: Vec<FileInfo>,
+    /// Containers whose files can be browsed (listed after the files)
+    pub containers: Vec<String>,
+    pub browse: Option<ContainerBrowse>,
     pub selected_index: usize,
 }
 
@@ -9,28 +58,55 @@ impl FileListState {
     pub fn new() -> Self {
         Self {
             files: Vec::new(),
+            containers: Vec::new(),
+            browse: None,
             selected_index: 0,
         }
     }
 
+    /// Rows currently shown: managed files and container roots, or the
+    /// directory being browsed
+    pub fn rows(&self) -> Vec<FileRow<'_>> {
+        match &self.browse {
+            Some(browse) => std::iter::once(FileRow::Parent)
+                .chain(browse.entries.iter().map(FileRow::Entry))
+                .collect(),
+            None => self
+                .files
+                .iter()
+                .map(FileRow::File)
+                .chain(self.containers.iter().map(|c| FileRow::Container(c)))
+                .collect(),
+        }
+    }
+
     pub fn next(&mut self) {
-        if !self.files.is_empty() {
-            self.selected_index = (self.selected_index + 1) % self.files.len();
+        let len = self.rows().len();
+        if len > 0 {
+            self.selected_index = (self.selected_index + 1) % len;
         }
     }
 
     pub fn previous(&mut self) {
-        if !self.files.is_empty() {
+        let len = self.rows().len();
+        if len > 0 {
             self.selected_index = if self.selected_index == 0 {
-                self.files.len() - 1
+                len - 1
             } else {
                 self.selected_index - 1
             };
         }
     }
 
+    pub fn selected_row(&self) -> Option<FileRow<'_>> {
+        self.rows().into_iter().nth(self.selected_index)
+    }
+
     pub fn selected(&self) -> Option<&FileInfo> {
-        self.files.get(self.selected_index)
+        match self.selected_row()? {
+            FileRow::File(file) => Some(file),
+            _ => None,
+        }
     }
 
     pub fn set_files(&mut self, files: Vec<FileInfo>) {
@@ -38,6 +114,9 @@ impl FileListState {
         let selected_name = self.selected().map(|f| f.name.clone());
 
         self.files = files;
+        if self.browse.is_some() {
+            return;
+        }
 
         // Try to restore previous selection
         if let Some(name) = selected_name
@@ -47,9 +126,41 @@ impl FileListState {
             return;
         }
 
-        // Fallback: Keep index within bounds
-        if self.selected_index >= self.files.len() && !self.files.is_empty() {
-            self.selected_index = self.files.len() - 1;
+        self.clamp_selection();
+    }
+
+    pub fn set_containers(&mut self, containers: Vec<String>) {
+        self.containers = containers;
+        if self.browse.is_none() {
+            self.clamp_selection();
+        }
+    }
+
+    /// Show a container directory, selecting `select` (an entry name) if given
+    pub fn enter_dir(&mut self, browse: ContainerBrowse, select: Option<&str>) {
+        let position = select.and_then(|name| browse.entries.iter().position(|e| e.name == name));
+        // Row 0 is ".."
+        self.selected_index = position.map_or(0, |pos| pos + 1);
+        self.browse = Some(browse);
+    }
+
+    /// Back to the managed files, with the container's root selected
+    pub fn leave_browse(&mut self) {
+        let Some(browse) = self.browse.take() else {
+            return;
+        };
+        self.selected_index = self
+            .containers
+            .iter()
+            .position(|c| *c == browse.container)
+            .map_or(0, |pos| self.files.len() + pos);
+        self.clamp_selection();
+    }
+
+    fn clamp_selection(&mut self) {
+        let len = self.rows().len();
+        if self.selected_index >= len && len > 0 {
+            self.selected_index = len - 1;
         }
     }
 }
@@ -0,0 +1,96 @@
+use crate::api::{ImageDetails, ImageInfo};
+
+/// Image actions waiting for confirmation
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImageConfirm {
+    Remove {
+        id: String,
+        label: String,
+        force: bool,
+    },
+    /// Remove all dangling images
+    Prune,
+}
+
+impl ImageConfirm {
+    pub fn toggle_force(&mut self) {
+        if let ImageConfirm::Remove { force, .. } = self {
+            *force = !*force;
+        }
+    }
+
+    /// Question shown while waiting for confirmation
+    pub fn prompt(&self) -> String {
+        match self {
+            ImageConfirm::Remove { label, force, .. } => format!(
+                "Remove image {}{}? [y/n, f: force]",
+                label,
+                if *force { " (force)" } else { "" }
+            ),
+            ImageConfirm::Prune => "Remove all dangling images? [y/n]".to_string(),
+        }
+    }
+}
+
+pub struct ImageListState {
+    pub images: Vec<ImageInfo>,
+    pub selected_index: usize,
+    /// Inspect result of the selected image (loaded with Enter)
+    pub details: Option<ImageDetails>,
+    pub pending: Option<ImageConfirm>,
+}
+
+impl ImageListState {
+    pub fn new() -> Self {
+        Self {
+            images: Vec::new(),
+            selected_index: 0,
+            details: None,
+            pending: None,
+        }
+    }
+
+    pub fn next(&mut self) {
+        if !self.images.is_empty() {
+            self.selected_index = (self.selected_index + 1) % self.images.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.images.is_empty() {
+            self.selected_index = if self.selected_index == 0 {
+                self.images.len() - 1
+            } else {
+                self.selected_index - 1
+            };
+        }
+    }
+
+    pub fn selected(&self) -> Option<&ImageInfo> {
+        self.images.get(self.selected_index)
+    }
+
+    /// Inspect details if they belong to the selected image
+    pub fn selected_details(&self) -> Option<&ImageDetails> {
+        let selected = self.selected()?;
+        self.details.as_ref().filter(|d| d.id == selected.id)
+    }
+
+    pub fn set_images(&mut self, images: Vec<ImageInfo>) {
+        // Preserve selection by image ID
+        let selected_id = self.selected().map(|i| i.id.clone());
+        self.images = images;
+
+        if let Some(pos) = selected_id.and_then(|id| self.images.iter().position(|i| i.id == id)) {
+            self.selected_index = pos;
+        } else if self.selected_index >= self.images.len() && !self.images.is_empty() {
+            self.selected_index = self.images.len() - 1;
+        }
+    }
+}
+
+/// Short form of an image ID for display
+pub fn short_image_id(id: &str) -> &str {
+    let hex = id.strip_prefix("sha256:").unwrap_or(id);
+    &hex[..12.min(hex.len())]
+}
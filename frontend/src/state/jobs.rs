@@ -0,0 +1,141 @@
+use super::{AppState, Pane, refresh, status_helper};
+use crate::{api, api::JobInfo, utils};
+use gloo_timers::future::TimeoutFuture;
+use std::{cell::RefCell, future::Future, rc::Rc};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+
+/// How often running jobs are polled
+const POLL_INTERVAL_MS: u32 = 500;
+
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Server-side jobs started from this page that are still running
+pub struct JobsState {
+    pub running: Vec<JobInfo>,
+}
+
+impl JobsState {
+    pub fn new() -> Self {
+        Self {
+            running: Vec::new(),
+        }
+    }
+
+    /// Show a job as running (until `follow_job` sees it finish)
+    pub fn update(&mut self, job: JobInfo) {
+        match self.running.iter_mut().find(|j| j.id == job.id) {
+            Some(existing) => *existing = job,
+            None => self.running.push(job),
+        }
+    }
+
+    fn remove(&mut self, id: u64) {
+        self.running.retain(|j| j.id != id);
+    }
+
+    /// Running job that holds a container
+    pub fn for_container(&self, container_id: &str) -> Option<&JobInfo> {
+        self.running
+            .iter()
+            .find(|j| j.container_ids.iter().any(|id| id == container_id))
+    }
+
+    /// Running job on a whole compose project
+    pub fn for_project(&self, project: &str) -> Option<&JobInfo> {
+        self.running
+            .iter()
+            .find(|j| j.project.as_deref() == Some(project))
+    }
+
+    /// One line summary for the status line, e.g. "⠙ restart web: docker restart"
+    pub fn summary(&self) -> Option<String> {
+        let first = self.running.first()?;
+        let mut text = format!("{} {} {}", spinner(), first.action, first.target);
+        if !first.step.is_empty() {
+            text.push_str(&format!(": {}", first.step));
+        }
+        if self.running.len() > 1 {
+            text.push_str(&format!(" (+{} more)", self.running.len() - 1));
+        }
+        Some(text)
+    }
+}
+
+/// Current spinner frame, advanced by wall clock so every render animates
+pub fn spinner() -> &'static str {
+    let frame = (js_sys::Date::now() / 100.0) as usize % SPINNER_FRAMES.len();
+    SPINNER_FRAMES[frame]
+}
+
+/// Start a job with `start` and follow it until it finishes
+///
+/// `label` names the operation in status messages ("restart web"). The
+/// container list is refreshed once the job is done.
+pub fn run_job<F>(state_rc: &Rc<RefCell<AppState>>, label: String, start: F)
+where
+    F: Future<Output = Result<JobInfo, JsValue>> + 'static,
+{
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        let result = match start.await {
+            Ok(job) => follow_job(&state_clone, job).await,
+            Err(e) => Err(utils::error::format_error(&e)),
+        };
+
+        let status = match result {
+            Ok(message) => format!("{}: {}", label, message),
+            Err(error) => format!("[ERROR {}: {}]", label, error),
+        };
+        status_helper::set_status_timed(&state_clone, status);
+        refresh::refresh_pane(Pane::ContainerList, &state_clone);
+    });
+}
+
+/// Poll a started job until it finishes, showing it as running meanwhile
+///
+/// Returns the job's result message, or its error if it failed.
+pub async fn follow_job(state_rc: &Rc<RefCell<AppState>>, job: JobInfo) -> Result<String, String> {
+    follow_job_output(state_rc, job, |_, _| {}).await
+}
+
+/// Like `follow_job`, passing the job's output so far to `on_output` after
+/// every poll
+pub async fn follow_job_output(
+    state_rc: &Rc<RefCell<AppState>>,
+    mut job: JobInfo,
+    mut on_output: impl FnMut(&mut AppState, &[String]),
+) -> Result<String, String> {
+    let id = job.id;
+    while job.is_running() {
+        {
+            let mut st = state_rc.borrow_mut();
+            st.jobs.update(job.clone());
+            on_output(&mut st, &job.output);
+        }
+        TimeoutFuture::new(POLL_INTERVAL_MS).await;
+        job = match api::fetch_job(id).await {
+            Ok(job) => job,
+            Err(e) => {
+                state_rc.borrow_mut().jobs.remove(id);
+                return Err(utils::error::format_error(&e));
+            }
+        };
+    }
+
+    {
+        let mut st = state_rc.borrow_mut();
+        st.jobs.remove(id);
+        on_output(&mut st, &job.output);
+    }
+    // Fall back to the last line docker printed when there is no summary
+    let message = job
+        .message
+        .or_else(|| job.output.last().cloned())
+        .unwrap_or_default();
+    if job.status == "succeeded" {
+        Ok(message)
+    } else {
+        Err(message)
+    }
+}
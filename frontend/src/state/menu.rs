@@ -6,7 +6,13 @@ pub struct MenuState {
 impl MenuState {
     pub fn new() -> Self {
         Self {
-            items: vec!["Config Files".to_string(), "Container".to_string()],
+            items: vec![
+                "Config Files".to_string(),
+                "Container".to_string(),
+                "Images".to_string(),
+                "Volumes".to_string(),
+                "Networks".to_string(),
+            ],
             selected_index: 0,
         }
     }
@@ -1,15 +1,41 @@
 pub mod app;
+pub mod buffer;
+pub mod command_line;
+pub mod compose_apply;
+pub mod container_confirm;
 pub mod container_list;
+pub mod container_settings;
+pub mod container_view;
 pub mod editor;
+pub mod editor_options;
 pub mod file_list;
+pub mod images;
+pub mod jobs;
 pub mod menu;
 pub mod pane;
 pub mod refresh;
+pub mod registers;
+pub mod resources;
+pub mod save_review;
 pub mod status_helper;
+pub mod terminal;
 
 pub use app::AppState;
-pub use container_list::ContainerListState;
-pub use editor::EditorState;
-pub use file_list::FileListState;
+pub use buffer::{Buffer, LeaveAction, LeavePrompt, UnsavedBuffer};
+pub use command_line::{CommandLine, Completion};
+pub use compose_apply::{ApplyStage, ComposeApplyState};
+pub use container_confirm::{ConfirmKind, PendingAction};
+pub use container_list::{ContainerListState, ContainerRow};
+pub use container_settings::SettingsForm;
+pub use container_view::ContainerView;
+pub use editor::{EditorState, LastChange, Search, TextRange};
+pub use editor_options::{EditorConfig, EditorOptions, OptionValues};
+pub use file_list::{ContainerBrowse, FileListState, FileRow};
+pub use images::{ImageConfirm, ImageListState};
+pub use jobs::{JobsState, follow_job, follow_job_output, run_job};
 pub use menu::MenuState;
 pub use pane::{Pane, VimMode};
+pub use registers::{Register, Registers};
+pub use resources::{Resource, ResourceConfirm, ResourceListState};
+pub use save_review::{DiffRow, SaveReview};
+pub use terminal::TerminalState;
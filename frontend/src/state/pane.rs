@@ -4,6 +4,11 @@ pub enum Pane {
     FileList,
     Editor,
     ContainerList,
+    /// Shell session in a container (shown in the container view)
+    Terminal,
+    Images,
+    Volumes,
+    Networks,
 }
 
 impl Pane {
@@ -13,6 +18,10 @@ impl Pane {
             Pane::FileList => "FileList",
             Pane::Editor => "Editor",
             Pane::ContainerList => "ContainerList",
+            Pane::Terminal => "Terminal",
+            Pane::Images => "Images",
+            Pane::Volumes => "Volumes",
+            Pane::Networks => "Networks",
         }
     }
 
@@ -22,6 +31,11 @@ impl Pane {
             "FileList" => Some(Pane::FileList),
             "Editor" => Some(Pane::Editor),
             "ContainerList" => Some(Pane::ContainerList),
+            // Shell sessions don't survive a reload
+            "Terminal" => Some(Pane::ContainerList),
+            "Images" => Some(Pane::Images),
+            "Volumes" => Some(Pane::Volumes),
+            "Networks" => Some(Pane::Networks),
             _ => None,
         }
     }
@@ -31,4 +45,10 @@ impl Pane {
 pub enum VimMode {
     Normal,
     Insert,
+    /// Characterwise selection (`v`)
+    Visual,
+    /// Linewise selection (`V`)
+    VisualLine,
+    /// Typing an ex command after `:`
+    Command,
 }
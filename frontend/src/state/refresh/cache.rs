@@ -32,11 +32,21 @@ pub fn load_pane_cache(pane: Pane, state: &mut AppState) {
         }
         Pane::ContainerList => {
             if let Some(containers) = crate::storage::generic::load("container-list") {
-                state.container_list.set_containers(containers);
+                let projects =
+                    crate::storage::generic::load("container-projects").unwrap_or_default();
+                state.container_list.set_containers(containers, projects);
+            }
+            // Restore filter and sort settings
+            if let Some(view) = crate::storage::generic::load("container-list-view") {
+                state.container_list.view = view;
+            }
+            // Restore collapsed compose projects
+            if let Some(collapsed) = crate::storage::generic::load("container-list-collapsed") {
+                state.container_list.collapsed = collapsed;
             }
             // Restore selection index
             if let Some(index) = crate::storage::generic::load::<usize>("container-list-selection")
-                && index < state.container_list.containers.len()
+                && index < state.container_list.rows().len()
             {
                 state.container_list.selected_index = index;
             }
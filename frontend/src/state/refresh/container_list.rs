@@ -8,17 +8,21 @@ pub fn refresh_container_list(state_rc: &Rc<RefCell<AppState>>) {
     let state_clone = Rc::clone(state_rc);
     spawn_local(async move {
         match crate::api::fetch_container_list().await {
-            Ok(containers) => {
+            Ok((containers, projects)) => {
                 let mut st = state_clone.borrow_mut();
                 // Only save to cache if data changed (important for background refresh!)
                 if st.container_list.containers != containers {
                     crate::storage::generic::save("container-list", &containers);
                 }
-                st.container_list.set_containers(containers);
+                if st.container_list.projects != projects {
+                    crate::storage::generic::save("container-projects", &projects);
+                }
+                st.container_list.set_containers(containers, projects);
                 // Don't overwrite status on success - let action messages show
             }
             Err(e) => {
                 crate::storage::generic::clear("container-list");
+                crate::storage::generic::clear("container-projects");
                 status_helper::set_status_timed(
                     &state_clone,
                     format!(
@@ -7,13 +7,14 @@ pub fn refresh_file_list(state_rc: &Rc<RefCell<AppState>>) {
     let state_clone = Rc::clone(state_rc);
     spawn_local(async move {
         match crate::api::fetch_file_list().await {
-            Ok(files) => {
+            Ok((files, containers)) => {
                 let mut st = state_clone.borrow_mut();
                 // Only save to cache if data changed
                 if st.file_list.files != files {
                     crate::storage::generic::save("file-list", &files);
                 }
                 st.file_list.set_files(files);
+                st.file_list.set_containers(containers);
                 // Don't overwrite status on success - let action messages show
             }
             Err(e) => {
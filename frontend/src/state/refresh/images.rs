@@ -0,0 +1,21 @@
+use crate::state::{AppState, status_helper};
+use crate::utils;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+pub fn refresh_image_list(state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        match crate::api::fetch_image_list().await {
+            Ok(images) => {
+                state_clone.borrow_mut().images.set_images(images);
+            }
+            Err(e) => {
+                status_helper::set_status_timed(
+                    &state_clone,
+                    format!("[ERROR loading images: {}]", utils::error::format_error(&e)),
+                );
+            }
+        }
+    });
+}
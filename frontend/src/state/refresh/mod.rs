@@ -1,6 +1,8 @@
 mod cache;
 mod container_list;
 mod file_list;
+mod images;
+mod resources;
 
 use crate::state::{AppState, Pane};
 use std::{cell::RefCell, rc::Rc};
@@ -16,6 +18,9 @@ pub fn refresh_pane(pane: Pane, state_rc: &Rc<RefCell<AppState>>) {
     match pane {
         Pane::FileList => file_list::refresh_file_list(state_rc),
         Pane::ContainerList => container_list::refresh_container_list(state_rc),
+        Pane::Images => images::refresh_image_list(state_rc),
+        Pane::Volumes => resources::refresh_volume_list(state_rc),
+        Pane::Networks => resources::refresh_network_list(state_rc),
         _ => {}
     }
 }
@@ -0,0 +1,44 @@
+use crate::state::{AppState, status_helper};
+use crate::utils;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+
+pub fn refresh_volume_list(state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        match crate::api::fetch_volume_list().await {
+            Ok(volumes) => {
+                state_clone.borrow_mut().volumes.set_items(volumes);
+            }
+            Err(e) => {
+                status_helper::set_status_timed(
+                    &state_clone,
+                    format!(
+                        "[ERROR loading volumes: {}]",
+                        utils::error::format_error(&e)
+                    ),
+                );
+            }
+        }
+    });
+}
+
+pub fn refresh_network_list(state_rc: &Rc<RefCell<AppState>>) {
+    let state_clone = Rc::clone(state_rc);
+    spawn_local(async move {
+        match crate::api::fetch_network_list().await {
+            Ok(networks) => {
+                state_clone.borrow_mut().networks.set_items(networks);
+            }
+            Err(e) => {
+                status_helper::set_status_timed(
+                    &state_clone,
+                    format!(
+                        "[ERROR loading networks: {}]",
+                        utils::error::format_error(&e)
+                    ),
+                );
+            }
+        }
+    });
+}
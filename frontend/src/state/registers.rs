@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+/// Register the last yank or delete goes to
+pub const UNNAMED: char = '"';
+
+/// Register that only receives yanks
+const YANK: char = '0';
+
+/// Register that discards what is written to it
+const BLACK_HOLE: char = '_';
+
+/// Text held by a register
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Register {
+    pub text: String,
+    /// Whole lines (pasted as new lines instead of into the current one)
+    pub linewise: bool,
+}
+
+/// Vim registers: unnamed, yank (`0`) and named (`a`-`z`, `A`-`Z` appends)
+pub struct Registers {
+    registers: HashMap<char, Register>,
+}
+
+impl Registers {
+    pub fn new() -> Self {
+        Self {
+            registers: HashMap::new(),
+        }
+    }
+
+    /// Whether `name` can follow `"`
+    pub fn is_valid_name(name: char) -> bool {
+        name.is_ascii_alphanumeric() || name == UNNAMED || name == BLACK_HOLE
+    }
+
+    /// Store yanked (`yank`) or deleted text in `name` and the unnamed register
+    pub fn store(&mut self, name: Option<char>, register: Register, yank: bool) {
+        match name {
+            Some(BLACK_HOLE) => return,
+            Some(upper) if upper.is_ascii_uppercase() => {
+                let entry = self
+                    .registers
+                    .entry(upper.to_ascii_lowercase())
+                    .or_default();
+                if entry.linewise || register.linewise {
+                    if !entry.text.is_empty() {
+                        entry.text.push('\n');
+                    }
+                    entry.linewise = true;
+                }
+                entry.text.push_str(&register.text);
+                let appended = entry.clone();
+                self.registers.insert(UNNAMED, appended);
+                return;
+            }
+            Some(name) if name != UNNAMED => {
+                self.registers.insert(name, register.clone());
+            }
+            _ if yank => {
+                self.registers.insert(YANK, register.clone());
+            }
+            _ => {}
+        }
+        self.registers.insert(UNNAMED, register);
+    }
+
+    /// Content of `name` (the unnamed register if None)
+    pub fn get(&self, name: Option<char>) -> Option<&Register> {
+        let name = name.unwrap_or(UNNAMED).to_ascii_lowercase();
+        self.registers
+            .get(&name)
+            .filter(|r| r.linewise || !r.text.is_empty())
+    }
+}
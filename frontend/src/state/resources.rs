@@ -0,0 +1,151 @@
+use crate::api::{NetworkInfo, VolumeInfo};
+
+/// Docker objects shown in the volume and network panes
+pub trait Resource {
+    /// Singular name used in prompts ("volume")
+    const KIND: &'static str;
+    /// What a prune removes, for the confirmation prompt
+    const PRUNE_TARGET: &'static str;
+
+    /// Identifier passed to the API, also used to keep the selection
+    fn key(&self) -> &str;
+    fn label(&self) -> &str;
+    /// Names of containers using this object
+    fn users(&self) -> &[String];
+
+    /// Objects docker never removes (predefined networks)
+    fn builtin(&self) -> bool {
+        false
+    }
+}
+
+impl Resource for VolumeInfo {
+    const KIND: &'static str = "volume";
+    const PRUNE_TARGET: &'static str = "unused anonymous volumes";
+
+    fn key(&self) -> &str {
+        &self.name
+    }
+
+    fn label(&self) -> &str {
+        &self.name
+    }
+
+    fn users(&self) -> &[String] {
+        &self.containers
+    }
+}
+
+impl Resource for NetworkInfo {
+    const KIND: &'static str = "network";
+    const PRUNE_TARGET: &'static str = "unused networks";
+
+    fn key(&self) -> &str {
+        &self.id
+    }
+
+    fn label(&self) -> &str {
+        &self.name
+    }
+
+    fn users(&self) -> &[String] {
+        &self.containers
+    }
+
+    fn builtin(&self) -> bool {
+        self.builtin
+    }
+}
+
+/// Volume/network actions waiting for confirmation
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResourceConfirm {
+    Remove { key: String, label: String },
+    Prune,
+}
+
+pub struct ResourceListState<T> {
+    pub items: Vec<T>,
+    pub selected_index: usize,
+    pub pending: Option<ResourceConfirm>,
+}
+
+impl<T: Resource> ResourceListState<T> {
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            selected_index: 0,
+            pending: None,
+        }
+    }
+
+    pub fn next(&mut self) {
+        if !self.items.is_empty() {
+            self.selected_index = (self.selected_index + 1) % self.items.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.items.is_empty() {
+            self.selected_index = if self.selected_index == 0 {
+                self.items.len() - 1
+            } else {
+                self.selected_index - 1
+            };
+        }
+    }
+
+    pub fn selected(&self) -> Option<&T> {
+        self.items.get(self.selected_index)
+    }
+
+    pub fn set_items(&mut self, items: Vec<T>) {
+        // Preserve selection by key
+        let selected_key = self.selected().map(|i| i.key().to_string());
+        self.items = items;
+
+        if let Some(pos) =
+            selected_key.and_then(|key| self.items.iter().position(|i| i.key() == key))
+        {
+            self.selected_index = pos;
+        } else if self.selected_index >= self.items.len() && !self.items.is_empty() {
+            self.selected_index = self.items.len() - 1;
+        }
+    }
+
+    /// Ask to remove the selected object, or explain why it can't be removed
+    pub fn request_remove(&mut self) -> Result<(), String> {
+        let Some(item) = self.selected() else {
+            return Ok(());
+        };
+        if item.builtin() {
+            return Err(format!(
+                "{} is predefined and cannot be removed",
+                item.label()
+            ));
+        }
+        if !item.users().is_empty() {
+            return Err(format!(
+                "{} is used by: {}",
+                item.label(),
+                item.users().join(", ")
+            ));
+        }
+
+        self.pending = Some(ResourceConfirm::Remove {
+            key: item.key().to_string(),
+            label: item.label().to_string(),
+        });
+        Ok(())
+    }
+
+    /// Question shown while waiting for confirmation
+    pub fn prompt(&self) -> Option<String> {
+        Some(match self.pending.as_ref()? {
+            ResourceConfirm::Remove { label, .. } => {
+                format!("Remove {} {}? [y/n]", T::KIND, label)
+            }
+            ResourceConfirm::Prune => format!("Remove all {}? [y/n]", T::PRUNE_TARGET),
+        })
+    }
+}
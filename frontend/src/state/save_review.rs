@@ -0,0 +1,119 @@
+use crate::utils::diff::{self, Hunk};
+
+/// Unchanged lines shown around each hunk
+const CONTEXT_LINES: usize = 3;
+
+/// Row of the rendered diff
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffRow {
+    /// `@@ -a,b +c,d @@` line of a hunk
+    Header(usize),
+    /// Unchanged line (index in the old and the new text)
+    Context(usize, usize),
+    /// Line of the old text that was removed
+    Deleted(usize),
+    /// Line of the buffer that was added
+    Inserted(usize),
+}
+
+/// Diff of the buffer against the saved file, shown before saving
+pub struct SaveReview {
+    pub filename: String,
+    pub old_lines: Vec<String>,
+    pub new_lines: Vec<String>,
+    pub hunks: Vec<Hunk>,
+    pub rows: Vec<DiffRow>,
+    /// Hunk the revert key acts on
+    pub current: usize,
+    /// First shown row
+    pub scroll: usize,
+    /// Old and new text next to each other instead of a unified diff
+    pub side_by_side: bool,
+}
+
+impl SaveReview {
+    pub fn new(filename: String, old: &str, new: &str, side_by_side: bool) -> Self {
+        let mut review = Self {
+            filename,
+            old_lines: Vec::new(),
+            new_lines: Vec::new(),
+            hunks: Vec::new(),
+            rows: Vec::new(),
+            current: 0,
+            scroll: 0,
+            side_by_side,
+        };
+        review.update(old, new);
+        review
+    }
+
+    /// Diff the texts again (after a hunk was reverted)
+    pub fn update(&mut self, old: &str, new: &str) {
+        // Split like the textarea does, so line indices match the buffer
+        self.old_lines = old.split('\n').map(String::from).collect();
+        self.new_lines = new.split('\n').map(String::from).collect();
+        self.hunks = diff::hunks(&self.old_lines, &self.new_lines);
+        self.rows = self.build_rows();
+        self.current = self.current.min(self.hunks.len().saturating_sub(1));
+        self.scroll = self.hunk_row(self.current);
+    }
+
+    /// Row of a hunk's header
+    pub fn hunk_row(&self, hunk: usize) -> usize {
+        self.rows
+            .iter()
+            .position(|row| *row == DiffRow::Header(hunk))
+            .unwrap_or(0)
+    }
+
+    fn build_rows(&self) -> Vec<DiffRow> {
+        let mut rows = Vec::new();
+        // Old line after the previous hunk's trailing context
+        let mut shown_until = 0;
+        for (index, hunk) in self.hunks.iter().enumerate() {
+            let old_end = hunk.old_start + hunk.old_lines.len();
+            let before = hunk
+                .old_start
+                .saturating_sub(CONTEXT_LINES)
+                .max(shown_until);
+            let after_end = self
+                .hunks
+                .get(index + 1)
+                .map_or(self.old_lines.len(), |next| next.old_start)
+                .min(old_end + CONTEXT_LINES);
+            // New lines are offset from old ones by the hunk's position
+            let offset = hunk.new_start as isize - hunk.old_start as isize;
+            let new_offset = offset + hunk.new_lines.len() as isize - hunk.old_lines.len() as isize;
+
+            rows.push(DiffRow::Header(index));
+            for old in before..hunk.old_start {
+                rows.push(DiffRow::Context(old, (old as isize + offset) as usize));
+            }
+            rows.extend((hunk.old_start..old_end).map(DiffRow::Deleted));
+            let new_end = hunk.new_start + hunk.new_lines.len();
+            rows.extend((hunk.new_start..new_end).map(DiffRow::Inserted));
+            for old in old_end..after_end {
+                rows.push(DiffRow::Context(old, (old as isize + new_offset) as usize));
+            }
+            shown_until = after_end;
+        }
+        rows
+    }
+
+    /// `@@ -a,b +c,d @@` with the changed lines (1-based)
+    pub fn header(&self, hunk: usize) -> String {
+        let hunk = &self.hunks[hunk];
+        let range = |start: usize, len: usize| {
+            if len == 0 {
+                format!("{},0", start)
+            } else {
+                format!("{},{}", start + 1, len)
+            }
+        };
+        format!(
+            "@@ -{} +{} @@",
+            range(hunk.old_start, hunk.old_lines.len()),
+            range(hunk.new_start, hunk.new_lines.len())
+        )
+    }
+}
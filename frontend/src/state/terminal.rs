@@ -0,0 +1,52 @@
+use crate::api::ExecSession;
+use std::cell::{Cell, RefCell};
+
+/// Lines kept above the visible screen
+const SCROLLBACK: usize = 500;
+
+/// Interactive shell session in a container
+pub struct TerminalState {
+    pub container_id: String,
+    pub container_name: String,
+    /// Terminal emulator fed with the session output
+    pub parser: RefCell<vt100::Parser>,
+    pub session: Option<ExecSession>,
+    /// Close reason once the session has ended
+    pub closed: Option<String>,
+    /// Size (cols, rows) the PTY currently has
+    size: Cell<(u16, u16)>,
+}
+
+impl TerminalState {
+    pub fn new(container_id: String, container_name: String, cols: u16, rows: u16) -> Self {
+        Self {
+            container_id,
+            container_name,
+            parser: RefCell::new(vt100::Parser::new(rows, cols, SCROLLBACK)),
+            session: None,
+            closed: None,
+            size: Cell::new((cols, rows)),
+        }
+    }
+
+    pub fn size(&self) -> (u16, u16) {
+        self.size.get()
+    }
+
+    /// Feed session output into the emulator
+    pub fn process(&self, data: &[u8]) {
+        self.parser.borrow_mut().process(data);
+    }
+
+    /// Resize emulator and PTY to the rendered area (no-op if unchanged)
+    pub fn fit(&self, cols: u16, rows: u16) {
+        if cols == 0 || rows == 0 || self.size.get() == (cols, rows) {
+            return;
+        }
+        self.size.set((cols, rows));
+        self.parser.borrow_mut().set_size(rows, cols);
+        if let Some(session) = &self.session {
+            session.resize(cols, rows);
+        }
+    }
+}
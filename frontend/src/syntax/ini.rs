@@ -0,0 +1,95 @@
+use super::scan::{Scanner, is_literal};
+use super::{Token, TokenKind};
+
+/// `[section]`, `key = value` / `key: value`, `;` and `#` comment lines
+pub(super) fn highlight_ini(line: &str) -> Vec<Token> {
+    let mut s = Scanner::new(line);
+    s.skip_whitespace();
+    let start = s.pos;
+
+    match s.peek() {
+        Some(';' | '#') => s.push_rest(TokenKind::Comment),
+        Some('[') => {
+            let header = s.rest().trim_end();
+            s.pos += header.len();
+            s.push(TokenKind::Section, start);
+        }
+        Some(_) => {
+            let rest = s.rest();
+            let Some(separator) = rest.find(['=', ':']) else {
+                return s.tokens;
+            };
+            s.pos += rest[..separator].trim_end().len();
+            s.push(TokenKind::Key, start);
+            s.pos = start + separator + 1;
+            scalar(&mut s);
+        }
+        None => {}
+    }
+    s.tokens
+}
+
+/// Shell-style `KEY=value` lines with `#` comments (.env, .conf)
+pub(super) fn highlight_env(line: &str) -> Vec<Token> {
+    let mut s = Scanner::new(line);
+    s.skip_whitespace();
+    if s.starts_with("#") {
+        s.push_rest(TokenKind::Comment);
+        return s.tokens;
+    }
+    if s.starts_with("export ") {
+        s.pos += "export ".len();
+        s.skip_whitespace();
+    }
+
+    let start = s.pos;
+    let rest = s.rest();
+    if let Some(separator) = rest.find('=') {
+        let name = rest[..separator].trim_end();
+        if !name.is_empty() && !name.contains(char::is_whitespace) {
+            s.pos += name.len();
+            s.push(TokenKind::Key, start);
+            s.pos = start + separator + 1;
+        }
+    }
+
+    // Quoted strings and a trailing comment
+    while !s.at_end() {
+        let before = s.pos;
+        s.skip_whitespace();
+        let start = s.pos;
+        match s.peek() {
+            None => break,
+            Some('#') if start > before => {
+                s.push_rest(TokenKind::Comment);
+                break;
+            }
+            Some(quote @ ('"' | '\'')) => {
+                s.quoted(quote == '"');
+                s.push(TokenKind::String, start);
+            }
+            Some(_) => {
+                if let Some(kind) = s.word(|c| !c.is_whitespace() && c != '"' && c != '\'') {
+                    s.push(kind, start);
+                }
+                if s.pos == start {
+                    s.bump();
+                }
+            }
+        }
+    }
+    s.tokens
+}
+
+/// INI values are unquoted; quoted ones and literals are highlighted
+fn scalar(s: &mut Scanner) {
+    s.skip_whitespace();
+    let start = s.pos;
+    let value = s.rest().trim_end();
+    s.pos += value.len();
+    if value.starts_with(['"', '\'']) {
+        s.push(TokenKind::String, start);
+    } else if is_literal(value) {
+        s.push(TokenKind::Number, start);
+    }
+}
@@ -0,0 +1,30 @@
+use super::scan::Scanner;
+use super::{Token, TokenKind};
+
+pub(super) fn highlight_line(line: &str) -> Vec<Token> {
+    let mut s = Scanner::new(line);
+    while !s.at_end() {
+        s.skip_whitespace();
+        let start = s.pos;
+        match s.peek() {
+            None => break,
+            Some('"') => {
+                s.quoted(true);
+                let kind = if s.followed_by(':') {
+                    TokenKind::Key
+                } else {
+                    TokenKind::String
+                };
+                s.push(kind, start);
+            }
+            Some(c) if c.is_alphanumeric() || matches!(c, '-' | '+' | '.') => {
+                if let Some(kind) = s.word(|c| c.is_alphanumeric() || matches!(c, '-' | '+' | '.'))
+                {
+                    s.push(kind, start);
+                }
+            }
+            Some(_) => s.bump(),
+        }
+    }
+    s.tokens
+}
@@ -0,0 +1,96 @@
+mod ini;
+mod json;
+mod scan;
+mod toml;
+mod yaml;
+
+/// Config file languages the editor knows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    Toml,
+    Json,
+    Yaml,
+    Ini,
+    /// Shell-style `KEY=value` files (.env, .conf)
+    Env,
+}
+
+impl Language {
+    /// Pick the language from a file name ("compose/app/docker-compose.yml")
+    pub fn from_filename(filename: &str) -> Option<Self> {
+        let name = filename.rsplit('/').next().unwrap_or(filename);
+        let lower = name.to_lowercase();
+
+        // .env, .env.local, app.env
+        if lower == ".env" || lower.starts_with(".env.") {
+            return Some(Language::Env);
+        }
+
+        match lower.rsplit_once('.')?.1 {
+            "toml" => Some(Language::Toml),
+            "json" => Some(Language::Json),
+            "yaml" | "yml" => Some(Language::Yaml),
+            "ini" | "cfg" => Some(Language::Ini),
+            "env" | "conf" => Some(Language::Env),
+            _ => None,
+        }
+    }
+
+    /// File type name, as used in `editor.toml`
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::Toml => "toml",
+            Language::Json => "json",
+            Language::Yaml => "yaml",
+            Language::Ini => "ini",
+            Language::Env => "env",
+        }
+    }
+}
+
+/// Semantic role of a highlighted span
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Key,
+    String,
+    /// Numbers and literals (true, false, null, dates)
+    Number,
+    Comment,
+    /// Section headers ([table], [[array]], YAML document markers)
+    Section,
+}
+
+/// Highlighted byte range within a line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Tokens of the first `count` lines
+///
+/// Lines are highlighted from the top because a line can continue a
+/// construct from an earlier one (TOML multi-line strings).
+pub fn highlight(language: Language, lines: &[String], count: usize) -> Vec<Vec<Token>> {
+    let lines = &lines[..count.min(lines.len())];
+    match language {
+        Language::Toml => {
+            let mut open_string = None;
+            lines
+                .iter()
+                .map(|line| toml::highlight_line(line, &mut open_string))
+                .collect()
+        }
+        Language::Json => lines
+            .iter()
+            .map(|line| json::highlight_line(line))
+            .collect(),
+        Language::Yaml => lines
+            .iter()
+            .map(|line| yaml::highlight_line(line))
+            .collect(),
+        Language::Ini => lines.iter().map(|line| ini::highlight_ini(line)).collect(),
+        Language::Env => lines.iter().map(|line| ini::highlight_env(line)).collect(),
+    }
+}
@@ -0,0 +1,114 @@
+use super::{Token, TokenKind};
+
+/// Character cursor over one line that collects tokens
+pub(super) struct Scanner<'a> {
+    line: &'a str,
+    pub pos: usize,
+    pub tokens: Vec<Token>,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(line: &'a str) -> Self {
+        Self {
+            line,
+            pos: 0,
+            tokens: Vec::new(),
+        }
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.line[self.pos..]
+    }
+
+    pub fn at_end(&self) -> bool {
+        self.pos >= self.line.len()
+    }
+
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.rest().starts_with(prefix)
+    }
+
+    pub fn bump(&mut self) {
+        if let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    pub fn eat_while(&mut self, pred: impl Fn(char) -> bool) {
+        while let Some(c) = self.peek() {
+            if !pred(c) {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        self.eat_while(char::is_whitespace);
+    }
+
+    /// Whether the next non-blank character is `c`
+    pub fn followed_by(&self, c: char) -> bool {
+        self.rest().trim_start().starts_with(c)
+    }
+
+    pub fn push(&mut self, kind: TokenKind, start: usize) {
+        if self.pos > start {
+            self.tokens.push(Token {
+                kind,
+                start,
+                end: self.pos,
+            });
+        }
+    }
+
+    /// Rest of the line as one token
+    pub fn push_rest(&mut self, kind: TokenKind) {
+        let start = self.pos;
+        self.pos = self.line.len();
+        self.push(kind, start);
+    }
+
+    /// Consume a quoted string starting at the opening quote; unterminated
+    /// strings run to the end of the line
+    pub fn quoted(&mut self, escapes: bool) {
+        let Some(quote) = self.peek() else {
+            return;
+        };
+        self.bump();
+        while let Some(c) = self.peek() {
+            self.bump();
+            if c == quote {
+                return;
+            }
+            if escapes && c == '\\' {
+                self.bump();
+            }
+        }
+    }
+
+    /// Consume a bare word made of `word` characters and classify it as a
+    /// number/literal if it looks like one
+    pub fn word(&mut self, word: impl Fn(char) -> bool) -> Option<TokenKind> {
+        let start = self.pos;
+        self.eat_while(word);
+        is_literal(&self.line[start..self.pos]).then_some(TokenKind::Number)
+    }
+}
+
+/// Numbers, dates and the usual literals
+pub(super) fn is_literal(word: &str) -> bool {
+    let unsigned = word.trim_start_matches(['+', '-']);
+    matches!(
+        word,
+        "true" | "false" | "null" | "True" | "False" | "Null" | "TRUE" | "FALSE" | "NULL" | "~"
+    ) || matches!(unsigned, "inf" | "nan" | ".inf" | ".nan")
+        || unsigned.starts_with(|c: char| c.is_ascii_digit())
+            && unsigned
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | ':' | '-' | '+'))
+}
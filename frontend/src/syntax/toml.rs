@@ -0,0 +1,122 @@
+use super::scan::Scanner;
+use super::{Token, TokenKind};
+
+/// Highlight one TOML line; `open_string` carries an unterminated multi-line
+/// string delimiter (`"""` or `'''`) over to the next line
+pub(super) fn highlight_line(line: &str, open_string: &mut Option<&'static str>) -> Vec<Token> {
+    let mut s = Scanner::new(line);
+
+    if let Some(delimiter) = *open_string {
+        let Some(end) = line.find(delimiter) else {
+            s.push_rest(TokenKind::String);
+            return s.tokens;
+        };
+        s.pos = end + delimiter.len();
+        // Up to two quotes right before the delimiter belong to the content
+        s.eat_while(|c| delimiter.starts_with(c));
+        s.push(TokenKind::String, 0);
+        *open_string = None;
+    } else {
+        s.skip_whitespace();
+        if s.starts_with("#") {
+            s.push_rest(TokenKind::Comment);
+            return s.tokens;
+        }
+        if s.starts_with("[") {
+            let start = s.pos;
+            let header = s.rest().split('#').next().unwrap_or("").trim_end();
+            s.pos += header.len();
+            s.push(TokenKind::Section, start);
+        } else {
+            key(&mut s);
+        }
+    }
+
+    value(&mut s, open_string);
+    s.tokens
+}
+
+/// `key`, `"quoted key"` or `dotted.key` before `=`
+fn key(s: &mut Scanner) {
+    let start = s.pos;
+    let mut end = start;
+    loop {
+        match s.peek() {
+            Some('"') => s.quoted(true),
+            Some('\'') => s.quoted(false),
+            _ => s.eat_while(|c| c.is_alphanumeric() || c == '_' || c == '-'),
+        }
+        end = end.max(s.pos);
+        s.skip_whitespace();
+        if s.peek() != Some('.') {
+            break;
+        }
+        s.bump();
+        s.skip_whitespace();
+    }
+
+    if s.peek() == Some('=') {
+        let after = s.pos;
+        s.pos = end;
+        s.push(TokenKind::Key, start);
+        s.pos = after;
+    } else {
+        // Not a key/value line, highlight it as values
+        s.pos = start;
+    }
+}
+
+fn value(s: &mut Scanner, open_string: &mut Option<&'static str>) {
+    while !s.at_end() {
+        s.skip_whitespace();
+        let start = s.pos;
+        match s.peek() {
+            None => break,
+            Some('#') => {
+                s.push_rest(TokenKind::Comment);
+                break;
+            }
+            Some(quote @ ('"' | '\'')) => {
+                let delimiter = if quote == '"' { "\"\"\"" } else { "'''" };
+                if s.starts_with(delimiter) {
+                    s.pos += delimiter.len();
+                    match s.rest().find(delimiter) {
+                        Some(end) => {
+                            s.pos += end + delimiter.len();
+                            s.eat_while(|c| c == quote);
+                        }
+                        None => {
+                            s.pos += s.rest().len();
+                            s.push(TokenKind::String, start);
+                            *open_string = Some(delimiter);
+                            break;
+                        }
+                    }
+                } else {
+                    s.quoted(quote == '"');
+                }
+                // Quoted keys inside inline tables
+                let kind = if s.followed_by('=') {
+                    TokenKind::Key
+                } else {
+                    TokenKind::String
+                };
+                s.push(kind, start);
+            }
+            Some('=' | ',' | '[' | ']' | '{' | '}') => s.bump(),
+            Some(_) => {
+                let literal = s.word(|c| {
+                    !c.is_whitespace() && !matches!(c, '=' | ',' | '[' | ']' | '{' | '}' | '#')
+                });
+                if s.followed_by('=') {
+                    s.push(TokenKind::Key, start);
+                } else if let Some(kind) = literal {
+                    s.push(kind, start);
+                }
+                if s.pos == start {
+                    s.bump();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use super::scan::{Scanner, is_literal};
+use super::{Token, TokenKind};
+
+pub(super) fn highlight_line(line: &str) -> Vec<Token> {
+    let mut s = Scanner::new(line);
+
+    // Document markers
+    for marker in ["---", "..."] {
+        if line.starts_with(marker) && line[3..].chars().next().is_none_or(char::is_whitespace) {
+            s.pos = 3;
+            s.push(TokenKind::Section, 0);
+        }
+    }
+
+    s.skip_whitespace();
+    // Sequence entries ("- key: value", "- - item")
+    while s.starts_with("- ") || s.rest() == "-" {
+        s.bump();
+        s.skip_whitespace();
+    }
+    key(&mut s);
+    value(&mut s);
+    s.tokens
+}
+
+/// `key:` (plain or quoted) at the start of the remaining line
+fn key(s: &mut Scanner) {
+    let start = s.pos;
+    match s.peek() {
+        Some('#') | Some('[') | Some('{') | None => return,
+        Some('"') => s.quoted(true),
+        Some('\'') => s.quoted(false),
+        Some(_) => {
+            // Plain keys end at ": " (or ":" at the end of the line)
+            let rest = s.rest();
+            let end = rest
+                .char_indices()
+                .find(|&(i, c)| {
+                    c == ':' && rest[i + 1..].chars().next().is_none_or(char::is_whitespace)
+                })
+                .map(|(i, _)| i);
+            match end {
+                Some(end) if !rest[..end].contains(" #") => s.pos += rest[..end].trim_end().len(),
+                _ => return,
+            }
+        }
+    }
+
+    if s.rest().trim_start().starts_with(':') {
+        s.push(TokenKind::Key, start);
+        s.skip_whitespace();
+        s.bump();
+    } else {
+        s.pos = start;
+    }
+}
+
+fn value(s: &mut Scanner) {
+    // Depth of flow collections ([a, b], {k: v})
+    let mut depth = 0usize;
+    while !s.at_end() {
+        let before = s.pos;
+        s.skip_whitespace();
+        let start = s.pos;
+        let after_space = start > before || start == 0;
+        match s.peek() {
+            None => break,
+            Some('#') if after_space => {
+                s.push_rest(TokenKind::Comment);
+                break;
+            }
+            Some(quote @ ('"' | '\'')) => {
+                s.quoted(quote == '"');
+                let kind = if depth > 0 && s.followed_by(':') {
+                    TokenKind::Key
+                } else {
+                    TokenKind::String
+                };
+                s.push(kind, start);
+            }
+            Some('[' | '{') => {
+                depth += 1;
+                s.bump();
+            }
+            Some(']' | '}') => {
+                depth = depth.saturating_sub(1);
+                s.bump();
+            }
+            Some(',' | ':') if depth > 0 => s.bump(),
+            Some(_) if depth > 0 => {
+                let literal = s.word(|c| !c.is_whitespace() && !matches!(c, ',' | ':' | ']' | '}'));
+                if s.followed_by(':') {
+                    s.push(TokenKind::Key, start);
+                } else if let Some(kind) = literal {
+                    s.push(kind, start);
+                }
+                if s.pos == start {
+                    s.bump();
+                }
+            }
+            Some(_) => {
+                // Plain scalar up to a comment
+                let rest = s.rest();
+                let end = rest.find(" #").unwrap_or(rest.len());
+                let scalar = rest[..end].trim_end();
+                s.pos += scalar.len();
+                if is_literal(scalar) {
+                    s.push(TokenKind::Number, start);
+                }
+                if s.pos == start {
+                    s.bump();
+                }
+            }
+        }
+    }
+}
@@ -25,6 +25,51 @@ impl ContainerListTheme {
         }
     }
 
+    pub fn project_style(theme: &ThemeConfig) -> Style {
+        theme.standard_title()
+    }
+
+    /// Aggregate color for a compose project based on its containers
+    pub fn project_status_color(
+        theme: &ThemeConfig,
+        running: usize,
+        total: usize,
+        unhealthy: usize,
+    ) -> Color {
+        if unhealthy > 0 {
+            theme.error()
+        } else if running == total {
+            theme.success()
+        } else if running == 0 {
+            theme.overlay1()
+        } else {
+            theme.modified()
+        }
+    }
+
+    /// Marker in front of containers marked for bulk actions
+    pub fn mark_style(theme: &ThemeConfig) -> Style {
+        Style::default()
+            .fg(theme.selected())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Spinner shown next to rows with a running job
+    pub fn job_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.accent())
+    }
+
+    /// Filter query shown at the bottom of the list
+    pub fn filter_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.accent())
+    }
+
+    pub fn confirm_style(theme: &ThemeConfig) -> Style {
+        Style::default()
+            .fg(theme.error())
+            .add_modifier(Modifier::BOLD)
+    }
+
     pub fn border_focused(theme: &ThemeConfig) -> Style {
         theme.standard_border_focused()
     }
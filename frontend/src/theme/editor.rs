@@ -1,6 +1,8 @@
 use super::ThemeConfig;
 use crate::state::VimMode;
-use ratzilla::ratatui::style::Style;
+use crate::syntax::TokenKind;
+use crate::validate::Severity;
+use ratzilla::ratatui::style::{Color, Modifier, Style};
 
 /// Theme styles for the text editor widget
 pub struct EditorTheme;
@@ -9,11 +11,108 @@ impl EditorTheme {
     pub fn border_style(theme: &ThemeConfig, vim_mode: VimMode, is_focused: bool) -> Style {
         if is_focused {
             match vim_mode {
-                VimMode::Normal => Style::default().fg(theme.normal_mode()),
+                VimMode::Normal | VimMode::Command => Style::default().fg(theme.normal_mode()),
                 VimMode::Insert => Style::default().fg(theme.insert_mode()),
+                VimMode::Visual | VimMode::VisualLine => Style::default().fg(theme.selected()),
             }
         } else {
             theme.standard_border_unfocused()
         }
     }
+
+    /// Selected text (visual mode)
+    pub fn selection_style(theme: &ThemeConfig) -> Style {
+        theme.standard_highlight_bg()
+    }
+
+    /// Highlighted cursor line (`cursorline`), halfway between the background
+    /// and the selection so a selection on it stays visible
+    pub fn cursor_line_style(theme: &ThemeConfig) -> Style {
+        let color = match (theme.mantle(), theme.surface1()) {
+            (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => {
+                Color::Rgb(r1.midpoint(r2), g1.midpoint(g2), b1.midpoint(b2))
+            }
+            (_, color) => color,
+        };
+        Style::default().bg(color)
+    }
+
+    /// Line numbers in the gutter
+    pub fn line_number_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.overlay1())
+    }
+
+    /// Number of the cursor line
+    pub fn current_line_number_style(theme: &ThemeConfig) -> Style {
+        Style::default()
+            .fg(theme.accent())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Tab and trailing space markers (`list`)
+    pub fn whitespace_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.overlay1())
+    }
+
+    /// Save/discard/cancel question about unsaved changes
+    pub fn leave_prompt_style(theme: &ThemeConfig) -> Style {
+        Style::default()
+            .fg(theme.modified())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// Entry of the buffer tab bar
+    pub fn tab_style(theme: &ThemeConfig, current: bool, dirty: bool) -> Style {
+        let style = if current {
+            Style::default()
+                .fg(theme.mantle())
+                .bg(theme.accent())
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.dim())
+        };
+        if dirty && !current {
+            style.fg(theme.modified())
+        } else {
+            style
+        }
+    }
+
+    /// Matches of the last search
+    pub fn search_match_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.mantle()).bg(theme.modified())
+    }
+
+    /// Text a diagnostic points at
+    pub fn diagnostic_style(theme: &ThemeConfig, severity: Severity) -> Style {
+        Style::default()
+            .fg(Self::diagnostic_color(theme, severity))
+            .add_modifier(Modifier::UNDERLINED)
+    }
+
+    /// Gutter marker of a line with a diagnostic
+    pub fn diagnostic_marker_style(theme: &ThemeConfig, severity: Severity) -> Style {
+        Style::default()
+            .fg(Self::diagnostic_color(theme, severity))
+            .add_modifier(Modifier::BOLD)
+    }
+
+    fn diagnostic_color(theme: &ThemeConfig, severity: Severity) -> Color {
+        match severity {
+            Severity::Error => theme.error(),
+            Severity::Warning => theme.modified(),
+        }
+    }
+
+    /// Style of a syntax-highlighted token
+    pub fn token_style(theme: &ThemeConfig, kind: TokenKind) -> Style {
+        let color = match kind {
+            TokenKind::Key => theme.syntax_key(),
+            TokenKind::String => theme.syntax_string(),
+            TokenKind::Number => theme.syntax_number(),
+            TokenKind::Comment => theme.syntax_comment(),
+            TokenKind::Section => theme.syntax_section(),
+        };
+        Style::default().fg(color)
+    }
 }
@@ -1,5 +1,5 @@
 use super::{SELECTED_PREFIX, ThemeConfig};
-use ratzilla::ratatui::style::Style;
+use ratzilla::ratatui::style::{Modifier, Style};
 
 /// Theme styles for the file list widget
 pub struct FileListTheme;
@@ -21,6 +21,13 @@ impl FileListTheme {
         theme.standard_normal_item()
     }
 
+    /// Container roots and directories inside containers
+    pub fn directory_style(theme: &ThemeConfig) -> Style {
+        Style::default()
+            .fg(theme.accent())
+            .add_modifier(Modifier::BOLD)
+    }
+
     pub fn selected_prefix() -> &'static str {
         SELECTED_PREFIX
     }
@@ -0,0 +1,50 @@
+use super::ThemeConfig;
+use ratzilla::ratatui::style::{Modifier, Style};
+
+/// Theme styles for the image list widget
+pub struct ImageListTheme;
+
+impl ImageListTheme {
+    pub fn id_style(theme: &ThemeConfig) -> Style {
+        theme.standard_value()
+    }
+
+    pub fn tag_style(theme: &ThemeConfig) -> Style {
+        theme.standard_normal_item()
+    }
+
+    /// Untagged images (candidates for pruning)
+    pub fn dangling_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.modified())
+    }
+
+    pub fn info_style(theme: &ThemeConfig) -> Style {
+        theme.standard_label()
+    }
+
+    /// Number of containers using an image
+    pub fn in_use_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.success())
+    }
+
+    pub fn confirm_style(theme: &ThemeConfig) -> Style {
+        Style::default()
+            .fg(theme.error())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    pub fn border_focused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_focused()
+    }
+
+    pub fn border_unfocused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_unfocused()
+    }
+
+    pub fn highlight_style(theme: &ThemeConfig) -> Style {
+        theme
+            .standard_highlight_bg()
+            .fg(theme.text())
+            .add_modifier(Modifier::BOLD)
+    }
+}
@@ -42,8 +42,11 @@
 pub mod container_list;
 pub mod editor;
 pub mod file_list;
+pub mod image_list;
 pub mod menu;
+pub mod resource_list;
 pub mod status_line;
+pub mod terminal;
 
 // Theme core modules
 mod builder;
@@ -0,0 +1,47 @@
+use super::ThemeConfig;
+use ratzilla::ratatui::style::{Modifier, Style};
+
+/// Theme styles for the volume and network lists
+pub struct ResourceListTheme;
+
+impl ResourceListTheme {
+    pub fn name_style(theme: &ThemeConfig) -> Style {
+        theme.standard_normal_item()
+    }
+
+    /// Predefined networks that cannot be removed
+    pub fn builtin_style(theme: &ThemeConfig) -> Style {
+        theme.standard_label()
+    }
+
+    pub fn info_style(theme: &ThemeConfig) -> Style {
+        theme.standard_label()
+    }
+
+    /// Number of containers using a volume or network
+    pub fn in_use_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.success())
+    }
+
+    /// Objects no container uses (candidates for removal)
+    pub fn unused_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.modified())
+    }
+
+    pub fn confirm_style(theme: &ThemeConfig) -> Style {
+        Style::default()
+            .fg(theme.error())
+            .add_modifier(Modifier::BOLD)
+    }
+
+    pub fn border_focused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_focused()
+    }
+
+    pub fn highlight_style(theme: &ThemeConfig) -> Style {
+        theme
+            .standard_highlight_bg()
+            .fg(theme.text())
+            .add_modifier(Modifier::BOLD)
+    }
+}
@@ -14,13 +14,17 @@ impl StatusLineTheme {
         match vim_mode {
             VimMode::Normal => "NORMAL",
             VimMode::Insert => "INSERT",
+            VimMode::Visual => "VISUAL",
+            VimMode::VisualLine => "V-LINE",
+            VimMode::Command => "COMMAND",
         }
     }
 
     pub fn mode_style(theme: &ThemeConfig, vim_mode: VimMode) -> Style {
         let color = match vim_mode {
-            VimMode::Normal => theme.normal_mode(),
+            VimMode::Normal | VimMode::Command => theme.normal_mode(),
             VimMode::Insert => theme.insert_mode(),
+            VimMode::Visual | VimMode::VisualLine => theme.selected(),
         };
         Style::default().fg(color).add_modifier(Modifier::BOLD)
     }
@@ -49,6 +53,16 @@ impl StatusLineTheme {
         Style::default().fg(theme.error())
     }
 
+    /// Text typed on the `:` command line
+    pub fn command_line_style(theme: &ThemeConfig) -> Style {
+        theme.standard_normal_item()
+    }
+
+    /// Character under the command line cursor
+    pub fn command_cursor_style(theme: &ThemeConfig) -> Style {
+        Self::command_line_style(theme).add_modifier(Modifier::REVERSED)
+    }
+
     pub fn help_text_style(theme: &ThemeConfig) -> Style {
         theme.standard_label()
     }
@@ -0,0 +1,20 @@
+use super::ThemeConfig;
+use ratzilla::ratatui::style::Style;
+
+/// Theme styles for the container shell pane
+pub struct TerminalTheme;
+
+impl TerminalTheme {
+    /// Default text color of the terminal (programs may override it)
+    pub fn text_style(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.text())
+    }
+
+    pub fn border_focused(theme: &ThemeConfig) -> Style {
+        Style::default().fg(theme.insert_mode())
+    }
+
+    pub fn border_unfocused(theme: &ThemeConfig) -> Style {
+        theme.standard_border_unfocused()
+    }
+}
@@ -56,4 +56,15 @@ pub struct SemanticMappings {
     pub normal_mode: String,
     pub insert_mode: String,
     pub dim: String,
+    // Editor syntax highlighting (fall back to the roles above if not set)
+    #[serde(default)]
+    pub syntax_key: Option<String>,
+    #[serde(default)]
+    pub syntax_string: Option<String>,
+    #[serde(default)]
+    pub syntax_number: Option<String>,
+    #[serde(default)]
+    pub syntax_comment: Option<String>,
+    #[serde(default)]
+    pub syntax_section: Option<String>,
 }
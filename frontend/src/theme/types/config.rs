@@ -30,6 +30,11 @@ impl ThemeConfig {
         Color::Rgb(rgb[0], rgb[1], rgb[2])
     }
 
+    /// Color of a syntax role, or of `fallback` for themes without it
+    fn syntax_color(&self, role: &Option<String>, fallback: &str) -> Color {
+        self.get_base_color(role.as_deref().unwrap_or(fallback))
+    }
+
     // Semantic color accessors
     pub fn accent(&self) -> Color {
         self.get_base_color(&self.semantic.accent)
@@ -55,6 +60,24 @@ impl ThemeConfig {
     pub fn dim(&self) -> Color {
         self.get_base_color(&self.semantic.dim)
     }
+
+    // Syntax highlighting accessors
+    pub fn syntax_key(&self) -> Color {
+        self.syntax_color(&self.semantic.syntax_key, &self.semantic.accent)
+    }
+    pub fn syntax_string(&self) -> Color {
+        self.syntax_color(&self.semantic.syntax_string, &self.semantic.success)
+    }
+    pub fn syntax_number(&self) -> Color {
+        self.syntax_color(&self.semantic.syntax_number, &self.semantic.modified)
+    }
+    pub fn syntax_comment(&self) -> Color {
+        self.syntax_color(&self.semantic.syntax_comment, &self.semantic.dim)
+    }
+    pub fn syntax_section(&self) -> Color {
+        self.syntax_color(&self.semantic.syntax_section, &self.semantic.selected)
+    }
+
     pub fn text(&self) -> Color {
         self.get_base_color("text")
     }
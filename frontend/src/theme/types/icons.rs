@@ -5,6 +5,16 @@ use serde::Deserialize;
 pub struct IconConfig {
     pub config_files: String,
     pub container: String,
+    #[serde(default = "default_icon")]
+    pub images: String,
+    #[serde(default = "default_icon")]
+    pub volumes: String,
+    #[serde(default = "default_icon")]
+    pub networks: String,
+}
+
+fn default_icon() -> String {
+    "▪".to_string()
 }
 
 /// Default icon configuration (Unicode symbols)
@@ -12,5 +22,8 @@ pub fn default_icon_config() -> IconConfig {
     IconConfig {
         config_files: "▪".to_string(), // Black small square (U+25AA)
         container: "▪".to_string(),    // Black small square (U+25AA)
+        images: default_icon(),
+        volumes: default_icon(),
+        networks: default_icon(),
     }
 }
@@ -0,0 +1,92 @@
+use crate::{
+    state::{AppState, ApplyStage},
+    theme::ThemeConfig,
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+/// Height of the apply panel below the editor
+pub const PANEL_HEIGHT: u16 = 12;
+
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let Some(apply) = &state.compose_apply else {
+        return;
+    };
+    let theme = &state.current_theme;
+    let apply_key = &state.keybinds.global.apply_compose;
+
+    let (stage_text, stage_color) = match apply.stage {
+        ApplyStage::Planning => ("validating", theme.selected()),
+        ApplyStage::Review => ("review", theme.accent()),
+        ApplyStage::Invalid => ("invalid", theme.error()),
+        ApplyStage::Running => ("running", theme.selected()),
+        ApplyStage::Done(true) => ("done", theme.success()),
+        ApplyStage::Done(false) => ("failed", theme.error()),
+    };
+
+    let mut lines = Vec::new();
+    for service in &apply.services {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {:<10} ", service.action),
+                Style::default().fg(action_color(theme, &service.action)),
+            ),
+            Span::styled(service.service.clone(), Style::default().fg(theme.text())),
+        ]));
+    }
+    if !apply.services.is_empty() && !apply.log.is_empty() {
+        lines.push(Line::from(""));
+    }
+
+    // Only the tail of the log fits into the panel
+    let available = (area.height as usize).saturating_sub(lines.len() + 3);
+    let skip = apply.log.len().saturating_sub(available);
+    for line in apply.log.iter().skip(skip) {
+        lines.push(Line::from(Span::styled(
+            line.clone(),
+            Style::default().fg(theme.dim()),
+        )));
+    }
+
+    let hint = match apply.stage {
+        ApplyStage::Review => format!("{}: compose up  Esc: close", apply_key),
+        ApplyStage::Planning | ApplyStage::Running => String::new(),
+        _ => format!("{}: re-check  Esc: close", apply_key),
+    };
+    if !hint.is_empty() {
+        lines.push(Line::from(Span::styled(
+            hint,
+            Style::default().fg(theme.dim()),
+        )));
+    }
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Line::from(vec![
+            Span::raw(format!(" Apply: {} ", apply.project)),
+            Span::styled(
+                format!("[{}] ", stage_text),
+                Style::default().fg(stage_color),
+            ),
+        ]))
+        .border_style(Style::default().fg(stage_color));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    f.render_widget(paragraph, area);
+}
+
+fn action_color(theme: &ThemeConfig, action: &str) -> ratzilla::ratatui::style::Color {
+    match action {
+        "create" => theme.success(),
+        "recreate" => theme.modified(),
+        "orphan" => theme.error(),
+        _ => theme.dim(),
+    }
+}
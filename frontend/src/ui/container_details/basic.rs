@@ -32,6 +32,41 @@ pub(super) fn add_basic_info(
         Span::styled(details.state.clone(), Style::default().fg(state_color)),
     ]));
 
+    // Exit info of the last run, once the container has stopped at least once
+    if details.state != "running" && !details.finished.is_empty() {
+        let mut exit = vec![
+            Span::styled("Exit code: ", Style::default().fg(theme.dim())),
+            Span::styled(
+                details.exit_code.to_string(),
+                Style::default().fg(if details.exit_code == 0 {
+                    theme.text()
+                } else {
+                    theme.error()
+                }),
+            ),
+        ];
+        if details.oom_killed {
+            exit.push(Span::styled(
+                " (OOM killed)",
+                Style::default().fg(theme.error()),
+            ));
+        }
+        lines.push(Line::from(exit));
+        lines.push(Line::from(vec![
+            Span::styled("Finished: ", Style::default().fg(theme.dim())),
+            Span::styled(details.finished.clone(), Style::default().fg(theme.text())),
+        ]));
+    }
+    if details.restart_count > 0 {
+        lines.push(Line::from(vec![
+            Span::styled("Restarts: ", Style::default().fg(theme.dim())),
+            Span::styled(
+                details.restart_count.to_string(),
+                Style::default().fg(theme.modified()),
+            ),
+        ]));
+    }
+
     if let Some(health) = &details.health {
         let health_color = match health.as_str() {
             "healthy" => theme.success(),
@@ -1,4 +1,7 @@
-use crate::{api::ContainerDetails, theme::ThemeConfig};
+use crate::{
+    api::{ContainerDetails, ResourceLimits},
+    theme::ThemeConfig,
+};
 use ratzilla::ratatui::{
     style::Style,
     text::{Line, Span},
@@ -9,6 +12,9 @@ pub(super) fn add_config_info(
     details: &ContainerDetails,
     theme: &ThemeConfig,
 ) {
+    add_runtime_info(lines, details, theme);
+    add_limits(lines, &details.limits, theme);
+
     lines.push(Line::from(vec![
         Span::styled("Restart: ", Style::default().fg(theme.dim())),
         Span::styled(
@@ -49,4 +55,106 @@ pub(super) fn add_config_info(
             )));
         }
     }
+
+    if !details.labels.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Labels:",
+            Style::default().fg(theme.selected()),
+        )));
+        for (key, value) in &details.labels {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {}", key), Style::default().fg(theme.dim())),
+                Span::styled(format!("={}", value), Style::default().fg(theme.text())),
+            ]));
+        }
+    }
+}
+
+fn field(label: &str, value: String, theme: &ThemeConfig) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(format!("{}: ", label), Style::default().fg(theme.dim())),
+        Span::styled(value, Style::default().fg(theme.text())),
+    ])
+}
+
+/// Entrypoint, command, working directory and user (only those that are set)
+fn add_runtime_info(
+    lines: &mut Vec<Line<'static>>,
+    details: &ContainerDetails,
+    theme: &ThemeConfig,
+) {
+    let before = lines.len();
+    if !details.entrypoint.is_empty() {
+        lines.push(field("Entrypoint", details.entrypoint.join(" "), theme));
+    }
+    if !details.command.is_empty() {
+        lines.push(field("Command", details.command.join(" "), theme));
+    }
+    if !details.working_dir.is_empty() {
+        lines.push(field("Working dir", details.working_dir.clone(), theme));
+    }
+    if !details.user.is_empty() {
+        lines.push(field("User", details.user.clone(), theme));
+    }
+    if lines.len() > before {
+        lines.push(Line::from(""));
+    }
+}
+
+fn add_limits(lines: &mut Vec<Line<'static>>, limits: &ResourceLimits, theme: &ThemeConfig) {
+    let before = lines.len();
+    if limits.memory > 0 {
+        lines.push(field("Memory limit", format_bytes(limits.memory), theme));
+    }
+    if limits.memory_reservation > 0 {
+        lines.push(field(
+            "Memory reservation",
+            format_bytes(limits.memory_reservation),
+            theme,
+        ));
+    }
+    if limits.nano_cpus > 0 {
+        lines.push(field(
+            "CPUs",
+            format!("{}", limits.nano_cpus as f64 / 1e9),
+            theme,
+        ));
+    }
+    if limits.cpu_quota > 0 {
+        // The period defaults to 100ms when only a quota is set
+        let period = if limits.cpu_period > 0 {
+            limits.cpu_period
+        } else {
+            100_000
+        };
+        lines.push(field(
+            "CPU quota",
+            format!(
+                "{}/{}µs ({} CPUs)",
+                limits.cpu_quota,
+                period,
+                limits.cpu_quota as f64 / period as f64
+            ),
+            theme,
+        ));
+    }
+    if limits.cpu_shares > 0 && limits.cpu_shares != 1024 {
+        lines.push(field("CPU shares", limits.cpu_shares.to_string(), theme));
+    }
+    if lines.len() > before {
+        lines.push(Line::from(""));
+    }
+}
+
+/// Binary units, as used for docker memory limits (`512m` = 512MiB)
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.0}{}", size, UNITS[unit])
 }
@@ -0,0 +1,58 @@
+use crate::{api::ContainerDetails, theme::ThemeConfig};
+use ratzilla::ratatui::{
+    style::Style,
+    text::{Line, Span},
+};
+
+pub(super) fn add_health_info(
+    lines: &mut Vec<Line<'static>>,
+    details: &ContainerDetails,
+    theme: &ThemeConfig,
+) {
+    let Some(check) = &details.healthcheck else {
+        return;
+    };
+
+    lines.push(Line::from(Span::styled(
+        "Healthcheck:",
+        Style::default().fg(theme.selected()),
+    )));
+    // Test is ["CMD", ...] or ["CMD-SHELL", "command"]
+    let test = match check.test.split_first() {
+        Some((kind, rest)) if kind.starts_with("CMD") => rest.join(" "),
+        _ => check.test.join(" "),
+    };
+    lines.push(Line::from(vec![
+        Span::raw("  "),
+        Span::styled(test, Style::default().fg(theme.text())),
+    ]));
+    lines.push(Line::from(Span::styled(
+        format!(
+            "  every {}, timeout {}, start period {}, {} retries",
+            check.interval, check.timeout, check.start_period, check.retries
+        ),
+        Style::default().fg(theme.dim()),
+    )));
+
+    // Most recent runs first
+    for entry in details.health_log.iter().rev() {
+        let color = if entry.exit_code == 0 {
+            theme.success()
+        } else {
+            theme.error()
+        };
+        let output = entry.output.lines().next().unwrap_or("").to_string();
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {} ", entry.start.get(..19).unwrap_or(&entry.start)),
+                Style::default().fg(theme.dim()),
+            ),
+            Span::styled(
+                format!("exit {}", entry.exit_code),
+                Style::default().fg(color),
+            ),
+            Span::styled(format!(" {}", output), Style::default().fg(theme.text())),
+        ]));
+    }
+    lines.push(Line::from(""));
+}
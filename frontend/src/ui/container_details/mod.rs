@@ -1,6 +1,9 @@
 mod basic;
 mod config;
+mod health;
 mod network;
+mod raw;
+mod settings;
 mod storage;
 
 use crate::state::AppState;
@@ -13,19 +16,44 @@ use ratzilla::ratatui::{
 
 pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
     let theme = &state.current_theme;
+    let list = &state.container_list;
+    let title = if list.settings_form.is_some() {
+        " Container Settings "
+    } else if list.raw_inspect {
+        " Container Details (raw) "
+    } else {
+        " Container Details "
+    };
     let block = Block::default()
         .borders(Borders::ALL)
-        .title(" Container Details ")
+        .title(title)
         .border_style(Style::default().fg(theme.dim()));
 
-    if let Some(details) = &state.container_details {
-        let mut lines = Vec::new();
-        basic::add_basic_info(&mut lines, details, theme);
-        network::add_network_info(&mut lines, details, theme);
-        storage::add_storage_info(&mut lines, details, theme);
-        config::add_config_info(&mut lines, details, theme);
+    if let Some(form) = &list.settings_form {
+        let paragraph = Paragraph::new(settings::form_lines(form, theme))
+            .block(block)
+            .wrap(Wrap { trim: true });
+        f.render_widget(paragraph, area);
+    } else if let Some(details) = &state.container_details {
+        let lines = if list.raw_inspect {
+            raw::raw_lines(details, theme)
+        } else {
+            let mut lines = Vec::new();
+            basic::add_basic_info(&mut lines, details, theme);
+            network::add_network_info(&mut lines, details, theme);
+            storage::add_storage_info(&mut lines, details, theme);
+            health::add_health_info(&mut lines, details, theme);
+            config::add_config_info(&mut lines, details, theme);
+            lines
+        };
 
-        let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: true });
+        // Keep indentation in the raw JSON view
+        let paragraph = Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap {
+                trim: !list.raw_inspect,
+            })
+            .scroll((list.details_scroll, 0));
         f.render_widget(paragraph, area);
     } else {
         let paragraph = Paragraph::new("No container selected")
@@ -15,19 +15,29 @@ pub(super) fn add_network_info(
             Style::default().fg(theme.selected()),
         )));
         for port in &details.ports {
-            lines.push(Line::from(vec![
-                Span::raw("  "),
-                Span::styled(port.host_port.clone(), Style::default().fg(theme.text())),
-                Span::raw(" → "),
-                Span::styled(
-                    port.container_port.clone(),
+            let mut spans = vec![Span::raw("  ")];
+            if let Some(host_port) = &port.host_port {
+                spans.push(Span::styled(
+                    host_address(port.host_ip.as_deref(), host_port),
                     Style::default().fg(theme.text()),
-                ),
-                Span::styled(
-                    format!("/{}", port.protocol),
+                ));
+                spans.push(Span::raw(" → "));
+            }
+            spans.push(Span::styled(
+                port.container_port.clone(),
+                Style::default().fg(theme.text()),
+            ));
+            spans.push(Span::styled(
+                format!("/{}", port.protocol),
+                Style::default().fg(theme.dim()),
+            ));
+            if port.host_port.is_none() {
+                spans.push(Span::styled(
+                    " (not published)",
                     Style::default().fg(theme.dim()),
-                ),
-            ]));
+                ));
+            }
+            lines.push(Line::from(spans));
         }
         lines.push(Line::from(""));
     }
@@ -43,3 +53,12 @@ pub(super) fn add_network_info(
         lines.push(Line::from(""));
     }
 }
+
+/// `ip:port`, with IPv6 addresses in brackets (`[::]:8080`)
+fn host_address(ip: Option<&str>, port: &str) -> String {
+    match ip {
+        Some(ip) if ip.contains(':') => format!("[{}]:{}", ip, port),
+        Some(ip) if !ip.is_empty() => format!("{}:{}", ip, port),
+        _ => port.to_string(),
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{api::ContainerDetails, theme::ThemeConfig};
+use ratzilla::ratatui::{style::Style, text::Line};
+use serde_json::Value;
+
+/// Pretty-printed inspect JSON; environment values are masked like in the
+/// summary view
+pub(super) fn raw_lines(details: &ContainerDetails, theme: &ThemeConfig) -> Vec<Line<'static>> {
+    let mut raw = details.raw.clone();
+    if let Some(env) = raw
+        .pointer_mut("/Config/Env")
+        .and_then(|e| e.as_array_mut())
+    {
+        for entry in env.iter_mut() {
+            if let Some((key, _)) = entry.as_str().and_then(|s| s.split_once('=')) {
+                *entry = Value::String(format!("{}=***", key));
+            }
+        }
+    }
+
+    serde_json::to_string_pretty(&raw)
+        .unwrap_or_default()
+        .lines()
+        .map(|l| Line::styled(l.to_string(), Style::default().fg(theme.text())))
+        .collect()
+}
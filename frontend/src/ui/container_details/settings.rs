@@ -0,0 +1,63 @@
+use crate::{state::SettingsForm, theme::ThemeConfig};
+use ratzilla::ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+/// Lines of the restart policy / limits form
+pub(super) fn form_lines(form: &SettingsForm, theme: &ThemeConfig) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("Settings of {}", form.container_name),
+            Style::default()
+                .fg(theme.accent())
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+
+    for (i, field) in form.fields.iter().enumerate() {
+        let focused = i == form.focused;
+        let label_style = if focused {
+            Style::default()
+                .fg(theme.accent())
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.dim())
+        };
+        let cursor = if focused { "▏" } else { "" };
+        let marker = if field.changed() { " *" } else { "" };
+
+        lines.push(Line::from(vec![
+            Span::styled(format!("{}: ", field.label), label_style),
+            Span::styled(
+                format!("{}{}", field.value, cursor),
+                Style::default().fg(theme.text()),
+            ),
+            Span::styled(marker, Style::default().fg(theme.modified())),
+        ]));
+        lines.push(Line::from(Span::styled(
+            format!("  {}", field.hint),
+            Style::default().fg(theme.dim()),
+        )));
+    }
+
+    lines.push(Line::from(""));
+    if let Some(error) = &form.error {
+        lines.push(Line::from(Span::styled(
+            error.clone(),
+            Style::default().fg(theme.error()),
+        )));
+    } else if form.submitting {
+        lines.push(Line::from(Span::styled(
+            "Applying...",
+            Style::default().fg(theme.dim()),
+        )));
+    }
+    lines.push(Line::from(Span::styled(
+        "Enter: apply  Esc: cancel  Tab/↑/↓: field",
+        Style::default().fg(theme.dim()),
+    )));
+
+    lines
+}
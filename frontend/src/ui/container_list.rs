@@ -1,46 +1,43 @@
 use crate::{
-    state::{AppState, Pane},
-    theme::container_list::ContainerListTheme,
+    api::{ContainerInfo, JobInfo, ProjectInfo},
+    state::{AppState, ContainerRow, Pane, jobs},
+    theme::{ThemeConfig, container_list::ContainerListTheme},
 };
 use ratzilla::ratatui::{
     Frame,
     layout::Rect,
-    text::Line,
+    style::Style,
+    text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState},
 };
 
 pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
     let theme = &state.current_theme;
     let is_focused = state.focus == Pane::ContainerList;
+    let container_list = &state.container_list;
 
-    let items: Vec<ListItem> = state
-        .container_list
-        .containers
-        .iter()
-        .map(|container| {
-            let status_color = ContainerListTheme::status_color(theme, &container.state);
-
-            let short_id = &container.id[..12.min(container.id.len())];
-            let line = Line::from(vec![
-                ratzilla::ratatui::text::Span::styled(
-                    format!("{:<12} ", short_id),
-                    ContainerListTheme::id_style(theme),
-                ),
-                ratzilla::ratatui::text::Span::styled(
-                    format!("{:<15} ", container.name),
-                    ContainerListTheme::name_style(theme),
-                ),
-                ratzilla::ratatui::text::Span::styled(
-                    format!("[{}] ", container.state),
-                    ratzilla::ratatui::style::Style::default().fg(status_color),
-                ),
-                ratzilla::ratatui::text::Span::styled(
-                    &container.status,
-                    ContainerListTheme::status_info_style(theme),
-                ),
-            ]);
-
-            ListItem::new(line)
+    let items: Vec<ListItem> = container_list
+        .rows()
+        .into_iter()
+        .map(|row| match row {
+            ContainerRow::Project(pi) => {
+                let project = &container_list.projects[pi];
+                let collapsed = container_list.collapsed.contains(&project.name);
+                let mut line = project_line(project, collapsed, theme);
+                if let Some(job) = state.jobs.for_project(&project.name) {
+                    line.push_span(job_span(job, theme));
+                }
+                ListItem::new(line)
+            }
+            ContainerRow::Container(ci) => {
+                let container = &container_list.containers[ci];
+                let marked = container_list.marked.contains(&container.id);
+                let mut line = container_line(container, marked, theme);
+                if let Some(job) = state.jobs.for_container(&container.id) {
+                    line.push_span(job_span(job, theme));
+                }
+                ListItem::new(line)
+            }
         })
         .collect();
 
@@ -50,17 +47,112 @@ pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
         ContainerListTheme::border_unfocused(theme)
     };
 
-    let block = Block::default()
+    let view = &container_list.view;
+    let mut title = " Containers".to_string();
+    if !container_list.marked.is_empty() {
+        title.push_str(&format!(" ({} marked)", container_list.marked.len()));
+    }
+    title.push_str(&format!(" · sort: {}", view.sort.label()));
+    if view.running_only {
+        title.push_str(" · running only");
+    }
+    title.push(' ');
+    let mut block = Block::default()
         .borders(Borders::ALL)
-        .title(" Containers ")
+        .title(title)
         .border_style(border_style);
 
+    // Confirmation prompt for destructive actions
+    if let Some(pending) = &container_list.pending {
+        block = block.title_bottom(Span::styled(
+            format!(" {} ", pending.prompt()),
+            ContainerListTheme::confirm_style(theme),
+        ));
+    } else if container_list.filter_input {
+        block = block.title_bottom(Span::styled(
+            format!(" /{}▏ ", view.query),
+            ContainerListTheme::filter_style(theme),
+        ));
+    } else if !view.query.is_empty() {
+        block = block.title_bottom(Span::styled(
+            format!(" filter: {} ", view.query),
+            ContainerListTheme::filter_style(theme),
+        ));
+    }
+
     let list = List::new(items)
         .block(block)
         .highlight_style(ContainerListTheme::highlight_style(theme));
 
     let mut list_state = ListState::default();
-    list_state.select(Some(state.container_list.selected_index));
+    list_state.select(Some(container_list.selected_index));
 
     f.render_stateful_widget(list, area, &mut list_state);
 }
+
+fn project_line(project: &ProjectInfo, collapsed: bool, theme: &ThemeConfig) -> Line<'static> {
+    let total = project.containers.len();
+    let status_color =
+        ContainerListTheme::project_status_color(theme, project.running, total, project.unhealthy);
+    let marker = if collapsed { "▸" } else { "▾" };
+
+    let mut spans = vec![
+        Span::styled(
+            format!("{} {} ", marker, project.name),
+            ContainerListTheme::project_style(theme),
+        ),
+        Span::styled(
+            format!("[{}/{} running]", project.running, total),
+            Style::default().fg(status_color),
+        ),
+    ];
+    if project.unhealthy > 0 {
+        spans.push(Span::styled(
+            format!(" {} unhealthy", project.unhealthy),
+            Style::default().fg(theme.error()),
+        ));
+    }
+
+    Line::from(spans)
+}
+
+fn container_line<'a>(container: &'a ContainerInfo, marked: bool, theme: &ThemeConfig) -> Line<'a> {
+    let status_color = ContainerListTheme::status_color(theme, &container.state);
+
+    // Indent containers that belong to a compose project
+    let indent = if container.project.is_some() {
+        "  "
+    } else {
+        ""
+    };
+    let short_id = &container.id[..12.min(container.id.len())];
+    let mark = if marked { "● " } else { "  " };
+    Line::from(vec![
+        Span::raw(indent),
+        Span::styled(mark, ContainerListTheme::mark_style(theme)),
+        Span::styled(
+            format!("{:<12} ", short_id),
+            ContainerListTheme::id_style(theme),
+        ),
+        Span::styled(
+            format!("{:<15} ", container.name),
+            ContainerListTheme::name_style(theme),
+        ),
+        Span::styled(
+            format!("[{}] ", container.state),
+            Style::default().fg(status_color),
+        ),
+        Span::styled(
+            &container.status,
+            ContainerListTheme::status_info_style(theme),
+        ),
+    ])
+}
+
+/// Spinner with the action of a job running on a container or project
+fn job_span(job: &JobInfo, theme: &ThemeConfig) -> Span<'static> {
+    Span::styled(
+        format!(" {} {}", jobs::spinner(), job.action),
+        ContainerListTheme::job_style(theme),
+    )
+}
@@ -0,0 +1,94 @@
+mod tabs;
+mod text;
+
+use crate::{
+    state::{AppState, Buffer, Pane},
+    theme::editor::EditorTheme,
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    text::Span,
+    widgets::{Block, Borders},
+};
+
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    // Open files above the text when there is more than one
+    let area = if state.editor.buffer_count() > 1 {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(area);
+        tabs::render(f, state, chunks[0]);
+        chunks[1]
+    } else {
+        area
+    };
+
+    let Some(other) = state.editor.split_buffer() else {
+        render_current(f, state, area);
+        return;
+    };
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+    // Both sides keep their buffer order from left to right
+    let other_first = state.editor.buffer_index(&other.name) < Some(state.editor.active);
+    let (current_area, other_area) = if other_first {
+        (columns[1], columns[0])
+    } else {
+        (columns[0], columns[1])
+    };
+    render_current(f, state, current_area);
+    render_other(f, state, other_area, other);
+}
+
+fn render_current(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let is_focused = state.focus == Pane::Editor;
+    let border_style = EditorTheme::border_style(theme, state.vim_mode, is_focused);
+
+    let title = if let Some(filename) = &state.editor.current_file {
+        let new_marker = if state.editor.new_file { " [new]" } else { "" };
+        let dirty_marker = if state.dirty { " [+]" } else { "" };
+        format!("{}{}{}", filename, new_marker, dirty_marker)
+    } else {
+        "No file loaded".to_string()
+    };
+
+    let mut block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border_style);
+
+    // List containers that mount this file
+    let users = state.current_file_users();
+    if let Some(prompt) = &state.leave_prompt {
+        block = block.title_bottom(Span::styled(
+            format!(" {} ", prompt.prompt()),
+            EditorTheme::leave_prompt_style(theme),
+        ));
+    } else if !users.is_empty() {
+        let names: Vec<&str> = users.iter().map(|u| u.name.as_str()).collect();
+        block = block.title_bottom(format!(" used by: {} ", names.join(", ")));
+    }
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    text::render(f, state, inner);
+}
+
+/// The other side of a split, shown but not edited
+fn render_other(f: &mut Frame, state: &AppState, area: Rect, buffer: &Buffer) {
+    let new_marker = if buffer.new_file { " [new]" } else { "" };
+    let dirty_marker = if buffer.dirty { " [+]" } else { "" };
+    let block = Block::default()
+        .title(format!("{}{}{}", buffer.name, new_marker, dirty_marker))
+        .borders(Borders::ALL)
+        .border_style(state.current_theme.standard_border_unfocused());
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    text::render_buffer(f, state, inner, buffer);
+}
@@ -0,0 +1,43 @@
+use crate::{state::AppState, theme::editor::EditorTheme};
+use ratzilla::ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+/// One line with the open files, numbered for `:b N`; tabs scroll off to the
+/// left so the current one stays visible
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let active = state.editor.active;
+    let tabs: Vec<(String, bool)> = state
+        .open_buffers()
+        .iter()
+        .enumerate()
+        .map(|(i, (name, dirty))| {
+            let marker = if *dirty { " +" } else { "" };
+            (format!(" {} {}{} ", i + 1, name, marker), *dirty)
+        })
+        .collect();
+
+    let width = area.width as usize;
+    let widths: Vec<usize> = tabs
+        .iter()
+        .map(|(text, _)| text.chars().count() + 1)
+        .collect();
+    let mut first = 0;
+    while first < active && widths[first..=active].iter().sum::<usize>() > width {
+        first += 1;
+    }
+
+    let mut spans = Vec::new();
+    for (i, (text, dirty)) in tabs.into_iter().enumerate().skip(first) {
+        spans.push(Span::styled(
+            text,
+            EditorTheme::tab_style(theme, i == active, dirty),
+        ));
+        spans.push(Span::raw(" "));
+    }
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
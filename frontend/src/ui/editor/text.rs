@@ -0,0 +1,337 @@
+use crate::{
+    state::{AppState, Buffer, EditorOptions, TextRange},
+    syntax::{self, Language, Token},
+    theme::editor::EditorTheme,
+    validate::{self, Diagnostic, Severity},
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+use regex_lite::Regex;
+use std::cell::Cell;
+use tui_textarea::TextArea;
+
+/// Width of the diagnostics gutter
+const GUTTER_WIDTH: usize = 2;
+
+/// Digits the line number gutter makes room for at least
+const MIN_NUMBER_DIGITS: usize = 3;
+
+/// Text of a buffer as drawn
+struct View<'a> {
+    textarea: &'a TextArea<'static>,
+    filename: Option<&'a str>,
+    viewport: &'a Cell<(usize, usize)>,
+    diagnostics: &'a [Diagnostic],
+    options: EditorOptions,
+    /// The current buffer shows its cursor and selection
+    current: bool,
+}
+
+/// Render the current buffer
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let editor = &state.editor;
+    let view = View {
+        textarea: &editor.textarea,
+        filename: editor.current_file.as_deref(),
+        viewport: &editor.viewport,
+        diagnostics: &editor.diagnostics,
+        options: editor.options,
+        current: true,
+    };
+    draw(f, state, area, view);
+}
+
+/// Render a background buffer (the other side of a split)
+pub fn render_buffer(f: &mut Frame, state: &AppState, area: Rect, buffer: &Buffer) {
+    let view = View {
+        textarea: &buffer.textarea,
+        filename: Some(&buffer.name),
+        viewport: &buffer.viewport,
+        diagnostics: &buffer.diagnostics,
+        options: state.editor.options_for(Some(&buffer.name)),
+        current: false,
+    };
+    draw(f, state, area, view);
+}
+
+/// Draw a buffer with line numbers, syntax highlighting, diagnostics, search
+/// matches, selection and cursor
+///
+/// `tui_textarea` can't style individual tokens, so the text is drawn here
+/// from its lines and cursor. The viewport follows the cursor like the
+/// textarea's own one does.
+fn draw(f: &mut Frame, state: &AppState, area: Rect, view: View) {
+    let editor = &state.editor;
+    let textarea = view.textarea;
+    let options = view.options;
+    let lines = textarea.lines();
+    let tab_length = options.tab_width.max(1) as usize;
+    let height = area.height as usize;
+    let language = view.filename.and_then(Language::from_filename);
+    // Files that are validated get a gutter for diagnostic markers
+    let marker_width = if language.is_some_and(validate::supports) {
+        GUTTER_WIDTH
+    } else {
+        0
+    };
+    let number_width = if options.number || options.relative_number {
+        lines.len().to_string().len().max(MIN_NUMBER_DIGITS) + 1
+    } else {
+        0
+    };
+    let width = (area.width as usize).saturating_sub(marker_width + number_width);
+    if height == 0 || width == 0 {
+        return;
+    }
+
+    let (cursor_row, cursor_col) = textarea.cursor();
+    let (mut top, mut left) = view.viewport.get();
+    if cursor_row < top {
+        top = cursor_row;
+    } else if cursor_row >= top + height {
+        top = cursor_row + 1 - height;
+    }
+    let cursor_x = lines
+        .get(cursor_row)
+        .map_or(0, |line| display_column(line, cursor_col, tab_length));
+    if options.wrap {
+        // Wrapped lines take several rows; scroll until the cursor's one fits
+        left = 0;
+        let screen_rows = |top: usize| {
+            (top..cursor_row)
+                .map(|row| wrapped_rows(&lines[row], tab_length, width))
+                .sum::<usize>()
+                + cursor_x / width
+                + 1
+        };
+        while top < cursor_row && screen_rows(top) > height {
+            top += 1;
+        }
+    } else if cursor_x < left {
+        left = cursor_x;
+    } else if cursor_x >= left + width {
+        left = cursor_x + 1 - width;
+    }
+    view.viewport.set((top, left));
+
+    let bottom = (top + height).min(lines.len());
+    let tokens = language
+        .map(|language| syntax::highlight(language, lines, bottom))
+        .unwrap_or_default();
+
+    let theme = &state.current_theme;
+    let selection = editor.visual_range(state.vim_mode).filter(|_| view.current);
+    let search = editor
+        .search
+        .as_ref()
+        .filter(|_| editor.highlight_search)
+        .map(|search| &search.regex);
+    let mut rendered: Vec<Line> = Vec::new();
+    for (row, line) in lines.iter().enumerate().take(bottom).skip(top) {
+        let mut cells = Vec::new();
+        let line_tokens = tokens.get(row).map(Vec::as_slice).unwrap_or_default();
+        let line_matches = search.map_or_else(Vec::new, |regex| matches(line, regex));
+        let line_diagnostics: Vec<&Diagnostic> =
+            view.diagnostics.iter().filter(|d| d.line == row).collect();
+        let is_cursor_row = view.current && row == cursor_row;
+        let base = if !is_cursor_row {
+            textarea.style()
+        } else if options.cursor_line {
+            textarea
+                .style()
+                .patch(EditorTheme::cursor_line_style(theme))
+        } else {
+            textarea.style().patch(textarea.cursor_line_style())
+        };
+        let trailing = line.trim_end_matches([' ', '\t']).chars().count();
+
+        for (col, (byte, c)) in line.char_indices().enumerate() {
+            let mut style = token_at(line_tokens, byte).map_or(base, |token| {
+                base.patch(EditorTheme::token_style(theme, token))
+            });
+            let whitespace = options.list && (c == '\t' || (c == ' ' && col >= trailing));
+            if whitespace {
+                style = style.patch(EditorTheme::whitespace_style(theme));
+            }
+            if line_matches
+                .iter()
+                .any(|&(start, end)| (start..end).contains(&col))
+            {
+                style = style.patch(EditorTheme::search_match_style(theme));
+            }
+            if selection.is_some_and(|range| selected(range, row, col)) {
+                style = style.patch(EditorTheme::selection_style(theme));
+            }
+            if is_cursor_row && col == cursor_col {
+                style = style.patch(textarea.cursor_style());
+            }
+
+            if c == '\t' {
+                let spaces = tab_length - cells.len() % tab_length;
+                cells.push((if whitespace { '→' } else { ' ' }, style));
+                cells.extend(std::iter::repeat_n((' ', style), spaces - 1));
+            } else if whitespace {
+                cells.push(('·', style));
+            } else {
+                cells.push((c, style));
+            }
+        }
+        // Cursor past the end of the line; selected empty lines and
+        // diagnostics at the end of a line (missing text) stay visible
+        let len = line.chars().count();
+        let past_end = line_diagnostics.iter().find(|d| d.start >= len);
+        if is_cursor_row && cursor_col >= len {
+            cells.push((' ', base.patch(textarea.cursor_style())));
+        } else if len == 0 && selection.is_some_and(|range| selected(range, row, 0)) {
+            cells.push((' ', base.patch(EditorTheme::selection_style(theme))));
+        } else if let Some(diagnostic) = past_end {
+            let style = EditorTheme::diagnostic_style(theme, diagnostic.severity);
+            cells.push((' ', base.patch(style)));
+        }
+
+        let mut chunks: Vec<Vec<(char, Style)>> = if options.wrap {
+            cells.chunks(width).map(<[_]>::to_vec).collect()
+        } else {
+            vec![cells.into_iter().skip(left).take(width).collect()]
+        };
+        if chunks.is_empty() {
+            chunks.push(Vec::new());
+        }
+        for (index, mut chunk) in chunks.into_iter().enumerate() {
+            // The highlighted cursor line spans the whole width
+            if is_cursor_row && options.cursor_line {
+                chunk.resize(width, (' ', base));
+            }
+            let mut line = spans(chunk.into_iter());
+            if number_width > 0 {
+                let number = if index == 0 {
+                    line_number(row, cursor_row, options, number_width - 1)
+                } else {
+                    " ".repeat(number_width - 1)
+                };
+                let style = if is_cursor_row {
+                    EditorTheme::current_line_number_style(theme)
+                } else {
+                    EditorTheme::line_number_style(theme)
+                };
+                line.insert(0, Span::styled(format!("{} ", number), style));
+            }
+            if marker_width > 0 {
+                let marker = if index == 0 {
+                    marker(&line_diagnostics, textarea, state)
+                } else {
+                    Span::styled(" ".repeat(GUTTER_WIDTH), textarea.style())
+                };
+                line.insert(0, marker);
+            }
+            rendered.push(Line::from(line));
+        }
+        if rendered.len() >= height {
+            break;
+        }
+    }
+    rendered.truncate(height);
+
+    f.render_widget(Paragraph::new(rendered), area);
+}
+
+/// Number shown next to a line: relative to the cursor line with
+/// `relativenumber`, where the cursor line itself shows its own number if
+/// `number` is also on (and 0 otherwise)
+fn line_number(row: usize, cursor_row: usize, options: EditorOptions, width: usize) -> String {
+    let number = if !options.relative_number {
+        row + 1
+    } else if row != cursor_row {
+        row.abs_diff(cursor_row)
+    } else if options.number {
+        return format!("{:<width$}", row + 1);
+    } else {
+        0
+    };
+    format!("{:>width$}", number)
+}
+
+/// Screen rows a line takes when wrapped
+fn wrapped_rows(line: &str, tab_length: usize, width: usize) -> usize {
+    display_column(line, line.chars().count(), tab_length)
+        .div_ceil(width)
+        .max(1)
+}
+
+/// `E>` or `W>` in the gutter of a line with an error or a warning
+fn marker(diagnostics: &[&Diagnostic], textarea: &TextArea, state: &AppState) -> Span<'static> {
+    let Some(diagnostic) = diagnostics.first() else {
+        return Span::styled(" ".repeat(GUTTER_WIDTH), textarea.style());
+    };
+    let text = match diagnostic.severity {
+        Severity::Error => "E>",
+        Severity::Warning => "W>",
+    };
+    Span::styled(
+        text,
+        EditorTheme::diagnostic_marker_style(&state.current_theme, diagnostic.severity),
+    )
+}
+
+/// Screen column of a character index, with tabs expanded
+fn display_column(line: &str, col: usize, tab_length: usize) -> usize {
+    line.chars().take(col).fold(0, |x, c| {
+        if c == '\t' {
+            x + tab_length - x % tab_length
+        } else {
+            x + 1
+        }
+    })
+}
+
+fn selected(range: TextRange, row: usize, col: usize) -> bool {
+    match range {
+        TextRange::Chars { start, end } => (row, col) >= start && (row, col) <= end,
+        TextRange::Lines { start, end } => (start..=end).contains(&row),
+    }
+}
+
+/// Character ranges `[start, end)` of the non-empty matches in a line
+fn matches(line: &str, regex: &Regex) -> Vec<(usize, usize)> {
+    regex
+        .find_iter(line)
+        .filter(|m| !m.is_empty())
+        .map(|m| {
+            let start = line[..m.start()].chars().count();
+            (start, start + m.as_str().chars().count())
+        })
+        .collect()
+}
+
+fn token_at(tokens: &[Token], byte: usize) -> Option<syntax::TokenKind> {
+    tokens
+        .iter()
+        .find(|t| t.start <= byte && byte < t.end)
+        .map(|t| t.kind)
+}
+
+/// Merge runs of equally styled characters into spans
+fn spans(cells: impl Iterator<Item = (char, Style)>) -> Vec<Span<'static>> {
+    let mut spans: Vec<Span> = Vec::new();
+    let mut text = String::new();
+    let mut current = None;
+    for (c, style) in cells {
+        if current.is_some_and(|s| s != style) {
+            spans.push(Span::styled(
+                std::mem::take(&mut text),
+                current.unwrap_or_default(),
+            ));
+        }
+        current = Some(style);
+        text.push(c);
+    }
+    if let Some(style) = current {
+        spans.push(Span::styled(text, style));
+    }
+    spans
+}
@@ -1,5 +1,6 @@
 use crate::{
-    state::{AppState, Pane},
+    state::file_list::CONTAINER_FILE_PREFIX,
+    state::{AppState, FileRow, Pane},
     theme::file_list::FileListTheme,
 };
 use ratzilla::ratatui::{
@@ -21,20 +22,43 @@ pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
 
     let items: Vec<ListItem> = state
         .file_list
-        .files
-        .iter()
-        .map(|file| {
-            ListItem::new(Line::from(vec![Span::styled(
-                &file.name,
-                FileListTheme::normal_item_style(theme),
-            )]))
+        .rows()
+        .into_iter()
+        .map(|row| {
+            let span = match row {
+                FileRow::File(file) => {
+                    Span::styled(file.name.clone(), FileListTheme::normal_item_style(theme))
+                }
+                FileRow::Container(name) => Span::styled(
+                    format!("{}{}/", CONTAINER_FILE_PREFIX, name),
+                    FileListTheme::directory_style(theme),
+                ),
+                FileRow::Parent => Span::styled("../", FileListTheme::directory_style(theme)),
+                FileRow::Entry(entry) if entry.dir => Span::styled(
+                    format!("{}/", entry.name),
+                    FileListTheme::directory_style(theme),
+                ),
+                FileRow::Entry(entry) => {
+                    Span::styled(entry.name.clone(), FileListTheme::normal_item_style(theme))
+                }
+            };
+            ListItem::new(Line::from(vec![span]))
         })
         .collect();
 
+    // Title shows where we are inside a container
+    let title = match &state.file_list.browse {
+        Some(browse) => format!(
+            "{}{}:{}",
+            CONTAINER_FILE_PREFIX, browse.container, browse.path
+        ),
+        None => "Config Files".to_string(),
+    };
+
     let list = List::new(items)
         .block(
             Block::default()
-                .title("Config Files")
+                .title(title)
                 .borders(Borders::ALL)
                 .border_style(border_style),
         )
@@ -0,0 +1,138 @@
+use crate::{
+    api::{ImageDetails, ImageInfo},
+    state::AppState,
+    theme::ThemeConfig,
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Image Details ")
+        .border_style(Style::default().fg(theme.dim()));
+
+    let Some(image) = state.images.selected() else {
+        let paragraph = Paragraph::new("No image selected")
+            .block(block)
+            .style(Style::default().fg(theme.dim()));
+        f.render_widget(paragraph, area);
+        return;
+    };
+
+    let mut lines = Vec::new();
+    add_summary(&mut lines, image, theme);
+    add_containers(&mut lines, &image.containers, theme);
+    if let Some(details) = state.images.selected_details() {
+        add_inspect(&mut lines, details, theme);
+    } else {
+        lines.push(Line::from(Span::styled(
+            format!("{}: inspect", state.keybinds.images.inspect),
+            Style::default().fg(theme.dim()),
+        )));
+    }
+
+    let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: true });
+    f.render_widget(paragraph, area);
+}
+
+fn field(label: &str, value: String, style: Style, theme: &ThemeConfig) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(format!("{}: ", label), Style::default().fg(theme.dim())),
+        Span::styled(value, style),
+    ])
+}
+
+fn add_summary(lines: &mut Vec<Line<'static>>, image: &ImageInfo, theme: &ThemeConfig) {
+    let text = Style::default().fg(theme.text());
+    lines.push(field("ID", image.id.clone(), text, theme));
+    if image.dangling {
+        lines.push(field(
+            "Tags",
+            "<none> (dangling)".to_string(),
+            Style::default().fg(theme.modified()),
+            theme,
+        ));
+    } else {
+        lines.push(field(
+            "Tags",
+            image.tags.join(", "),
+            Style::default().fg(theme.accent()),
+            theme,
+        ));
+    }
+    lines.push(field("Size", image.size.clone(), text, theme));
+    lines.push(field("Created", image.created.clone(), text, theme));
+    lines.push(Line::from(""));
+}
+
+fn add_containers(lines: &mut Vec<Line<'static>>, containers: &[String], theme: &ThemeConfig) {
+    lines.push(Line::from(Span::styled(
+        "Used by:",
+        Style::default().fg(theme.accent()),
+    )));
+    if containers.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  (no containers)",
+            Style::default().fg(theme.dim()),
+        )));
+    }
+    for name in containers {
+        lines.push(Line::from(Span::styled(
+            format!("  {}", name),
+            Style::default().fg(theme.text()),
+        )));
+    }
+    lines.push(Line::from(""));
+}
+
+fn add_inspect(lines: &mut Vec<Line<'static>>, details: &ImageDetails, theme: &ThemeConfig) {
+    let text = Style::default().fg(theme.text());
+    lines.push(field(
+        "Platform",
+        format!("{}/{}", details.os, details.architecture),
+        text,
+        theme,
+    ));
+    lines.push(field("Created at", details.created.clone(), text, theme));
+    lines.push(field("Layers", details.layers.to_string(), text, theme));
+    if !details.entrypoint.is_empty() {
+        lines.push(field(
+            "Entrypoint",
+            details.entrypoint.join(" "),
+            text,
+            theme,
+        ));
+    }
+    if !details.cmd.is_empty() {
+        lines.push(field("Cmd", details.cmd.join(" "), text, theme));
+    }
+    if !details.exposed_ports.is_empty() {
+        lines.push(field(
+            "Ports",
+            details.exposed_ports.join(", "),
+            text,
+            theme,
+        ));
+    }
+    for digest in &details.digests {
+        lines.push(field("Digest", digest.clone(), text, theme));
+    }
+
+    if !details.environment.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Environment:",
+            Style::default().fg(theme.accent()),
+        )));
+        for env in &details.environment {
+            lines.push(Line::from(Span::styled(format!("  {}", env), text)));
+        }
+    }
+}
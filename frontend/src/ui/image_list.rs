@@ -0,0 +1,83 @@
+use crate::{
+    api::ImageInfo,
+    state::{AppState, Pane, images::short_image_id},
+    theme::{ThemeConfig, image_list::ImageListTheme},
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+};
+
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let is_focused = state.focus == Pane::Images;
+    let images = &state.images;
+
+    let items: Vec<ListItem> = images
+        .images
+        .iter()
+        .map(|image| ListItem::new(image_line(image, theme)))
+        .collect();
+
+    let border_style = if is_focused {
+        ImageListTheme::border_focused(theme)
+    } else {
+        ImageListTheme::border_unfocused(theme)
+    };
+
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" Images ({}) ", images.images.len()))
+        .border_style(border_style);
+
+    // Confirmation prompt for remove/prune
+    if let Some(pending) = &images.pending {
+        block = block.title_bottom(Span::styled(
+            format!(" {} ", pending.prompt()),
+            ImageListTheme::confirm_style(theme),
+        ));
+    }
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(ImageListTheme::highlight_style(theme));
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(images.selected_index));
+
+    f.render_stateful_widget(list, area, &mut list_state);
+}
+
+fn image_line<'a>(image: &'a ImageInfo, theme: &ThemeConfig) -> Line<'a> {
+    let name = if image.dangling {
+        Span::styled("<none>".to_string(), ImageListTheme::dangling_style(theme))
+    } else {
+        let mut name = image.tags[0].clone();
+        if image.tags.len() > 1 {
+            name.push_str(&format!(" +{}", image.tags.len() - 1));
+        }
+        Span::styled(name, ImageListTheme::tag_style(theme))
+    };
+
+    let mut spans = vec![
+        Span::styled(
+            format!("{:<12} ", short_image_id(&image.id)),
+            ImageListTheme::id_style(theme),
+        ),
+        name,
+        Span::styled(
+            format!(" {} {}", image.size, image.created),
+            ImageListTheme::info_style(theme),
+        ),
+    ];
+    if !image.containers.is_empty() {
+        spans.push(Span::styled(
+            format!(" [{} in use]", image.containers.len()),
+            ImageListTheme::in_use_style(theme),
+        ));
+    }
+
+    Line::from(spans)
+}
@@ -37,6 +37,9 @@ pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
             let icon = match item.as_str() {
                 "Config Files" => format!("{} ", theme.icons.config_files),
                 "Container" => format!("{} ", theme.icons.container),
+                "Images" => format!("{} ", theme.icons.images),
+                "Volumes" => format!("{} ", theme.icons.volumes),
+                "Networks" => format!("{} ", theme.icons.networks),
                 _ => String::new(),
             };
             prefix.len() + icon.len() + item.len()
@@ -64,6 +67,9 @@ pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
         let icon = match item.as_str() {
             "Config Files" => format!("{} ", theme.icons.config_files),
             "Container" => format!("{} ", theme.icons.container),
+            "Images" => format!("{} ", theme.icons.images),
+            "Volumes" => format!("{} ", theme.icons.volumes),
+            "Networks" => format!("{} ", theme.icons.networks),
             _ => String::new(),
         };
 
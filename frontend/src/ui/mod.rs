@@ -1,9 +1,17 @@
+mod compose_apply;
 mod container_details;
 mod container_list;
 mod editor;
 mod file_list;
+mod image_details;
+mod image_list;
 mod menu;
+mod networks;
+mod resource_list;
+mod save_review;
 mod status_line;
+mod terminal;
+mod volumes;
 
 use crate::state::{AppState, Pane};
 use ratzilla::ratatui::{
@@ -29,7 +37,9 @@ pub fn render(f: &mut Frame, state: &AppState) {
     // Main content depends on current pane
     match state.focus {
         Pane::Menu => menu::render(f, state, chunks[0]),
-        Pane::ContainerList => render_container_view(f, state, chunks[0]),
+        Pane::ContainerList | Pane::Terminal => render_container_view(f, state, chunks[0]),
+        Pane::Images => render_image_view(f, state, chunks[0]),
+        Pane::Volumes | Pane::Networks => render_resource_view(f, state, chunks[0]),
         _ => render_main_content(f, state, chunks[0]),
     }
 
@@ -48,7 +58,24 @@ fn render_main_content(f: &mut Frame, state: &AppState, area: ratzilla::ratatui:
         .split(area);
 
     file_list::render(f, state, chunks[0]);
-    editor::render(f, state, chunks[2]);
+
+    // The diff review replaces the editor until the save is confirmed
+    if state.save_review.is_some() {
+        save_review::render(f, state, chunks[2]);
+    } else if state.compose_apply.is_some() {
+        // Compose apply panel below the editor while an apply is shown
+        let editor_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(0),
+                Constraint::Length(compose_apply::PANEL_HEIGHT),
+            ])
+            .split(chunks[2]);
+        editor::render(f, state, editor_chunks[0]);
+        compose_apply::render(f, state, editor_chunks[1]);
+    } else {
+        editor::render(f, state, chunks[2]);
+    }
 }
 
 fn render_container_view(f: &mut Frame, state: &AppState, area: ratzilla::ratatui::layout::Rect) {
@@ -62,5 +89,41 @@ fn render_container_view(f: &mut Frame, state: &AppState, area: ratzilla::ratatu
         .split(area);
 
     container_list::render(f, state, chunks[0]);
-    container_details::render(f, state, chunks[2]);
+    // An open shell replaces the details pane
+    if state.terminal.is_some() {
+        terminal::render(f, state, chunks[2]);
+    } else {
+        container_details::render(f, state, chunks[2]);
+    }
+}
+
+fn render_image_view(f: &mut Frame, state: &AppState, area: ratzilla::ratatui::layout::Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(40), // Image list
+            Constraint::Percentage(1),  // Empty gap
+            Constraint::Percentage(59), // Image details
+        ])
+        .split(area);
+
+    image_list::render(f, state, chunks[0]);
+    image_details::render(f, state, chunks[2]);
+}
+
+fn render_resource_view(f: &mut Frame, state: &AppState, area: ratzilla::ratatui::layout::Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(40), // Volume/network list
+            Constraint::Percentage(1),  // Empty gap
+            Constraint::Percentage(59), // Details
+        ])
+        .split(area);
+
+    if state.focus == Pane::Networks {
+        networks::render(f, state, chunks[0], chunks[2]);
+    } else {
+        volumes::render(f, state, chunks[0], chunks[2]);
+    }
 }
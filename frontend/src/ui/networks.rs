@@ -0,0 +1,38 @@
+use super::resource_list::{self, Details};
+use crate::state::AppState;
+use ratzilla::ratatui::{Frame, layout::Rect};
+
+pub fn render(f: &mut Frame, state: &AppState, list_area: Rect, details_area: Rect) {
+    let theme = &state.current_theme;
+    let networks = &state.networks;
+
+    resource_list::render(f, networks, "Networks", theme, list_area, |n| {
+        match n.subnets.first() {
+            Some(subnet) => format!("{} {}", n.driver, subnet),
+            None => n.driver.clone(),
+        }
+    });
+
+    let details = networks.selected().map(|n| {
+        let mut fields = vec![
+            ("Name", n.name.clone()),
+            ("ID", n.id.clone()),
+            ("Driver", n.driver.clone()),
+            ("Scope", n.scope.clone()),
+        ];
+        if n.internal {
+            fields.push(("Internal", "yes".to_string()));
+        }
+        if n.builtin {
+            fields.push(("Predefined", "yes (cannot be removed)".to_string()));
+        }
+        for subnet in &n.subnets {
+            fields.push(("Subnet", subnet.clone()));
+        }
+        Details {
+            fields,
+            users: &n.containers,
+        }
+    });
+    resource_list::render_details(f, "Network Details", details, theme, details_area);
+}
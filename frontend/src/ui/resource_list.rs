@@ -0,0 +1,128 @@
+use crate::{
+    state::{Resource, ResourceListState},
+    theme::{ThemeConfig, resource_list::ResourceListTheme},
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+/// List of volumes or networks with the confirmation prompt
+pub fn render<T: Resource>(
+    f: &mut Frame,
+    list: &ResourceListState<T>,
+    title: &str,
+    theme: &ThemeConfig,
+    area: Rect,
+    describe: impl Fn(&T) -> String,
+) {
+    let items: Vec<ListItem> = list
+        .items
+        .iter()
+        .map(|item| ListItem::new(item_line(item, describe(item), theme)))
+        .collect();
+
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" {} ({}) ", title, list.items.len()))
+        .border_style(ResourceListTheme::border_focused(theme));
+
+    if let Some(prompt) = list.prompt() {
+        block = block.title_bottom(Span::styled(
+            format!(" {} ", prompt),
+            ResourceListTheme::confirm_style(theme),
+        ));
+    }
+
+    let widget = List::new(items)
+        .block(block)
+        .highlight_style(ResourceListTheme::highlight_style(theme));
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(list.selected_index));
+
+    f.render_stateful_widget(widget, area, &mut list_state);
+}
+
+fn item_line<'a, T: Resource>(item: &'a T, info: String, theme: &ThemeConfig) -> Line<'a> {
+    let name_style = if item.builtin() {
+        ResourceListTheme::builtin_style(theme)
+    } else {
+        ResourceListTheme::name_style(theme)
+    };
+
+    let usage = if item.users().is_empty() {
+        Span::styled(" [unused]", ResourceListTheme::unused_style(theme))
+    } else {
+        Span::styled(
+            format!(" [{} in use]", item.users().len()),
+            ResourceListTheme::in_use_style(theme),
+        )
+    };
+
+    Line::from(vec![
+        Span::styled(item.label(), name_style),
+        Span::styled(format!(" {}", info), ResourceListTheme::info_style(theme)),
+        usage,
+    ])
+}
+
+/// Fields of the selected object and the containers using it
+pub struct Details<'a> {
+    pub fields: Vec<(&'static str, String)>,
+    pub users: &'a [String],
+}
+
+/// Details pane: a list of fields followed by the containers using the object
+pub fn render_details(
+    f: &mut Frame,
+    title: &str,
+    details: Option<Details>,
+    theme: &ThemeConfig,
+    area: Rect,
+) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" {} ", title))
+        .border_style(Style::default().fg(theme.dim()));
+
+    let Some(Details { fields, users }) = details else {
+        let paragraph = Paragraph::new("Nothing selected")
+            .block(block)
+            .style(Style::default().fg(theme.dim()));
+        f.render_widget(paragraph, area);
+        return;
+    };
+
+    let text = Style::default().fg(theme.text());
+    let mut lines: Vec<Line> = fields
+        .into_iter()
+        .map(|(label, value)| {
+            Line::from(vec![
+                Span::styled(format!("{}: ", label), Style::default().fg(theme.dim())),
+                Span::styled(value, text),
+            ])
+        })
+        .collect();
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Used by:",
+        Style::default().fg(theme.accent()),
+    )));
+    if users.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  (no containers)",
+            Style::default().fg(theme.dim()),
+        )));
+    }
+    for name in users {
+        lines.push(Line::from(Span::styled(format!("  {}", name), text)));
+    }
+
+    let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: true });
+    f.render_widget(paragraph, area);
+}
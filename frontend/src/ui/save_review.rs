@@ -0,0 +1,154 @@
+use crate::{
+    state::{AppState, DiffRow, SaveReview},
+    theme::ThemeConfig,
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let Some(review) = &state.save_review else {
+        return;
+    };
+    let theme = &state.current_theme;
+    let save_key = &state.keybinds.global.save;
+
+    let layout = if review.side_by_side {
+        "side by side"
+    } else {
+        "unified"
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Line::from(vec![
+            Span::raw(format!(" Review: {} ", review.filename)),
+            Span::styled(
+                format!("[hunk {}/{}] ", review.current + 1, review.hunks.len()),
+                Style::default().fg(theme.accent()),
+            ),
+        ]))
+        .title_bottom(format!(" {}  {}: write ", layout, save_key))
+        .border_style(Style::default().fg(theme.accent()));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    if review.side_by_side {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(inner);
+        let (old, new) = side_by_side(theme, review, inner.height as usize);
+        f.render_widget(Paragraph::new(old), columns[0]);
+        f.render_widget(Paragraph::new(new), columns[1]);
+    } else {
+        let lines: Vec<Line> = review
+            .rows
+            .iter()
+            .skip(review.scroll)
+            .take(inner.height as usize)
+            .map(|row| unified_line(theme, review, *row))
+            .collect();
+        f.render_widget(Paragraph::new(lines), inner);
+    }
+}
+
+fn unified_line(theme: &ThemeConfig, review: &SaveReview, row: DiffRow) -> Line<'static> {
+    match row {
+        DiffRow::Header(hunk) => header_line(theme, review, hunk),
+        DiffRow::Context(old, _) => diff_line(
+            "  ",
+            &review.old_lines[old],
+            Style::default().fg(theme.dim()),
+        ),
+        DiffRow::Deleted(old) => diff_line(
+            "- ",
+            &review.old_lines[old],
+            Style::default().fg(theme.error()),
+        ),
+        DiffRow::Inserted(new) => diff_line(
+            "+ ",
+            &review.new_lines[new],
+            Style::default().fg(theme.success()),
+        ),
+    }
+}
+
+/// Old and new columns from `scroll`, with runs of deleted and inserted lines
+/// paired up
+fn side_by_side(
+    theme: &ThemeConfig,
+    review: &SaveReview,
+    height: usize,
+) -> (Vec<Line<'static>>, Vec<Line<'static>>) {
+    let deleted = Style::default().fg(theme.error());
+    let inserted = Style::default().fg(theme.success());
+    let context = Style::default().fg(theme.dim());
+    let (mut old, mut new) = (Vec::new(), Vec::new());
+
+    let rows = &review.rows[review.scroll.min(review.rows.len())..];
+    let mut i = 0;
+    while i < rows.len() && old.len() < height {
+        match rows[i] {
+            DiffRow::Header(hunk) => {
+                old.push(header_line(theme, review, hunk));
+                new.push(Line::from(""));
+                i += 1;
+            }
+            DiffRow::Context(o, n) => {
+                old.push(diff_line("  ", &review.old_lines[o], context));
+                new.push(diff_line("  ", &review.new_lines[n], context));
+                i += 1;
+            }
+            DiffRow::Deleted(_) | DiffRow::Inserted(_) => {
+                let run: Vec<DiffRow> = rows[i..]
+                    .iter()
+                    .take_while(|row| matches!(row, DiffRow::Deleted(_) | DiffRow::Inserted(_)))
+                    .copied()
+                    .collect();
+                i += run.len();
+                let mut old_run = Vec::new();
+                let mut new_run = Vec::new();
+                for row in run {
+                    match row {
+                        DiffRow::Deleted(o) => {
+                            old_run.push(diff_line("- ", &review.old_lines[o], deleted))
+                        }
+                        DiffRow::Inserted(n) => {
+                            new_run.push(diff_line("+ ", &review.new_lines[n], inserted))
+                        }
+                        _ => {}
+                    }
+                }
+                let len = old_run.len().max(new_run.len());
+                old_run.resize(len, Line::from(""));
+                new_run.resize(len, Line::from(""));
+                old.extend(old_run);
+                new.extend(new_run);
+            }
+        }
+    }
+    (old, new)
+}
+
+/// `@@ … @@` line, highlighted for the hunk the revert key acts on
+fn header_line(theme: &ThemeConfig, review: &SaveReview, hunk: usize) -> Line<'static> {
+    let style = if hunk == review.current {
+        Style::default()
+            .fg(theme.accent())
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme.dim())
+    };
+    Line::from(Span::styled(review.header(hunk), style))
+}
+
+fn diff_line(prefix: &str, text: &str, style: Style) -> Line<'static> {
+    Line::from(Span::styled(
+        format!("{}{}", prefix, text.replace('\t', "    ")),
+        style,
+    ))
+}
@@ -0,0 +1,44 @@
+use crate::{state::AppState, theme::status_line::StatusLineTheme};
+use ratzilla::ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+/// The prompt (`:`, `/` or `?`) followed by the typed text, with the cursor shown as a reversed cell
+pub(super) fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let theme = &state.current_theme;
+    let command_line = &state.command_line;
+    let text_style = StatusLineTheme::command_line_style(theme);
+
+    let before: String = command_line
+        .input
+        .chars()
+        .take(command_line.cursor)
+        .collect();
+    let mut after = command_line.input.chars().skip(command_line.cursor);
+    let under = after.next().map_or(" ".to_string(), String::from);
+    let after: String = after.collect();
+
+    let mut spans = vec![
+        Span::styled(format!(" {}{}", command_line.prompt, before), text_style),
+        Span::styled(under, StatusLineTheme::command_cursor_style(theme)),
+        Span::styled(after, text_style),
+    ];
+    if let Some(completion) = &command_line.completion
+        && completion.candidates.len() > 1
+    {
+        spans.push(Span::styled(
+            format!(
+                "  [{}/{}]",
+                completion.index + 1,
+                completion.candidates.len()
+            ),
+            StatusLineTheme::label_style(theme),
+        ));
+    }
+
+    let line = Paragraph::new(Line::from(spans)).style(StatusLineTheme::background(theme));
+    f.render_widget(line, area);
+}
@@ -22,6 +22,12 @@ pub fn render_component(
 
         ComponentConfig::StatusMessage => state::render_status_message(state, theme),
 
+        ComponentConfig::Diagnostics => state::render_diagnostics(state, theme),
+
+        ComponentConfig::CursorPosition => state::render_cursor_position(state, theme),
+
+        ComponentConfig::LineCount => state::render_line_count(state, theme),
+
         ComponentConfig::HelpText => state::render_help_text(state, theme),
 
         ComponentConfig::BuildDate { style } => build::render_build_date(style.as_deref(), theme),
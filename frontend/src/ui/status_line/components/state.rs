@@ -1,6 +1,7 @@
 use crate::{
     state::{AppState, Pane, VimMode},
     theme::{ThemeConfig, status_line::StatusLineTheme},
+    validate::Severity,
 };
 use ratzilla::ratatui::text::Span;
 
@@ -52,8 +53,85 @@ pub fn render_status_message(state: &AppState, theme: &ThemeConfig) -> Option<Sp
         };
         Some(Span::styled(msg.clone(), style))
     } else {
-        None
+        // Fall back to progress of background jobs
+        state
+            .jobs
+            .summary()
+            .map(|text| Span::styled(text, StatusLineTheme::status_message_style(theme)))
+    }
+}
+
+/// Error and warning counts with the message of the first diagnostic on the
+/// cursor line (or in the file)
+pub fn render_diagnostics(state: &AppState, theme: &ThemeConfig) -> Option<Span<'static>> {
+    let diagnostics = &state.editor.diagnostics;
+    if state.focus != Pane::Editor || diagnostics.is_empty() {
+        return None;
     }
+
+    let errors = diagnostics
+        .iter()
+        .filter(|d| d.severity == Severity::Error)
+        .count();
+    let warnings = diagnostics.len() - errors;
+    let cursor_row = state.editor.textarea.cursor().0;
+    let shown = diagnostics
+        .iter()
+        .find(|d| d.line == cursor_row)
+        .unwrap_or(&diagnostics[0]);
+
+    let style = if errors > 0 {
+        StatusLineTheme::error_message_style(theme)
+    } else {
+        StatusLineTheme::modified_style(theme)
+    };
+    Some(Span::styled(
+        format!(
+            "E:{} W:{} line {}: {}",
+            errors,
+            warnings,
+            shown.line + 1,
+            shown.message
+        ),
+        style,
+    ))
+}
+
+/// `Ln 12, Col 5` of the editor cursor (1-based, tabs count as their width)
+pub fn render_cursor_position(state: &AppState, theme: &ThemeConfig) -> Option<Span<'static>> {
+    if state.focus != Pane::Editor || state.editor.current_file.is_none() {
+        return None;
+    }
+    let textarea = &state.editor.textarea;
+    let (row, col) = textarea.cursor();
+    let tab_length = textarea.tab_length().max(1) as usize;
+    let column = textarea.lines().get(row).map_or(0, |line| {
+        line.chars().take(col).fold(0, |x, c| {
+            if c == '\t' {
+                x + tab_length - x % tab_length
+            } else {
+                x + 1
+            }
+        })
+    });
+    Some(Span::styled(
+        format!("Ln {}, Col {}", row + 1, column + 1),
+        StatusLineTheme::value_style(theme),
+    ))
+}
+
+/// Number of lines in the editor buffer
+pub fn render_line_count(state: &AppState, theme: &ThemeConfig) -> Option<Span<'static>> {
+    if state.focus != Pane::Editor || state.editor.current_file.is_none() {
+        return None;
+    }
+    let count = state.editor.textarea.lines().len();
+    let text = if count == 1 {
+        "1 line".to_string()
+    } else {
+        format!("{} lines", count)
+    };
+    Some(Span::styled(text, StatusLineTheme::label_style(theme)))
 }
 
 pub fn render_help_text(state: &AppState, theme: &ThemeConfig) -> Option<Span<'static>> {
@@ -61,12 +139,23 @@ pub fn render_help_text(state: &AppState, theme: &ThemeConfig) -> Option<Span<'s
     let help_text = match (state.focus, state.vim_mode) {
         (Pane::Menu, _) => String::new(),
         (Pane::FileList, _) => state.keybinds.file_list.help_text(&state.keybinds.global),
-        (Pane::Editor, VimMode::Normal) => state.keybinds.global.editor_normal_help_text(),
+        (Pane::Editor, _) if state.save_review.is_some() => {
+            state.keybinds.review.help_text(&state.keybinds.global)
+        }
+        (Pane::Editor, VimMode::Normal | VimMode::Command) => {
+            state.keybinds.global.editor_normal_help_text()
+        }
         (Pane::Editor, VimMode::Insert) => state.keybinds.global.editor_insert_help_text(),
+        (Pane::Editor, VimMode::Visual | VimMode::VisualLine) => {
+            state.keybinds.global.editor_visual_help_text()
+        }
         (Pane::ContainerList, _) => state
             .keybinds
             .container_list
             .help_text(&state.keybinds.global),
+        (Pane::Terminal, _) => state.keybinds.terminal.help_text(),
+        (Pane::Images, _) => state.keybinds.images.help_text(),
+        (Pane::Volumes | Pane::Networks, _) => state.keybinds.resources.help_text(),
     };
 
     if !help_text.is_empty() {
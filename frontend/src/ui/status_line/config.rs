@@ -27,6 +27,9 @@ pub enum ComponentConfig {
     Filename,
     ModifiedIndicator,
     StatusMessage,
+    Diagnostics,
+    CursorPosition,
+    LineCount,
     HelpText,
     BuildDate {
         #[serde(default)]
@@ -65,7 +68,12 @@ impl StatusLineConfig {
             Pane::Menu => &self.menu,
             Pane::FileList => &self.file_list,
             Pane::Editor => &self.editor,
-            Pane::ContainerList => &self.container_list,
+            // The shell and resource panes share the container layout
+            Pane::ContainerList
+            | Pane::Terminal
+            | Pane::Images
+            | Pane::Volumes
+            | Pane::Networks => &self.container_list,
         }
     }
 }
@@ -1,9 +1,13 @@
+mod command_line;
 mod components;
 mod config;
 mod rendering;
 
-use crate::{state::AppState, theme::status_line::StatusLineTheme};
-use config::StatusLineConfig;
+use crate::{
+    state::{AppState, VimMode},
+    theme::status_line::StatusLineTheme,
+};
+use config::{ComponentConfig, StatusLineConfig};
 use ratzilla::ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -48,6 +52,17 @@ pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
 
     // Render each row
     for (row_idx, row_config) in pane_config.rows.iter().enumerate() {
+        // The command line takes over the row showing status messages
+        if state.vim_mode == VimMode::Command
+            && row_config
+                .components
+                .iter()
+                .any(|c| matches!(c, ComponentConfig::StatusMessage))
+        {
+            command_line::render(f, state, rows[row_idx]);
+            continue;
+        }
+
         let spans = rendering::render_row_with_spacing(row_config, state, theme);
 
         let line = Paragraph::new(Line::from(spans))
@@ -0,0 +1,113 @@
+use crate::{
+    state::{AppState, Pane, TerminalState},
+    theme::terminal::TerminalTheme,
+};
+use ratzilla::ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+pub fn render(f: &mut Frame, state: &AppState, area: Rect) {
+    let Some(terminal) = &state.terminal else {
+        return;
+    };
+    let theme = &state.current_theme;
+    let is_focused = state.focus == Pane::Terminal;
+
+    let title = match &terminal.closed {
+        Some(reason) => format!(" {} [{}] ", terminal.container_name, reason),
+        None => format!(" {} ", terminal.container_name),
+    };
+    let hint = if terminal.closed.is_some() {
+        " Esc: close ".to_string()
+    } else {
+        format!(" {}: detach ", state.keybinds.terminal.detach)
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .title_bottom(hint)
+        .border_style(if is_focused {
+            TerminalTheme::border_focused(theme)
+        } else {
+            TerminalTheme::border_unfocused(theme)
+        });
+
+    let inner = block.inner(area);
+    terminal.fit(inner.width, inner.height);
+
+    let lines = screen_lines(terminal, is_focused && terminal.closed.is_none());
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .style(TerminalTheme::text_style(theme));
+    f.render_widget(paragraph, area);
+}
+
+/// Convert the emulator screen into styled lines
+fn screen_lines(terminal: &TerminalState, show_cursor: bool) -> Vec<Line<'static>> {
+    let parser = terminal.parser.borrow();
+    let screen = parser.screen();
+    let (rows, cols) = screen.size();
+    let (cursor_row, cursor_col) = screen.cursor_position();
+    let show_cursor = show_cursor && !screen.hide_cursor();
+
+    (0..rows)
+        .map(|row| {
+            let spans: Vec<Span> = (0..cols)
+                .filter_map(|col| {
+                    let cell = screen.cell(row, col)?;
+                    if cell.is_wide_continuation() {
+                        return None;
+                    }
+                    let mut style = cell_style(cell);
+                    if show_cursor && row == cursor_row && col == cursor_col {
+                        style = style.add_modifier(Modifier::REVERSED);
+                    }
+                    let text = if cell.has_contents() {
+                        cell.contents()
+                    } else {
+                        " ".to_string()
+                    };
+                    Some(Span::styled(text, style))
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect()
+}
+
+fn cell_style(cell: &vt100::Cell) -> Style {
+    let mut style = Style::default();
+    if let Some(fg) = vt_color(cell.fgcolor()) {
+        style = style.fg(fg);
+    }
+    if let Some(bg) = vt_color(cell.bgcolor()) {
+        style = style.bg(bg);
+    }
+    if cell.bold() {
+        style = style.add_modifier(Modifier::BOLD);
+    }
+    if cell.italic() {
+        style = style.add_modifier(Modifier::ITALIC);
+    }
+    if cell.underline() {
+        style = style.add_modifier(Modifier::UNDERLINED);
+    }
+    if cell.inverse() {
+        style = style.add_modifier(Modifier::REVERSED);
+    }
+    style
+}
+
+/// Terminal default colors fall back to the theme
+fn vt_color(color: vt100::Color) -> Option<Color> {
+    match color {
+        vt100::Color::Default => None,
+        vt100::Color::Idx(i) => Some(Color::Indexed(i)),
+        vt100::Color::Rgb(r, g, b) => Some(Color::Rgb(r, g, b)),
+    }
+}
@@ -0,0 +1,31 @@
+use super::resource_list::{self, Details};
+use crate::state::AppState;
+use ratzilla::ratatui::{Frame, layout::Rect};
+
+pub fn render(f: &mut Frame, state: &AppState, list_area: Rect, details_area: Rect) {
+    let theme = &state.current_theme;
+    let volumes = &state.volumes;
+
+    resource_list::render(f, volumes, "Volumes", theme, list_area, |v| match &v.size {
+        Some(size) => format!("{} {}", v.driver, size),
+        None => v.driver.clone(),
+    });
+
+    let details = volumes.selected().map(|v| {
+        let fields = vec![
+            ("Name", v.name.clone()),
+            ("Driver", v.driver.clone()),
+            ("Scope", v.scope.clone()),
+            ("Mountpoint", v.mountpoint.clone()),
+            (
+                "Size",
+                v.size.clone().unwrap_or_else(|| "unknown".to_string()),
+            ),
+        ];
+        Details {
+            fields,
+            users: &v.containers,
+        }
+    });
+    resource_list::render_details(f, "Volume Details", details, theme, details_area);
+}
@@ -0,0 +1,171 @@
+/// A run of changed lines: `old_lines` replaced by `new_lines`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hunk {
+    /// First line of the change in the old and the new text (0-based)
+    pub old_start: usize,
+    pub new_start: usize,
+    pub old_lines: Vec<String>,
+    pub new_lines: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Changed runs of lines between two texts
+pub fn hunks(old: &[String], new: &[String]) -> Vec<Hunk> {
+    let ops = edit_script(old, new);
+
+    let mut hunks: Vec<Hunk> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    let mut in_hunk = false;
+    for op in ops {
+        if op == Op::Equal {
+            in_hunk = false;
+            i += 1;
+            j += 1;
+            continue;
+        }
+        if !in_hunk {
+            hunks.push(Hunk {
+                old_start: i,
+                new_start: j,
+                old_lines: Vec::new(),
+                new_lines: Vec::new(),
+            });
+            in_hunk = true;
+        }
+        let Some(hunk) = hunks.last_mut() else {
+            continue;
+        };
+        if op == Op::Delete {
+            hunk.old_lines.push(old[i].clone());
+            i += 1;
+        } else {
+            hunk.new_lines.push(new[j].clone());
+            j += 1;
+        }
+    }
+    hunks
+}
+
+/// Shortest edit script turning `a` into `b`
+///
+/// Myers' linear space variant: find where the forward and backward searches
+/// of the shortest path meet, then diff the parts before and after that
+/// point. Memory stays O(n + m) instead of a saved diagonal row per edit.
+fn edit_script(a: &[String], b: &[String]) -> Vec<Op> {
+    let mut ops = Vec::with_capacity(a.len() + b.len());
+    diff_into(a, b, &mut ops);
+    ops
+}
+
+fn diff_into(a: &[String], b: &[String], ops: &mut Vec<Op>) {
+    // Lines shared at both ends don't need the search
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a, b) = (&a[..a.len() - suffix], &b[..b.len() - suffix]);
+
+    ops.extend(std::iter::repeat_n(Op::Equal, prefix));
+    if a.is_empty() || b.is_empty() {
+        ops.extend(std::iter::repeat_n(Op::Delete, a.len()));
+        ops.extend(std::iter::repeat_n(Op::Insert, b.len()));
+    } else if let Some((x, y)) = middle(a, b) {
+        diff_into(&a[..x], &b[..y], ops);
+        diff_into(&a[x..], &b[y..], ops);
+    } else {
+        ops.extend(std::iter::repeat_n(Op::Delete, a.len()));
+        ops.extend(std::iter::repeat_n(Op::Insert, b.len()));
+    }
+    ops.extend(std::iter::repeat_n(Op::Equal, suffix));
+}
+
+/// Point on a shortest path from the start to the end of both texts, where
+/// the search from the start meets the one from the end
+///
+/// `forward[k]` and `backward[k]` hold the furthest x reached on diagonal k
+/// (counted from the start and from the end), -1 if not reached yet.
+fn middle(a: &[String], b: &[String]) -> Option<(usize, usize)> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max_d = (n + m + 1) / 2;
+    let offset = max_d;
+    let len = 2 * max_d + 2;
+    let mut forward = vec![-1isize; len as usize];
+    let mut backward = vec![-1isize; len as usize];
+    forward[(offset + 1) as usize] = 0;
+    backward[(offset + 1) as usize] = 0;
+    let delta = n - m;
+    // With an odd delta the paths meet on a forward step, otherwise backward
+    let odd = delta % 2 != 0;
+    // Diagonals that ran off the edges are skipped from then on
+    let (mut k1_start, mut k1_end, mut k2_start, mut k2_end) = (0, 0, 0, 0);
+
+    for d in 0..max_d {
+        for k1 in (-d + k1_start..=d - k1_end).step_by(2) {
+            let i = (offset + k1) as usize;
+            let mut x1 = if k1 == -d || (k1 != d && forward[i - 1] < forward[i + 1]) {
+                forward[i + 1]
+            } else {
+                forward[i - 1] + 1
+            };
+            let mut y1 = x1 - k1;
+            while x1 < n && y1 < m && a[x1 as usize] == b[y1 as usize] {
+                x1 += 1;
+                y1 += 1;
+            }
+            forward[i] = x1;
+            if x1 > n {
+                k1_end += 2;
+            } else if y1 > m {
+                k1_start += 2;
+            } else if odd {
+                let j = offset + delta - k1;
+                if (0..len).contains(&j)
+                    && backward[j as usize] != -1
+                    && x1 >= n - backward[j as usize]
+                {
+                    return Some((x1 as usize, y1 as usize));
+                }
+            }
+        }
+
+        for k2 in (-d + k2_start..=d - k2_end).step_by(2) {
+            let i = (offset + k2) as usize;
+            let mut x2 = if k2 == -d || (k2 != d && backward[i - 1] < backward[i + 1]) {
+                backward[i + 1]
+            } else {
+                backward[i - 1] + 1
+            };
+            let mut y2 = x2 - k2;
+            while x2 < n && y2 < m && a[(n - x2 - 1) as usize] == b[(m - y2 - 1) as usize] {
+                x2 += 1;
+                y2 += 1;
+            }
+            backward[i] = x2;
+            if x2 > n {
+                k2_end += 2;
+            } else if y2 > m {
+                k2_start += 2;
+            } else if !odd {
+                let j = offset + delta - k2;
+                if (0..len).contains(&j) && forward[j as usize] != -1 {
+                    let x1 = forward[j as usize];
+                    let y1 = offset + x1 - j;
+                    if x1 >= n - x2 {
+                        return Some((x1 as usize, y1 as usize));
+                    }
+                }
+            }
+        }
+    }
+    None
+}
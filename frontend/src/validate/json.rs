@@ -0,0 +1,30 @@
+use super::{Diagnostic, Severity};
+
+/// Syntax errors reported by serde_json
+pub(super) fn check(text: &str) -> Vec<Diagnostic> {
+    let Err(error) = serde_json::from_str::<serde_json::Value>(text) else {
+        return Vec::new();
+    };
+
+    // Lines and columns are 1-based; the column counts bytes
+    let line = error.line().saturating_sub(1);
+    let line_text = text.split('\n').nth(line).unwrap_or_default();
+    let byte = error.column().saturating_sub(1).min(line_text.len());
+    let col = line_text
+        .char_indices()
+        .take_while(|&(i, _)| i < byte)
+        .count();
+
+    // The message ends with " at line L column C"
+    let message = error.to_string();
+    let message = message
+        .rsplit_once(" at line ")
+        .map_or(message.as_str(), |(message, _)| message);
+    vec![Diagnostic {
+        severity: Severity::Error,
+        line,
+        start: col,
+        end: col + 1,
+        message: message.to_string(),
+    }]
+}
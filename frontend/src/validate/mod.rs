@@ -0,0 +1,63 @@
+mod json;
+mod toml;
+mod yaml;
+
+use crate::syntax::Language;
+use std::ops::Range;
+
+/// How bad a diagnostic is (errors sort first)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// Problem found in the buffer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// Line index (0-based)
+    pub line: usize,
+    /// Character columns of the offending text (`end` exclusive)
+    pub start: usize,
+    pub end: usize,
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Diagnostic for a byte range of `text`, cut off at the end of its first line
+    fn at_span(text: &str, span: Range<usize>, severity: Severity, message: String) -> Self {
+        let start = span.start.min(text.len());
+        let line_start = text[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = text[start..].find('\n').map_or(text.len(), |i| start + i);
+        let col = text[line_start..start].chars().count();
+        let len = text[start..span.end.clamp(start, line_end)].chars().count();
+        Self {
+            severity,
+            line: text[..start].matches('\n').count(),
+            start: col,
+            end: col + len.max(1),
+            message,
+        }
+    }
+}
+
+/// Languages `check` can parse
+pub fn supports(language: Language) -> bool {
+    matches!(language, Language::Toml | Language::Json | Language::Yaml)
+}
+
+/// Parse the buffer as `language`; errors and warnings sorted by line
+pub fn check(language: Language, text: &str) -> Vec<Diagnostic> {
+    if text.trim().is_empty() {
+        return Vec::new();
+    }
+    let mut diagnostics = match language {
+        Language::Toml => toml::check(text),
+        Language::Json => json::check(text),
+        Language::Yaml => yaml::check(text),
+        Language::Ini | Language::Env => Vec::new(),
+    };
+    diagnostics.sort_by_key(|d| (d.line, d.severity, d.start));
+    diagnostics
+}
@@ -0,0 +1,154 @@
+use super::{Diagnostic, Severity};
+use std::collections::HashMap;
+use toml_edit::{ImDocument, InlineTable, Item, Key, Table, Value};
+
+/// Syntax errors (including duplicate keys) from the `toml` crate, then
+/// warnings for keys that differ only in case or `-`/`_` and for values whose
+/// type doesn't match their siblings
+pub(super) fn check(text: &str) -> Vec<Diagnostic> {
+    if let Err(error) = ::toml::from_str::<::toml::Table>(text) {
+        let span = error.span().unwrap_or(text.len()..text.len());
+        return vec![Diagnostic::at_span(
+            text,
+            span,
+            Severity::Error,
+            // Messages can span lines ("invalid string\nexpected `\"`")
+            error
+                .message()
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>()
+                .join(": "),
+        )];
+    }
+    // The document has spans for every key and value
+    let Ok(document) = ImDocument::parse(text) else {
+        return Vec::new();
+    };
+    let mut checker = Checker {
+        text,
+        diagnostics: Vec::new(),
+    };
+    checker.table(document.as_table());
+    checker.diagnostics
+}
+
+struct Checker<'a> {
+    text: &'a str,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Checker<'_> {
+    fn warn(&mut self, span: Option<std::ops::Range<usize>>, message: String) {
+        if let Some(span) = span {
+            self.diagnostics.push(Diagnostic::at_span(
+                self.text,
+                span,
+                Severity::Warning,
+                message,
+            ));
+        }
+    }
+
+    fn table(&mut self, table: &Table) {
+        self.similar_keys(table.iter().map(|(name, _)| table.key(name)));
+        for (_, item) in table.iter() {
+            match item {
+                Item::Value(value) => self.value(value),
+                Item::Table(table) => self.table(table),
+                Item::ArrayOfTables(array) => {
+                    let tables: Vec<&Table> = array.iter().collect();
+                    for table in &tables {
+                        self.table(table);
+                    }
+                    self.entry_types(&tables);
+                }
+                Item::None => {}
+            }
+        }
+    }
+
+    fn inline_table(&mut self, table: &InlineTable) {
+        self.similar_keys(table.iter().map(|(name, _)| table.key(name)));
+        for (_, value) in table.iter() {
+            self.value(value);
+        }
+    }
+
+    fn value(&mut self, value: &Value) {
+        match value {
+            Value::Array(array) => {
+                let mut values = array.iter();
+                let first = values.next().map(Value::type_name);
+                if let Some(first) = first
+                    && let Some(other) = values.find(|v| v.type_name() != first)
+                {
+                    self.warn(
+                        other.span(),
+                        format!("Array mixes {} and {} values", first, other.type_name()),
+                    );
+                }
+                for value in array.iter() {
+                    self.value(value);
+                }
+            }
+            Value::InlineTable(table) => self.inline_table(table),
+            _ => {}
+        }
+    }
+
+    /// Keys like `Port` next to `port` (or `max-size` next to `max_size`)
+    /// are usually one setting written twice
+    fn similar_keys<'k>(&mut self, keys: impl Iterator<Item = Option<&'k Key>>) {
+        let mut seen: HashMap<String, &str> = HashMap::new();
+        for key in keys.flatten() {
+            let normalized = key.get().to_lowercase().replace('-', "_");
+            match seen.get(&normalized) {
+                Some(first) => {
+                    let message = format!("`{}` looks like a duplicate of `{}`", key.get(), first);
+                    self.warn(key.span(), message);
+                }
+                None => {
+                    seen.insert(normalized, key.get());
+                }
+            }
+        }
+    }
+
+    /// The same key with different types in entries of an array of tables
+    /// (`port = 80` in one `[[server]]`, `port = "80"` in another)
+    fn entry_types(&mut self, tables: &[&Table]) {
+        let mut types: HashMap<&str, &str> = HashMap::new();
+        for table in tables {
+            for (name, item) in table.iter() {
+                let type_name = item.type_name();
+                match types.get(name) {
+                    Some(&expected) if expected != type_name => {
+                        let message = format!(
+                            "`{}` is {} {} here but {} {} in an earlier entry",
+                            name,
+                            article(type_name),
+                            type_name,
+                            article(expected),
+                            expected
+                        );
+                        self.warn(table.key(name).and_then(Key::span), message);
+                    }
+                    Some(_) => {}
+                    None => {
+                        types.insert(name, type_name);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn article(type_name: &str) -> &'static str {
+    if type_name.starts_with(['a', 'e', 'i', 'o', 'u']) {
+        "an"
+    } else {
+        "a"
+    }
+}
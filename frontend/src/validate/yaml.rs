@@ -0,0 +1,301 @@
+use super::{Diagnostic, Severity};
+
+/// Line-based checks for the mistakes YAML parsers reject most often: tab
+/// indentation, duplicate keys, over-indented keys, `key: a: b`, and
+/// unclosed quotes or brackets
+pub(super) fn check(text: &str) -> Vec<Diagnostic> {
+    let mut checker = Checker::default();
+    for (row, line) in text.split('\n').enumerate() {
+        checker.line(row, line);
+    }
+    checker.finish()
+}
+
+#[derive(Default)]
+struct Checker {
+    diagnostics: Vec<Diagnostic>,
+    /// Block mappings being read: column of their keys and the keys with
+    /// the line they're on
+    mappings: Vec<(usize, Vec<(String, usize)>)>,
+    /// Column of the key of a block scalar (`key: |`); more indented lines
+    /// are its text
+    block_scalar: Option<usize>,
+    /// Column of the last key with its value on the same line; a more
+    /// indented key can't follow it
+    scalar_key: Option<usize>,
+    /// Open flow brackets as `(bracket, line, column)`
+    flow: Vec<(char, usize, usize)>,
+    /// Quoted scalar continuing on the next line
+    quote: Option<(char, usize, usize)>,
+}
+
+impl Checker {
+    fn error(&mut self, line: usize, start: usize, end: usize, message: String) {
+        self.diagnostics.push(Diagnostic {
+            severity: Severity::Error,
+            line,
+            start,
+            end,
+            message,
+        });
+    }
+
+    fn line(&mut self, row: usize, line: &str) {
+        let chars: Vec<char> = line.chars().collect();
+        let indent = chars.iter().take_while(|&&c| c == ' ').count();
+
+        if let Some(key_col) = self.block_scalar {
+            if line.trim().is_empty() || indent > key_col {
+                return;
+            }
+            self.block_scalar = None;
+        }
+        // Continuation of a flow collection or quoted scalar
+        if self.quote.is_some() || !self.flow.is_empty() {
+            self.scan(row, &chars, 0);
+            return;
+        }
+
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            return;
+        }
+        if chars.get(indent) == Some(&'\t') {
+            self.error(
+                row,
+                indent,
+                indent + 1,
+                "Tabs can't be used for indentation".to_string(),
+            );
+            return;
+        }
+        if ["---", "..."]
+            .iter()
+            .any(|marker| line.starts_with(marker) && line[3..].trim().is_empty())
+        {
+            self.mappings.clear();
+            self.scalar_key = None;
+            return;
+        }
+
+        // Sequence entries ("- key: value", "- - item")
+        let mut pos = indent;
+        let mut in_sequence = false;
+        while chars.get(pos) == Some(&'-') && chars.get(pos + 1).is_none_or(|c| *c == ' ') {
+            in_sequence = true;
+            pos += 1;
+            while chars.get(pos) == Some(&' ') {
+                pos += 1;
+            }
+        }
+
+        let Some((key, value_start)) = key(&chars, pos) else {
+            if in_sequence {
+                // A new entry; keys below a bare `-` start a new mapping
+                self.mappings.retain(|(col, _)| *col <= indent);
+                self.scalar_key = None;
+            }
+            self.scan(row, &chars, pos);
+            return;
+        };
+        let key_end = pos + key.chars().count();
+
+        if !in_sequence && self.scalar_key.is_some_and(|col| pos > col) {
+            self.error(row, pos, key_end, "Unexpected indentation".to_string());
+        }
+        self.add_key(row, pos, key_end, key, in_sequence);
+
+        let value: String = chars[value_start..].iter().collect();
+        let value = value.trim_start();
+        let value_col = chars.len() - value.chars().count();
+        if value.is_empty() || value.starts_with('#') {
+            // The value is the block below
+            self.scalar_key = None;
+        } else if is_block_scalar(value) {
+            self.block_scalar = Some(pos);
+            self.scalar_key = None;
+        } else {
+            self.scalar_key = Some(pos);
+            if let Some(offset) = nested_mapping(value) {
+                let col = value_col + offset;
+                self.error(
+                    row,
+                    col,
+                    col + 1,
+                    "Mapping values are not allowed here".to_string(),
+                );
+            }
+            self.scan(row, &chars, value_col);
+        }
+    }
+
+    /// Record a key of a block mapping, reporting it if already defined
+    fn add_key(&mut self, row: usize, col: usize, end: usize, key: String, in_sequence: bool) {
+        // Deeper mappings have ended; a sequence entry starts a new mapping
+        while self
+            .mappings
+            .last()
+            .is_some_and(|(indent, _)| *indent > col || (in_sequence && *indent == col))
+        {
+            self.mappings.pop();
+        }
+        match self.mappings.last_mut() {
+            Some((indent, keys)) if *indent == col => {
+                if let Some((_, first)) = keys.iter().find(|(k, _)| *k == key) {
+                    let message = format!("Duplicate key `{}` (first on line {})", key, first + 1);
+                    self.error(row, col, end, message);
+                } else {
+                    keys.push((key, row));
+                }
+            }
+            _ => self.mappings.push((col, vec![(key, row)])),
+        }
+    }
+
+    /// Track quotes and flow brackets from `from` to the end of the line
+    fn scan(&mut self, row: usize, chars: &[char], from: usize) {
+        // Quotes and brackets only start a scalar or collection at the start
+        // of a value (`it's` and `a[0]` are plain text)
+        let mut token_start = true;
+        let mut col = from;
+        while col < chars.len() {
+            let c = chars[col];
+            if let Some((quote, ..)) = self.quote {
+                if c == '\\' && quote == '"' {
+                    col += 1;
+                } else if c == quote {
+                    // '' is an escaped quote in single-quoted scalars
+                    if quote == '\'' && chars.get(col + 1) == Some(&'\'') {
+                        col += 1;
+                    } else {
+                        self.quote = None;
+                    }
+                }
+                col += 1;
+                continue;
+            }
+
+            match c {
+                '#' if col == 0 || chars[col - 1] == ' ' => return,
+                '"' | '\'' if token_start => self.quote = Some((c, row, col)),
+                '[' | '{' if token_start => self.flow.push((c, row, col)),
+                ']' | '}'
+                    if self
+                        .flow
+                        .last()
+                        .is_some_and(|(open, ..)| closing(*open) == c) =>
+                {
+                    self.flow.pop();
+                }
+                ']' | '}' if !self.flow.is_empty() => {
+                    self.flow.pop();
+                    self.error(row, col, col + 1, format!("Unexpected `{}`", c));
+                }
+                _ => {}
+            }
+            if c != ' ' {
+                token_start = matches!(c, '[' | '{' | ',' | ':' | '-' | '?');
+            }
+            col += 1;
+        }
+    }
+
+    fn finish(mut self) -> Vec<Diagnostic> {
+        if let Some((_, row, col)) = self.quote.take() {
+            self.error(row, col, col + 1, "Unterminated string".to_string());
+        }
+        for (open, row, col) in std::mem::take(&mut self.flow) {
+            self.error(row, col, col + 1, format!("Unclosed `{}`", open));
+        }
+        self.diagnostics
+    }
+}
+
+fn closing(open: char) -> char {
+    if open == '[' { ']' } else { '}' }
+}
+
+/// `key:` (plain or quoted) at `pos`; the key and where its value starts
+fn key(chars: &[char], pos: usize) -> Option<(String, usize)> {
+    let is_colon =
+        |i: usize| chars.get(i) == Some(&':') && chars.get(i + 1).is_none_or(|c| *c == ' ');
+    match chars.get(pos)? {
+        quote @ ('"' | '\'') => {
+            let end = pos + 1 + chars[pos + 1..].iter().position(|c| c == quote)?;
+            let colon = end + 1 + chars[end + 1..].iter().take_while(|&&c| c == ' ').count();
+            is_colon(colon).then(|| (chars[pos..=end].iter().collect(), colon + 1))
+        }
+        '#' | '[' | '{' | '|' | '>' | '&' | '*' | '!' | '%' | '@' | '`' => None,
+        _ => {
+            let colon = (pos..chars.len())
+                .find(|&i| is_colon(i) || (chars[i] == '#' && i > pos && chars[i - 1] == ' '))?;
+            if chars[colon] == '#' {
+                return None;
+            }
+            let key: String = chars[pos..colon].iter().collect();
+            Some((key.trim_end().to_string(), colon + 1))
+        }
+    }
+}
+
+/// `|`, `>` and their variants (`|-`, `>+`, `|2`)
+fn is_block_scalar(value: &str) -> bool {
+    let mut chars = value.chars();
+    matches!(chars.next(), Some('|' | '>'))
+        && chars
+            .take_while(|c| !c.is_whitespace())
+            .all(|c| matches!(c, '-' | '+' | '1'..='9'))
+        && value
+            .split_whitespace()
+            .nth(1)
+            .is_none_or(|rest| rest.starts_with('#'))
+}
+
+/// Offset (in characters) of a `: ` inside a plain scalar value (`a: b: c`)
+fn nested_mapping(value: &str) -> Option<usize> {
+    if value.starts_with(['"', '\'', '[', '{', '&', '*', '!']) {
+        return None;
+    }
+    let value = value.split(" #").next().unwrap_or(value);
+    let chars: Vec<char> = value.chars().collect();
+    (0..chars.len()).find(|&i| chars[i] == ':' && chars.get(i + 1).is_none_or(|c| *c == ' '))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check;
+
+    fn messages(text: &str) -> Vec<String> {
+        check(text).into_iter().map(|d| d.message).collect()
+    }
+
+    #[test]
+    fn compact_sequence_entries_are_separate_mappings() {
+        assert!(messages("- name: a\n  port: 1\n- name: b\n  port: 2\n").is_empty());
+    }
+
+    #[test]
+    fn bare_dash_entries_are_separate_mappings() {
+        assert!(messages("-\n  name: a\n-\n  name: b\n").is_empty());
+        assert!(messages("items:\n  -\n    name: a\n  -\n    name: b\n").is_empty());
+    }
+
+    #[test]
+    fn nested_sequence_entries_are_separate_mappings() {
+        assert!(messages("- - name: a\n  - name: b\n").is_empty());
+    }
+
+    #[test]
+    fn duplicate_key_within_an_entry() {
+        let expected = vec!["Duplicate key `name` (first on line 2)".to_string()];
+        assert_eq!(messages("-\n  name: a\n  name: b\n"), expected);
+        let expected = vec!["Duplicate key `name` (first on line 1)".to_string()];
+        assert_eq!(messages("- name: a\n  name: b\n"), expected);
+    }
+
+    #[test]
+    fn keys_after_a_sequence_belong_to_the_outer_mapping() {
+        let expected = vec!["Duplicate key `items` (first on line 1)".to_string()];
+        assert_eq!(messages("items:\n-\n  name: a\nitems: b\n"), expected);
+    }
+}
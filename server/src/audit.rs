@@ -0,0 +1,50 @@
+use crate::config::AuditSettings;
+use serde::Serialize;
+use std::io::Write;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// One change made through sysrat
+#[derive(Serialize)]
+struct AuditEntry<'a> {
+    /// Seconds since the Unix epoch
+    timestamp: u64,
+    action: &'a str,
+    target: &'a str,
+    details: &'a str,
+    success: bool,
+}
+
+/// Record a change: printed to stdout and appended to the audit file if configured
+///
+/// Failing to write the audit file is logged but does not fail the change.
+pub fn record(settings: &AuditSettings, action: &str, target: &str, details: &str, success: bool) {
+    let outcome = if success { "ok" } else { "failed" };
+    println!("[audit] {} {}: {} ({})", action, target, details, outcome);
+
+    let Some(path) = &settings.path else {
+        return;
+    };
+
+    let entry = AuditEntry {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default(),
+        action,
+        target,
+        details,
+        success,
+    };
+    let Ok(line) = serde_json::to_string(&entry) else {
+        return;
+    };
+
+    let result = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| writeln!(file, "{}", line));
+    if let Err(e) = result {
+        eprintln!("Warning: Failed to write audit log {}: {}", path, e);
+    }
+}
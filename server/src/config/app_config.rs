@@ -1,13 +1,27 @@
-use super::models::{Config, ConfigFile};
+use super::models::{
+    AuditSettings, Config, ConfigDirectory, ConfigFile, ContainerAccess, ContainerFilesSettings,
+    ExecSettings,
+};
 use super::scanner::scan_directory;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 /// Global application state holding the configuration
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct AppConfig {
-    files_by_name: HashMap<String, ConfigFile>,
-    allowed_extensions: Vec<String>,
+    pub(super) files_by_name: HashMap<String, ConfigFile>,
+    /// Compose files discovered from container labels (refreshed at runtime)
+    pub(super) compose_files: RwLock<HashMap<String, ConfigFile>>,
+    /// Files created through the API since startup
+    pub(super) created_files: RwLock<HashMap<String, ConfigFile>>,
+    /// Scanned directories (new files may be created in writable ones)
+    pub(super) directories: Vec<ConfigDirectory>,
+    pub(super) allowed_extensions: Vec<String>,
+    pub(super) expose_compose_files: bool,
+    pub(super) container_access: ContainerAccess,
+    pub(super) exec: ExecSettings,
+    pub(super) container_files: ContainerFilesSettings,
+    pub(super) audit: AuditSettings,
 }
 
 impl AppConfig {
@@ -23,6 +37,7 @@ impl AppConfig {
 
         // Store allowed extensions
         let allowed_extensions = config.settings.allowed_extensions.clone();
+        let expose_compose_files = config.settings.compose_files;
 
         // Build hashmap for fast lookups
         let mut files_by_name = HashMap::new();
@@ -33,8 +48,8 @@ impl AppConfig {
         }
 
         // Scan directories and add found files
-        for dir_config in config.directories {
-            match scan_directory(&dir_config) {
+        for dir_config in &config.directories {
+            match scan_directory(dir_config) {
                 Ok(files) => {
                     for file in files {
                         files_by_name.insert(file.name.clone(), file);
@@ -51,20 +66,41 @@ impl AppConfig {
 
         Ok(AppConfig {
             files_by_name,
+            compose_files: RwLock::new(HashMap::new()),
+            created_files: RwLock::new(HashMap::new()),
+            directories: config.directories,
             allowed_extensions,
+            expose_compose_files,
+            container_access: config.containers,
+            exec: config.exec,
+            container_files: config.container_files,
+            audit: config.audit,
         })
     }
 
-    /// Get all file names
+    /// Get all file names (configured files and discovered compose files)
     pub fn list_files(&self) -> Vec<String> {
         let mut names: Vec<_> = self.files_by_name.keys().cloned().collect();
+        if let Ok(compose_files) = self.compose_files.read() {
+            names.extend(compose_files.keys().cloned());
+        }
+        if let Ok(created_files) = self.created_files.read() {
+            names.extend(created_files.keys().cloned());
+        }
         names.sort();
+        names.dedup();
         names
     }
 
     /// Get config for a specific file
-    pub fn get_file(&self, name: &str) -> Option<&ConfigFile> {
-        self.files_by_name.get(name)
+    pub fn get_file(&self, name: &str) -> Option<ConfigFile> {
+        if let Some(file) = self.files_by_name.get(name) {
+            return Some(file.clone());
+        }
+        if let Some(file) = self.created_files.read().ok()?.get(name) {
+            return Some(file.clone());
+        }
+        self.compose_files.read().ok()?.get(name).cloned()
     }
 
     /// Get allowed file extensions
@@ -72,6 +108,26 @@ impl AppConfig {
         &self.allowed_extensions
     }
 
+    /// Get the container allow/deny rules
+    pub fn container_access(&self) -> &ContainerAccess {
+        &self.container_access
+    }
+
+    /// Get the exec session settings
+    pub fn exec_settings(&self) -> &ExecSettings {
+        &self.exec
+    }
+
+    /// Get the container file browsing settings
+    pub fn container_files_settings(&self) -> &ContainerFilesSettings {
+        &self.container_files
+    }
+
+    /// Get the audit log settings
+    pub fn audit_settings(&self) -> &AuditSettings {
+        &self.audit
+    }
+
     /// Get the config file path (XDG-compliant)
     ///
     /// Search order:
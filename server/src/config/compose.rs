@@ -0,0 +1,57 @@
+use super::app_config::AppConfig;
+use super::models::ConfigFile;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Prefix for display names of discovered compose files
+pub const COMPOSE_PREFIX: &str = "compose/";
+
+impl AppConfig {
+    /// Whether compose files should be exposed as managed configs
+    pub fn compose_files_enabled(&self) -> bool {
+        self.expose_compose_files
+    }
+
+    /// Replace the discovered compose files
+    ///
+    /// Takes `(project, path)` pairs from container labels. Files whose
+    /// extension is not whitelisted or which are already configured
+    /// explicitly are skipped.
+    pub fn set_compose_files(&self, files: Vec<(String, String)>) {
+        let mut discovered = HashMap::new();
+
+        for (project, path) in files {
+            let file_name = Path::new(&path)
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or("unknown")
+                .to_string();
+
+            let allowed = self
+                .allowed_extensions
+                .iter()
+                .any(|ext| file_name.ends_with(&format!(".{}", ext)));
+            let configured = self.files_by_name.values().any(|f| f.path == path);
+            if !allowed || configured {
+                continue;
+            }
+
+            let name = format!("{}{}/{}", COMPOSE_PREFIX, project, file_name);
+            discovered.insert(
+                name.clone(),
+                ConfigFile {
+                    path,
+                    name,
+                    description: format!("Compose file of project {}", project),
+                    readonly: false,
+                    theme: None,
+                    compose_project: Some(project),
+                },
+            );
+        }
+
+        if let Ok(mut compose_files) = self.compose_files.write() {
+            *compose_files = discovered;
+        }
+    }
+}
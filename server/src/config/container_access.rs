@@ -0,0 +1,117 @@
+use super::models::{ContainerAccess, ContainerRule};
+use std::collections::HashMap;
+
+/// What access rules are matched against
+pub struct ContainerIdentity<'a> {
+    pub name: &'a str,
+    pub project: Option<&'a str>,
+    /// Values of the labels referenced by the rules (see `label_keys`)
+    pub labels: &'a HashMap<String, String>,
+}
+
+impl ContainerAccess {
+    /// Label keys referenced by any rule
+    pub fn label_keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self
+            .allow
+            .iter()
+            .chain(&self.deny)
+            .filter_map(|rule| rule.label.as_deref())
+            .map(|label| label.split_once('=').map_or(label, |(k, _)| k).to_string())
+            .collect();
+        keys.sort();
+        keys.dedup();
+        keys
+    }
+
+    /// Whether a container is exposed at all
+    pub fn is_visible(&self, container: &ContainerIdentity) -> bool {
+        let denied = self
+            .deny
+            .iter()
+            .any(|rule| rule.actions.is_none() && rule.matches(container));
+        if denied {
+            return false;
+        }
+
+        self.allow.is_empty() || self.allow.iter().any(|rule| rule.matches(container))
+    }
+
+    /// Whether an action (e.g. "restart") may be run on a container
+    pub fn allows_action(&self, container: &ContainerIdentity, action: &str) -> bool {
+        if !self.is_visible(container) {
+            return false;
+        }
+
+        let denied = self
+            .deny
+            .iter()
+            .any(|rule| rule.covers(action) && rule.matches(container));
+        if denied {
+            return false;
+        }
+
+        self.allow.is_empty()
+            || self
+                .allow
+                .iter()
+                .any(|rule| rule.covers(action) && rule.matches(container))
+    }
+}
+
+impl ContainerRule {
+    fn matches(&self, container: &ContainerIdentity) -> bool {
+        let name_matches = self
+            .name
+            .as_deref()
+            .is_none_or(|pattern| wildcard_match(pattern, container.name));
+
+        let project_matches = self
+            .project
+            .as_deref()
+            .is_none_or(|project| container.project == Some(project));
+
+        let label_matches = self
+            .label
+            .as_deref()
+            .is_none_or(|label| match label.split_once('=') {
+                Some((key, value)) => container.labels.get(key).is_some_and(|v| v == value),
+                None => container.labels.get(label).is_some_and(|v| !v.is_empty()),
+            });
+
+        name_matches && project_matches && label_matches
+    }
+
+    fn covers(&self, action: &str) -> bool {
+        self.actions
+            .as_ref()
+            .is_none_or(|actions| actions.iter().any(|a| a == action))
+    }
+}
+
+/// Match text against a pattern with `*` (any run) and `?` (one char) wildcards
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            // Let the last `*` swallow one more character
+            p = star_p + 1;
+            t = star_t + 1;
+            backtrack = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
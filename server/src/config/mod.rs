@@ -1,5 +1,11 @@
 mod app_config;
+mod compose;
+mod container_access;
 mod models;
+mod new_files;
 mod scanner;
 
 pub use app_config::{AppConfig, SharedConfig};
+pub use compose::COMPOSE_PREFIX;
+pub use container_access::ContainerIdentity;
+pub use models::{AuditSettings, ConfigFile, ContainerAccess};
@@ -1,9 +1,25 @@
 use serde::Deserialize;
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct Settings {
     #[serde(default = "default_allowed_extensions")]
     pub allowed_extensions: Vec<String>,
+    /// Expose compose files of running projects as managed configs
+    #[serde(default = "default_compose_files")]
+    pub compose_files: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            allowed_extensions: default_allowed_extensions(),
+            compose_files: default_compose_files(),
+        }
+    }
+}
+
+fn default_compose_files() -> bool {
+    true
 }
 
 fn default_allowed_extensions() -> Vec<String> {
@@ -26,6 +42,9 @@ pub struct ConfigFile {
     /// If not specified, the default theme is used
     #[serde(default)]
     pub theme: Option<String>,
+    /// Compose project this file belongs to (set for discovered compose files)
+    #[serde(skip)]
+    pub compose_project: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -46,11 +65,88 @@ fn default_depth() -> usize {
     3
 }
 
+/// Which containers are exposed and which actions are permitted on them
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ContainerAccess {
+    /// If non-empty, only containers matching one of these rules are exposed
+    #[serde(default)]
+    pub allow: Vec<ContainerRule>,
+    /// Containers matching these rules are hidden (or lose the listed actions)
+    #[serde(default)]
+    pub deny: Vec<ContainerRule>,
+}
+
+/// Match criteria for containers; all given criteria must match
+#[derive(Debug, Clone, Deserialize)]
+pub struct ContainerRule {
+    /// Container name pattern (`*` and `?` wildcards)
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Label key (`key`) or key and value (`key=value`)
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Compose project name
+    #[serde(default)]
+    pub project: Option<String>,
+    /// Actions this rule applies to (all actions if not specified)
+    #[serde(default)]
+    pub actions: Option<Vec<String>>,
+}
+
+/// Interactive exec sessions into containers
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExecSettings {
+    /// Exec is disabled unless explicitly enabled
+    #[serde(default)]
+    pub enabled: bool,
+    /// Command started in the container
+    #[serde(default = "default_exec_command")]
+    pub command: String,
+}
+
+impl Default for ExecSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            command: default_exec_command(),
+        }
+    }
+}
+
+fn default_exec_command() -> String {
+    "/bin/sh".to_string()
+}
+
+/// Browsing and editing files inside containers
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ContainerFilesSettings {
+    /// Disabled unless explicitly enabled
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// Where changes made through sysrat are recorded
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AuditSettings {
+    /// File audit entries are appended to (one JSON object per line);
+    /// entries are only printed to stdout if not set
+    #[serde(default)]
+    pub path: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Config {
     #[serde(default)]
     pub settings: Settings,
     #[serde(default)]
+    pub containers: ContainerAccess,
+    #[serde(default)]
+    pub exec: ExecSettings,
+    #[serde(default)]
+    pub container_files: ContainerFilesSettings,
+    #[serde(default)]
+    pub audit: AuditSettings,
+    #[serde(default)]
     pub files: Vec<ConfigFile>,
     #[serde(default)]
     pub directories: Vec<ConfigDirectory>,
@@ -0,0 +1,102 @@
+use super::app_config::AppConfig;
+use super::models::{ConfigDirectory, ConfigFile};
+use super::scanner::expand_path;
+use std::path::Component;
+
+impl AppConfig {
+    /// Where a new managed file called `name` (`<directory>/<relative path>`)
+    /// would be created
+    ///
+    /// Only writable scanned directories accept new files, within their
+    /// depth and file types. The file itself must not exist yet.
+    pub async fn new_file(&self, name: &str) -> Result<ConfigFile, String> {
+        if self.get_file(name).is_some() {
+            return Err(format!("File already exists: {}", name));
+        }
+
+        let (dir_name, relative) = name
+            .split_once('/')
+            .ok_or_else(|| "New files must be inside a configured directory".to_string())?;
+        let dir = self
+            .directories
+            .iter()
+            .find(|d| d.name == dir_name)
+            .ok_or_else(|| format!("Unknown directory: {}", dir_name))?;
+        if dir.readonly {
+            return Err(format!("Directory is read-only: {}", dir_name));
+        }
+
+        let relative_path = std::path::Path::new(relative);
+        let depth = relative_path.components().count();
+        let plain = relative_path
+            .components()
+            .all(|c| matches!(c, Component::Normal(_)));
+        if !plain || depth == 0 {
+            return Err(format!("Invalid file name: {}", name));
+        }
+        if depth > dir.depth {
+            return Err(format!(
+                "{} only holds files up to {} level(s) deep",
+                dir_name, dir.depth
+            ));
+        }
+        if !accepts_type(dir, relative) {
+            return Err(format!(
+                "{} only holds {} files",
+                dir_name,
+                dir.types.join(", ")
+            ));
+        }
+
+        let path = expand_path(&dir.path)?.join(relative_path);
+        if tokio::fs::try_exists(&path).await.unwrap_or(false) {
+            return Err(format!("File already exists on disk: {}", path.display()));
+        }
+
+        Ok(ConfigFile {
+            path: path.to_string_lossy().to_string(),
+            name: name.to_string(),
+            description: format!("From directory: {}", dir.description),
+            readonly: false,
+            theme: None,
+            compose_project: None,
+        })
+    }
+
+    /// Make a newly created file available as a managed config
+    pub fn add_file(&self, file: ConfigFile) {
+        if let Ok(mut created_files) = self.created_files.write() {
+            created_files.insert(file.name.clone(), file);
+        }
+    }
+
+    /// Suggest a name for a new file `<stem>.<ext>` in the first writable
+    /// directory that accepts one of `extensions`
+    pub async fn new_file_name(&self, stem: &str, extensions: &[&str]) -> Option<String> {
+        for ext in extensions {
+            let file_name = format!("{}.{}", stem, ext);
+            if !self.allowed_extensions.iter().any(|e| e == ext) {
+                continue;
+            }
+            let dirs = self
+                .directories
+                .iter()
+                .filter(|d| !d.readonly && accepts_type(d, &file_name));
+            for dir in dirs {
+                let name = format!("{}/{}", dir.name, file_name);
+                if self.new_file(&name).await.is_ok() {
+                    return Some(name);
+                }
+            }
+        }
+        None
+    }
+}
+
+fn accepts_type(dir: &ConfigDirectory, file_name: &str) -> bool {
+    dir.types.is_empty()
+        || dir
+            .types
+            .iter()
+            .any(|t| file_name.ends_with(&format!(".{}", t)))
+}
@@ -5,16 +5,7 @@ use walkdir::WalkDir;
 /// Scan a directory and return all matching files
 pub fn scan_directory(dir_config: &ConfigDirectory) -> Result<Vec<ConfigFile>, String> {
     let mut found_files = Vec::new();
-    let base_path = Path::new(&dir_config.path);
-
-    // Expand home directory
-    let expanded_path = if dir_config.path.starts_with("~/") {
-        let home =
-            std::env::var("HOME").map_err(|_| "HOME environment variable not set".to_string())?;
-        PathBuf::from(home).join(&dir_config.path[2..])
-    } else {
-        base_path.to_path_buf()
-    };
+    let expanded_path = expand_path(&dir_config.path)?;
 
     if !expanded_path.exists() {
         return Err(format!(
@@ -73,6 +64,7 @@ pub fn scan_directory(dir_config: &ConfigDirectory) -> Result<Vec<ConfigFile>, S
             description: format!("From directory: {}", dir_config.description),
             readonly: dir_config.readonly,
             theme: None,
+            compose_project: None,
         });
     }
 
@@ -81,3 +73,15 @@ pub fn scan_directory(dir_config: &ConfigDirectory) -> Result<Vec<ConfigFile>, S
 
     Ok(found_files)
 }
+
+/// Expand a leading `~/` to the home directory
+pub(super) fn expand_path(path: &str) -> Result<PathBuf, String> {
+    match path.strip_prefix("~/") {
+        Some(rest) => {
+            let home = std::env::var("HOME")
+                .map_err(|_| "HOME environment variable not set".to_string())?;
+            Ok(PathBuf::from(home).join(rest))
+        }
+        None => Ok(Path::new(path).to_path_buf()),
+    }
+}
@@ -0,0 +1,72 @@
+mod registry;
+
+pub use registry::{JobHandle, JobRegistry, SharedJobs};
+
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatus {
+    Running,
+    Succeeded,
+    Failed,
+}
+
+/// What a job operates on; every container and the project (if any) stay
+/// locked until the job finishes
+#[derive(Clone)]
+pub struct JobTarget {
+    /// Display name (container or project)
+    pub name: String,
+    pub project: Option<String>,
+    pub container_ids: Vec<String>,
+}
+
+impl JobTarget {
+    pub fn container(id: &str, name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            project: None,
+            container_ids: vec![id.to_string()],
+        }
+    }
+
+    pub fn project(name: &str, container_ids: &[String]) -> Self {
+        Self {
+            name: name.to_string(),
+            project: Some(name.to_string()),
+            container_ids: container_ids.to_vec(),
+        }
+    }
+
+    fn lock_keys(&self) -> Vec<String> {
+        let mut keys = self.container_ids.clone();
+        if let Some(project) = &self.project {
+            keys.push(format!("project:{}", project));
+        }
+        keys
+    }
+}
+
+/// Snapshot of a job as returned by the API
+#[derive(Serialize, Clone)]
+pub struct JobInfo {
+    pub id: u64,
+    pub action: String,
+    pub target: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    pub container_ids: Vec<String>,
+    pub status: JobStatus,
+    /// Current step while running (e.g. "docker compose pull")
+    pub step: String,
+    /// Output lines of the docker commands run so far
+    pub output: Vec<String>,
+    /// Result message once finished (error text for failed jobs)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Unix timestamps in seconds
+    pub started_at: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<u64>,
+}
@@ -0,0 +1,194 @@
+use super::{JobInfo, JobStatus, JobTarget};
+use axum::http::StatusCode;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Finished jobs kept for the job endpoints
+const FINISHED_JOBS_KEPT: usize = 50;
+/// Output lines kept per job
+const OUTPUT_LINES_KEPT: usize = 200;
+
+pub type SharedJobs = Arc<JobRegistry>;
+
+/// Background container operations and the locks they hold
+#[derive(Default)]
+pub struct JobRegistry {
+    next_id: AtomicU64,
+    jobs: Mutex<VecDeque<JobInfo>>,
+    /// Lock key (container id or `project:<name>`) -> job id
+    locks: Mutex<HashMap<String, u64>>,
+}
+
+impl JobRegistry {
+    pub fn new() -> SharedJobs {
+        Arc::new(Self::default())
+    }
+
+    /// Lock the target and run `task` in the background
+    ///
+    /// Fails with 409 if another job still holds one of the target's locks.
+    /// The task returns the result message, or an error that fails the job.
+    pub fn start<F, Fut>(
+        self: &Arc<Self>,
+        action: &str,
+        target: JobTarget,
+        task: F,
+    ) -> Result<JobInfo, (StatusCode, String)>
+    where
+        F: FnOnce(JobHandle) -> Fut,
+        Fut: Future<Output = Result<String, (StatusCode, String)>> + Send + 'static,
+    {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let keys = target.lock_keys();
+
+        {
+            let mut locks = self.locks.lock().unwrap();
+            if let Some(busy) = keys.iter().find_map(|k| locks.get(k)) {
+                let running = self
+                    .get(*busy)
+                    .map(|j| format!("{} {}", j.action, j.target))
+                    .unwrap_or_default();
+                return Err((
+                    StatusCode::CONFLICT,
+                    format!(
+                        "{} is busy: job {} ({}) is running",
+                        target.name, busy, running
+                    ),
+                ));
+            }
+            for key in &keys {
+                locks.insert(key.clone(), id);
+            }
+        }
+
+        let job = JobInfo {
+            id,
+            action: action.to_string(),
+            target: target.name,
+            project: target.project,
+            container_ids: target.container_ids,
+            status: JobStatus::Running,
+            step: String::new(),
+            output: Vec::new(),
+            message: None,
+            started_at: now(),
+            finished_at: None,
+        };
+        self.jobs.lock().unwrap().push_back(job.clone());
+
+        let handle = JobHandle {
+            registry: Arc::clone(self),
+            id,
+        };
+        let future = task(handle.clone());
+        tokio::spawn(async move {
+            // Run the task on its own so a panic still fails the job and
+            // releases its locks
+            let result = tokio::spawn(future).await.unwrap_or_else(|e| {
+                Err((
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("Job panicked: {}", e),
+                ))
+            });
+            handle.finish(result, &keys);
+        });
+
+        Ok(job)
+    }
+
+    pub fn get(&self, id: u64) -> Option<JobInfo> {
+        self.jobs
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|j| j.id == id)
+            .cloned()
+    }
+
+    /// Running and recently finished jobs, newest first
+    pub fn list(&self) -> Vec<JobInfo> {
+        self.jobs.lock().unwrap().iter().rev().cloned().collect()
+    }
+
+    fn update(&self, id: u64, f: impl FnOnce(&mut JobInfo)) {
+        if let Some(job) = self.jobs.lock().unwrap().iter_mut().find(|j| j.id == id) {
+            f(job);
+        }
+    }
+}
+
+/// Lets a running job report progress
+#[derive(Clone)]
+pub struct JobHandle {
+    registry: SharedJobs,
+    id: u64,
+}
+
+impl JobHandle {
+    pub fn step(&self, step: impl Into<String>) {
+        let step = step.into();
+        self.registry.update(self.id, |job| job.step = step);
+    }
+
+    /// Display name of what the job operates on
+    pub fn target(&self) -> String {
+        self.registry
+            .get(self.id)
+            .map(|job| job.target)
+            .unwrap_or_default()
+    }
+
+    pub fn log(&self, line: impl Into<String>) {
+        let line = line.into();
+        self.registry.update(self.id, |job| {
+            if job.output.len() >= OUTPUT_LINES_KEPT {
+                job.output.remove(0);
+            }
+            job.output.push(line);
+        });
+    }
+
+    fn finish(&self, result: Result<String, (StatusCode, String)>, keys: &[String]) {
+        self.registry.update(self.id, |job| {
+            let (status, message) = match result {
+                Ok(message) => (JobStatus::Succeeded, message),
+                Err((_, error)) => (JobStatus::Failed, error),
+            };
+            job.status = status;
+            job.message = Some(message);
+            job.step.clear();
+            job.finished_at = Some(now());
+        });
+
+        let mut locks = self.registry.locks.lock().unwrap();
+        for key in keys {
+            if locks.get(key) == Some(&self.id) {
+                locks.remove(key);
+            }
+        }
+        drop(locks);
+
+        // Forget the oldest finished jobs
+        let mut jobs = self.registry.jobs.lock().unwrap();
+        let mut finished = jobs
+            .iter()
+            .filter(|j| j.status != JobStatus::Running)
+            .count();
+        while finished > FINISHED_JOBS_KEPT {
+            if let Some(pos) = jobs.iter().position(|j| j.status != JobStatus::Running) {
+                jobs.remove(pos);
+            }
+            finished -= 1;
+        }
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
@@ -1,10 +1,13 @@
+mod audit;
 mod config;
+mod jobs;
 mod routes;
+mod state;
 mod version;
 
 use axum::{
     Router,
-    routing::{get, post},
+    routing::{get, post, put},
 };
 use std::sync::Arc;
 use tower_http::services::ServeDir;
@@ -39,19 +42,70 @@ async fn main() {
         .route("/api/configs", get(routes::list_configs))
         .route("/api/configs/{*filename}", get(routes::read_config))
         .route("/api/configs/{*filename}", post(routes::write_config))
+        .route("/api/configs/{*filename}", put(routes::create_config))
         .route("/api/containers", get(routes::list_containers))
         .route(
             "/api/containers/{id}/details",
             get(routes::get_container_details),
         )
+        .route("/api/containers/{id}/compose", get(routes::export_compose))
+        .route(
+            "/api/containers/{id}/files",
+            get(routes::list_container_files),
+        )
         .route("/api/containers/{id}/start", post(routes::start_container))
         .route("/api/containers/{id}/stop", post(routes::stop_container))
         .route(
             "/api/containers/{id}/restart",
             post(routes::restart_container),
         )
-        // Pass config as state
-        .with_state(app_config)
+        .route("/api/containers/{id}/pause", post(routes::pause_container))
+        .route(
+            "/api/containers/{id}/unpause",
+            post(routes::unpause_container),
+        )
+        .route("/api/containers/{id}/exec", get(routes::exec_container))
+        .route("/api/containers/{id}/kill", post(routes::kill_container))
+        .route(
+            "/api/containers/{id}/remove",
+            post(routes::remove_container),
+        )
+        .route(
+            "/api/containers/{id}/recreate",
+            post(routes::recreate_container),
+        )
+        .route(
+            "/api/containers/{id}/update",
+            post(routes::update_container),
+        )
+        .route("/api/jobs", get(routes::list_jobs))
+        .route("/api/jobs/{id}", get(routes::get_job))
+        .route("/api/images", get(routes::list_images))
+        .route("/api/images/prune", post(routes::prune_images))
+        .route("/api/images/{id}", get(routes::inspect_image))
+        .route("/api/images/{id}/remove", post(routes::remove_image))
+        .route("/api/volumes", get(routes::list_volumes))
+        .route("/api/volumes/prune", post(routes::prune_volumes))
+        .route("/api/volumes/{name}/remove", post(routes::remove_volume))
+        .route("/api/networks", get(routes::list_networks))
+        .route("/api/networks/prune", post(routes::prune_networks))
+        .route("/api/networks/{id}/remove", post(routes::remove_network))
+        .route("/api/projects", get(routes::list_projects))
+        .route("/api/projects/{name}/start", post(routes::start_project))
+        .route("/api/projects/{name}/stop", post(routes::stop_project))
+        .route(
+            "/api/projects/{name}/restart",
+            post(routes::restart_project),
+        )
+        .route("/api/projects/{name}/up", post(routes::compose_up))
+        .route("/api/projects/{name}/down", post(routes::compose_down))
+        .route("/api/projects/{name}/plan", get(routes::plan_project))
+        .route("/api/projects/{name}/apply", post(routes::apply_project))
+        // Handlers extract the config or the job registry from the state
+        .with_state(state::ServerState {
+            config: app_config,
+            jobs: jobs::JobRegistry::new(),
+        })
         // Static files (frontend)
         .fallback_service(ServeDir::new("frontend/dist"));
 
@@ -68,10 +122,34 @@ async fn main() {
     println!("  GET  /api/configs");
     println!("  GET  /api/configs/{{*filename}}");
     println!("  POST /api/configs/{{*filename}}");
+    println!("  PUT  /api/configs/{{*filename}} (create in a configured directory)");
     println!("  GET  /api/containers");
     println!("  POST /api/containers/{{id}}/start");
     println!("  POST /api/containers/{{id}}/stop");
     println!("  POST /api/containers/{{id}}/restart");
+    println!("  POST /api/containers/{{id}}/{{pause|unpause|recreate}}");
+    println!("  GET  /api/containers/{{id}}/exec (WebSocket)");
+    println!("  GET  /api/containers/{{id}}/compose");
+    println!("  GET  /api/containers/{{id}}/files?path=DIR");
+    println!("  POST /api/containers/{{id}}/kill?signal=SIGNAL");
+    println!("  POST /api/containers/{{id}}/remove?volumes=BOOL&force=BOOL");
+    println!("  POST /api/containers/{{id}}/update (JSON restart_policy, memory, cpus)");
+    println!("  GET  /api/jobs");
+    println!("  GET  /api/jobs/{{id}}");
+    println!("  GET  /api/images");
+    println!("  GET  /api/images/{{id}}");
+    println!("  POST /api/images/{{id}}/remove?force=BOOL");
+    println!("  POST /api/images/prune");
+    println!("  GET  /api/volumes");
+    println!("  POST /api/volumes/{{name}}/remove");
+    println!("  POST /api/volumes/prune");
+    println!("  GET  /api/networks");
+    println!("  POST /api/networks/{{id}}/remove");
+    println!("  POST /api/networks/prune");
+    println!("  GET  /api/projects");
+    println!("  POST /api/projects/{{name}}/{{start|stop|restart|up|down}}");
+    println!("  GET  /api/projects/{{name}}/plan");
+    println!("  POST /api/projects/{{name}}/apply");
 
     axum::serve(listener, app).await.unwrap();
 }
@@ -1,3 +1,8 @@
+use super::super::containers::{
+    CONTAINER_FILE_PREFIX, browsable_containers, container_mounts, read_container_file, users_of,
+    write_container_file,
+};
+use super::lookup::{find_file, refresh_compose_files};
 use super::validation::validate_filename;
 use crate::config::SharedConfig;
 use crate::routes::types::{
@@ -8,11 +13,19 @@ use axum::{
     extract::{Path, State},
     http::StatusCode,
 };
+use tokio::io::AsyncWriteExt;
 
 /// GET /api/configs - List all config files
 pub async fn list_configs(
     State(config): State<SharedConfig>,
 ) -> Result<Json<FileListResponse>, (StatusCode, String)> {
+    refresh_compose_files(&config).await;
+
+    // Cross-reference bind mounts (docker errors just mean no users)
+    let mounts = container_mounts(config.container_access())
+        .await
+        .unwrap_or_default();
+
     // Build file info list with metadata
     let mut files = Vec::new();
     for name in config.list_files() {
@@ -22,10 +35,13 @@ pub async fn list_configs(
                 description: file_cfg.description.clone(),
                 readonly: file_cfg.readonly,
                 theme: file_cfg.theme.clone(),
+                compose_project: file_cfg.compose_project.clone(),
+                used_by: users_of(&file_cfg.path, &mounts).await,
             });
         }
     }
-    Ok(Json(FileListResponse { files }))
+    let containers = browsable_containers(&config).await;
+    Ok(Json(FileListResponse { files, containers }))
 }
 
 /// GET /api/configs/*filename - Read a config file
@@ -36,13 +52,16 @@ pub async fn read_config(
     // Wildcard routes include leading slash, strip it
     let filename = filename.strip_prefix('/').unwrap_or(&filename);
 
+    // Files inside containers are not managed configs (no extension whitelist)
+    if filename.starts_with(CONTAINER_FILE_PREFIX) {
+        let content = read_container_file(&config, filename).await?;
+        return Ok(Json(FileContentResponse { content }));
+    }
+
     validate_filename(filename, &config)?;
 
     // Look up file in config
-    let file_config = config.get_file(filename).ok_or((
-        StatusCode::NOT_FOUND,
-        format!("File not found in config: {}", filename),
-    ))?;
+    let file_config = find_file(&config, filename).await?;
 
     let path = &file_config.path;
 
@@ -68,13 +87,15 @@ pub async fn write_config(
     // Wildcard routes include leading slash, strip it
     let filename = filename.strip_prefix('/').unwrap_or(&filename);
 
+    if filename.starts_with(CONTAINER_FILE_PREFIX) {
+        write_container_file(&config, filename, &payload.content).await?;
+        return Ok(Json(WriteConfigResponse { success: true }));
+    }
+
     validate_filename(filename, &config)?;
 
     // Look up file in config
-    let file_config = config.get_file(filename).ok_or((
-        StatusCode::NOT_FOUND,
-        format!("File not found in config: {}", filename),
-    ))?;
+    let file_config = find_file(&config, filename).await?;
 
     // Check if file is readonly
     if file_config.readonly {
@@ -98,3 +119,54 @@ pub async fn write_config(
         )),
     }
 }
+
+/// PUT /api/configs/*filename - Create a new file in a configured directory
+pub async fn create_config(
+    State(config): State<SharedConfig>,
+    Path(filename): Path<String>,
+    Json(payload): Json<WriteConfigRequest>,
+) -> Result<Json<WriteConfigResponse>, (StatusCode, String)> {
+    // Wildcard routes include leading slash, strip it
+    let filename = filename.strip_prefix('/').unwrap_or(&filename);
+
+    validate_filename(filename, &config)?;
+
+    let file_config = config
+        .new_file(filename)
+        .await
+        .map_err(|e| (StatusCode::CONFLICT, e))?;
+    let path = std::path::Path::new(&file_config.path);
+
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await.map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to create directory: {}", e),
+            )
+        })?;
+    }
+
+    // create_new: never overwrite a file that appeared in the meantime
+    let mut file = tokio::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Create error: {}", e),
+            )
+        })?;
+    file.write_all(payload.content.as_bytes())
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Write error: {}", e),
+            )
+        })?;
+
+    config.add_file(file_config);
+    Ok(Json(WriteConfigResponse { success: true }))
+}
@@ -0,0 +1,37 @@
+use super::super::containers::compose_config_files;
+use crate::config::{COMPOSE_PREFIX, ConfigFile, SharedConfig};
+use axum::http::StatusCode;
+
+/// Rediscover compose files from container labels (no-op if disabled)
+/// Docker errors are ignored so the file list keeps working without docker
+pub(super) async fn refresh_compose_files(config: &SharedConfig) {
+    if !config.compose_files_enabled() {
+        return;
+    }
+    if let Ok(files) = compose_config_files(config.container_access()).await {
+        config.set_compose_files(files);
+    }
+}
+
+/// Look up a managed file by name
+/// Compose files are rediscovered once if they are not known yet
+pub(super) async fn find_file(
+    config: &SharedConfig,
+    filename: &str,
+) -> Result<ConfigFile, (StatusCode, String)> {
+    if let Some(file) = config.get_file(filename) {
+        return Ok(file);
+    }
+
+    if filename.starts_with(COMPOSE_PREFIX) {
+        refresh_compose_files(config).await;
+        if let Some(file) = config.get_file(filename) {
+            return Ok(file);
+        }
+    }
+
+    Err((
+        StatusCode::NOT_FOUND,
+        format!("File not found in config: {}", filename),
+    ))
+}
@@ -1,4 +1,5 @@
 mod handlers;
+mod lookup;
 mod validation;
 
-pub use handlers::{list_configs, read_config, write_config};
+pub use handlers::{create_config, list_configs, read_config, write_config};
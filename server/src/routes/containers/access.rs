@@ -0,0 +1,63 @@
+use super::super::docker::validate_docker_name;
+use super::listing::{ListedContainer, fetch_containers};
+use crate::config::ContainerAccess;
+use axum::http::StatusCode;
+
+/// Find a visible container by ID (full or short) or name
+pub(super) async fn find_container(
+    access: &ContainerAccess,
+    id: &str,
+) -> Result<ListedContainer, (StatusCode, String)> {
+    validate_docker_name(id, "container id")?;
+
+    let mut containers = fetch_containers(access).await?;
+    if let Some(pos) = containers.iter().position(|c| c.info.name == id) {
+        return Ok(containers.swap_remove(pos));
+    }
+
+    // ID prefix (like docker) or full ID of a listed short ID
+    let mut matches: Vec<_> = containers
+        .into_iter()
+        .filter(|c| c.info.id.starts_with(id) || id.starts_with(&c.info.id))
+        .collect();
+
+    match matches.len() {
+        0 => Err((
+            StatusCode::NOT_FOUND,
+            format!("Container not found: {}", id),
+        )),
+        1 => Ok(matches.remove(0)),
+        _ => Err((
+            StatusCode::BAD_REQUEST,
+            format!("Ambiguous container id: {}", id),
+        )),
+    }
+}
+
+/// Resolve a container and check an action against the access rules
+/// Returns the container ID to pass to docker
+pub(super) async fn authorize(
+    access: &ContainerAccess,
+    id: &str,
+    action: &str,
+) -> Result<String, (StatusCode, String)> {
+    let container = find_container(access, id).await?;
+    ensure_action(access, &container, action)?;
+    Ok(container.info.id)
+}
+
+/// Reject actions the access rules don't permit on a container
+pub(super) fn ensure_action(
+    access: &ContainerAccess,
+    container: &ListedContainer,
+    action: &str,
+) -> Result<(), (StatusCode, String)> {
+    if !access.allows_action(&container.identity(), action) {
+        return Err((
+            StatusCode::FORBIDDEN,
+            format!("Action {} not permitted on {}", action, container.info.name),
+        ));
+    }
+
+    Ok(())
+}
@@ -1,48 +1,78 @@
-use super::super::types::ContainerActionResponse;
+use super::super::docker::run_docker_logged;
+use super::super::types::JobResponse;
+use super::access::{ensure_action, find_container};
+use crate::config::SharedConfig;
+use crate::jobs::{JobHandle, JobTarget, SharedJobs};
 use axum::{Json, http::StatusCode};
+use std::future::Future;
 use std::time::Duration;
-use tokio::process::Command;
 
-/// Execute a docker action (start/stop/restart) on a container
+/// Timeout for single docker actions (start/stop/restart/...)
+pub(super) const ACTION_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Execute a docker action (start/stop/restart/pause/unpause) on a container
 /// Timeout: 120 seconds for long-running operations
 pub(super) async fn execute_container_action(
+    job: &JobHandle,
     container_id: &str,
     action: &str,
-) -> Result<Json<ContainerActionResponse>, (StatusCode, String)> {
-    let docker_cmd = Command::new("docker").args([action, container_id]).output();
+) -> Result<String, (StatusCode, String)> {
+    run_docker_logged(job, &[action, container_id], action, ACTION_TIMEOUT).await?;
+    Ok(format!("container {}", past_tense(action)))
+}
 
-    let output = tokio::time::timeout(Duration::from_secs(120), docker_cmd)
-        .await
-        .map_err(|_| {
-            (
-                StatusCode::REQUEST_TIMEOUT,
-                format!("docker {} timed out", action),
-            )
-        })?
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("docker {} failed: {}", action, e),
-            )
-        })?;
+/// Check that `action` is allowed on a container and run `task` as a job
+/// holding the container's lock. The task gets the full container ID.
+pub(super) async fn container_job<F, Fut>(
+    config: &SharedConfig,
+    jobs: &SharedJobs,
+    id: &str,
+    action: &str,
+    task: F,
+) -> Result<Json<JobResponse>, (StatusCode, String)>
+where
+    F: FnOnce(JobHandle, String) -> Fut,
+    Fut: Future<Output = Result<String, (StatusCode, String)>> + Send + 'static,
+{
+    let access = config.container_access();
+    let container = find_container(access, id).await?;
+    ensure_action(access, &container, action)?;
 
-    if !output.status.success() {
-        let error = String::from_utf8_lossy(&output.stderr);
-        return Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            format!("docker {} failed: {}", action, error),
-        ));
-    }
+    let container_id = container.info.id.clone();
+    let target = JobTarget::container(&container.info.id, &container.info.name);
+    let job = jobs.start(action, target, |job| task(job, container_id))?;
+
+    Ok(Json(JobResponse { job }))
+}
+
+/// Job running a plain `docker <action> <id>` on a container
+pub(super) async fn simple_container_job(
+    config: &SharedConfig,
+    jobs: &SharedJobs,
+    id: &str,
+    action: &'static str,
+) -> Result<Json<JobResponse>, (StatusCode, String)> {
+    container_job(
+        config,
+        jobs,
+        id,
+        action,
+        move |job, container_id| async move {
+            execute_container_action(&job, &container_id, action).await
+        },
+    )
+    .await
+}
 
-    let past_tense = match action {
+pub(super) fn past_tense(action: &str) -> &str {
+    match action {
         "start" => "started",
         "stop" => "stopped",
         "restart" => "restarted",
+        "pause" => "paused",
+        "unpause" => "unpaused",
+        "kill" => "killed",
+        "rm" => "removed",
         _ => action,
-    };
-
-    Ok(Json(ContainerActionResponse {
-        success: true,
-        message: format!("container {}", past_tense),
-    }))
+    }
 }
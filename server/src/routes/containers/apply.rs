@@ -0,0 +1,117 @@
+use super::super::docker::{run_docker, run_docker_logged};
+use super::super::types::{ApplyPlanResponse, JobResponse, ServicePlan};
+use super::actions::ACTION_TIMEOUT;
+use super::compose::compose_args;
+use super::listing::fetch_containers;
+use super::projects::{COMPOSE_TIMEOUT, find_project};
+use crate::config::SharedConfig;
+use crate::jobs::{JobTarget, SharedJobs};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+/// GET /api/projects/:name/plan - Validate compose files and list services to recreate
+pub async fn plan_project(
+    State(config): State<SharedConfig>,
+    Path(name): Path<String>,
+) -> Result<Json<ApplyPlanResponse>, (StatusCode, String)> {
+    let access = config.container_access();
+    let project = find_project(access, &name, None).await?;
+
+    let validate_args = compose_args(&project, &["config", "--quiet"]).await?;
+    if let Err((_, error)) = run_compose(&validate_args, "compose config").await {
+        return Ok(Json(ApplyPlanResponse {
+            valid: false,
+            error: Some(error),
+            services: Vec::new(),
+        }));
+    }
+
+    // Compose recreates a container when its config hash label differs
+    let hash_args = compose_args(&project, &["config", "--hash", "*"]).await?;
+    let hashes = run_compose(&hash_args, "compose config").await?;
+    let containers = fetch_containers(access).await?;
+    let project_containers: Vec<_> = containers
+        .iter()
+        .filter(|c| c.info.project.as_deref() == Some(project.name.as_str()))
+        .collect();
+
+    let mut services = Vec::new();
+    for line in hashes.lines() {
+        let Some((service, hash)) = line.split_once(' ') else {
+            continue;
+        };
+        let existing: Vec<_> = project_containers
+            .iter()
+            .filter(|c| c.info.service.as_deref() == Some(service))
+            .collect();
+
+        let action = if existing.is_empty() {
+            "create"
+        } else if existing
+            .iter()
+            .any(|c| c.config_hash.as_deref() != Some(hash.trim()))
+        {
+            "recreate"
+        } else {
+            "unchanged"
+        };
+        services.push(ServicePlan {
+            service: service.to_string(),
+            action: action.to_string(),
+        });
+    }
+
+    // Containers whose service is no longer defined
+    for container in &project_containers {
+        if let Some(service) = &container.info.service
+            && !services.iter().any(|s| &s.service == service)
+        {
+            services.push(ServicePlan {
+                service: service.clone(),
+                action: "orphan".to_string(),
+            });
+        }
+    }
+
+    Ok(Json(ApplyPlanResponse {
+        valid: true,
+        error: None,
+        services,
+    }))
+}
+
+/// POST /api/projects/:name/apply - Validate, then run `compose up -d` as a job
+///
+/// Invalid compose files fail with 422 before the job starts; the output of
+/// `compose up` is collected by the job.
+pub async fn apply_project(
+    State(config): State<SharedConfig>,
+    State(jobs): State<SharedJobs>,
+    Path(name): Path<String>,
+) -> Result<Json<JobResponse>, (StatusCode, String)> {
+    // Applying runs `compose up`, so it needs the same permission
+    let project = find_project(config.container_access(), &name, Some("up")).await?;
+
+    let validate_args = compose_args(&project, &["config", "--quiet"]).await?;
+    run_compose(&validate_args, "compose config")
+        .await
+        .map_err(|(_, e)| (StatusCode::UNPROCESSABLE_ENTITY, e))?;
+
+    let args = compose_args(&project, &["--progress", "plain", "up", "-d"]).await?;
+    let target = JobTarget::project(&project.name, &project.containers);
+    let job = jobs.start("apply", target, |job| async move {
+        let args: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+        run_docker_logged(&job, &args, "compose up", COMPOSE_TIMEOUT).await?;
+        Ok("project applied".to_string())
+    })?;
+
+    Ok(Json(JobResponse { job }))
+}
+
+async fn run_compose(args: &[String], action: &str) -> Result<String, (StatusCode, String)> {
+    let args: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+    run_docker(&args, action, ACTION_TIMEOUT).await
+}
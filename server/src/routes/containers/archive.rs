@@ -0,0 +1,143 @@
+/// Tar block size (only as much of the format as `docker cp` of single files needs)
+const BLOCK: usize = 512;
+/// Longest name that fits into the header itself
+const NAME_LEN: usize = 100;
+/// Name of the GNU entry holding a longer name for the entry after it
+const LONG_NAME: &str = "././@LongLink";
+
+/// Ownership and permissions of an archived file
+#[derive(Clone, Copy)]
+pub(super) struct EntryMeta {
+    pub mode: u64,
+    pub uid: u64,
+    pub gid: u64,
+    pub mtime: u64,
+}
+
+/// First entry of a `docker cp` archive
+pub(super) struct ArchiveEntry {
+    /// Tar type flag (`b'0'` regular file, `b'5'` directory, `b'2'` symlink)
+    pub kind: u8,
+    pub meta: EntryMeta,
+    /// Symlink target
+    pub link: String,
+    pub data: Vec<u8>,
+}
+
+/// Read the first real entry, skipping pax and GNU long name headers
+pub(super) fn first_entry(archive: &[u8]) -> Option<ArchiveEntry> {
+    let mut offset = 0;
+    while offset + BLOCK <= archive.len() {
+        let header = &archive[offset..offset + BLOCK];
+        if header.iter().all(|&b| b == 0) {
+            return None;
+        }
+
+        let size = octal(&header[124..136]) as usize;
+        let kind = header[156];
+        let data_start = offset + BLOCK;
+        let data_end = data_start.checked_add(size)?;
+        if data_end > archive.len() {
+            return None;
+        }
+
+        if matches!(kind, b'x' | b'g' | b'L' | b'K') {
+            offset = data_start + padded(size);
+            continue;
+        }
+
+        return Some(ArchiveEntry {
+            kind: if kind == 0 { b'0' } else { kind },
+            meta: EntryMeta {
+                mode: octal(&header[100..108]),
+                uid: octal(&header[108..116]),
+                gid: octal(&header[116..124]),
+                mtime: octal(&header[136..148]),
+            },
+            link: text(&header[157..257]),
+            data: archive[data_start..data_end].to_vec(),
+        });
+    }
+    None
+}
+
+/// Build an archive of regular files, all with the same metadata
+///
+/// Fails if a value doesn't fit into its header field.
+pub(super) fn build(files: &[(&str, &[u8])], meta: EntryMeta) -> Result<Vec<u8>, String> {
+    let mut archive = Vec::new();
+    for (name, data) in files {
+        // Longer names go into a GNU long name entry before the file's own
+        if name.len() > NAME_LEN {
+            let mut long_name = name.as_bytes().to_vec();
+            long_name.push(0);
+            let header = header(LONG_NAME, long_name.len(), meta, b'L')?;
+            push_entry(&mut archive, &header, &long_name);
+        }
+        let header = header(name, data.len(), meta, b'0')?;
+        push_entry(&mut archive, &header, data);
+    }
+    // End of archive marker
+    archive.resize(archive.len() + 2 * BLOCK, 0);
+    Ok(archive)
+}
+
+fn push_entry(archive: &mut Vec<u8>, header: &[u8], data: &[u8]) {
+    archive.extend_from_slice(header);
+    archive.extend_from_slice(data);
+    archive.resize(archive.len() + padded(data.len()) - data.len(), 0);
+}
+
+/// Header of an entry; names over `NAME_LEN` bytes are cut off (the long
+/// name entry before it has the full name)
+fn header(name: &str, size: usize, meta: EntryMeta, kind: u8) -> Result<[u8; BLOCK], String> {
+    let mut header = [0u8; BLOCK];
+    let name = name.as_bytes();
+    let len = name.len().min(NAME_LEN);
+    header[..len].copy_from_slice(&name[..len]);
+
+    write_octal(&mut header[100..108], meta.mode & 0o7777, "mode")?;
+    write_octal(&mut header[108..116], meta.uid, "uid")?;
+    write_octal(&mut header[116..124], meta.gid, "gid")?;
+    write_octal(&mut header[124..136], size as u64, "size")?;
+    write_octal(&mut header[136..148], meta.mtime, "mtime")?;
+    header[156] = kind;
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+
+    // Checksum is computed with the checksum field filled with spaces
+    header[148..156].fill(b' ');
+    let checksum: u64 = header.iter().map(|&b| b as u64).sum();
+    write_octal(&mut header[148..155], checksum, "checksum")?;
+    header[155] = b' ';
+    Ok(header)
+}
+
+/// Zero-padded octal number followed by a NUL byte
+fn write_octal(field: &mut [u8], value: u64, what: &str) -> Result<(), String> {
+    let digits = field.len() - 1;
+    let text = format!("{:0width$o}", value, width = digits);
+    if text.len() > digits {
+        return Err(format!(
+            "File {} {} is too large for the archive",
+            what, value
+        ));
+    }
+    field[..digits].copy_from_slice(text.as_bytes());
+    field[digits] = 0;
+    Ok(())
+}
+
+fn octal(field: &[u8]) -> u64 {
+    let text = text(field);
+    u64::from_str_radix(text.trim(), 8).unwrap_or(0)
+}
+
+fn text(field: &[u8]) -> String {
+    let end = field.iter().position(|&b| b == 0).unwrap_or(field.len());
+    String::from_utf8_lossy(&field[..end]).to_string()
+}
+
+fn padded(size: usize) -> usize {
+    size.div_ceil(BLOCK) * BLOCK
+}
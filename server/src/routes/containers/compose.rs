@@ -0,0 +1,96 @@
+use super::super::types::ProjectInfo;
+use super::listing::ListedContainer;
+use axum::http::StatusCode;
+use std::collections::BTreeMap;
+
+/// Group listed containers by their compose project (sorted by project name)
+pub(super) fn group_projects(containers: &[ListedContainer]) -> Vec<ProjectInfo> {
+    let mut projects: BTreeMap<String, ProjectInfo> = BTreeMap::new();
+
+    for listed in containers {
+        let Some(name) = &listed.info.project else {
+            continue;
+        };
+
+        let project = projects.entry(name.clone()).or_insert_with(|| ProjectInfo {
+            name: name.clone(),
+            containers: Vec::new(),
+            running: 0,
+            unhealthy: 0,
+            config_files: Vec::new(),
+            working_dir: None,
+        });
+
+        project.containers.push(listed.info.id.clone());
+        if listed.info.state == "running" {
+            project.running += 1;
+        }
+        if listed.info.status.contains("(unhealthy)") {
+            project.unhealthy += 1;
+        }
+        if project.config_files.is_empty() {
+            project.config_files = listed.config_files.clone();
+        }
+        if project.working_dir.is_none() {
+            project.working_dir = listed.working_dir.clone();
+        }
+    }
+
+    projects.into_values().collect()
+}
+
+/// Validates a compose project name
+/// Compose only allows lowercase letters, digits, dashes and underscores
+pub(super) fn validate_project_name(name: &str) -> Result<(), (StatusCode, String)> {
+    let valid = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+
+    if !valid {
+        return Err((StatusCode::BAD_REQUEST, "Invalid project name".into()));
+    }
+
+    Ok(())
+}
+
+/// Build `docker compose` arguments for a project with a known compose file
+pub(super) async fn compose_args(
+    project: &ProjectInfo,
+    command: &[&str],
+) -> Result<Vec<String>, (StatusCode, String)> {
+    if project.config_files.is_empty() {
+        return Err((
+            StatusCode::CONFLICT,
+            format!("Compose file for project {} is not known", project.name),
+        ));
+    }
+
+    let mut args = vec![
+        "compose".to_string(),
+        "-p".to_string(),
+        project.name.clone(),
+    ];
+
+    if let Some(dir) = &project.working_dir {
+        args.push("--project-directory".to_string());
+        args.push(dir.clone());
+    }
+
+    for file in &project.config_files {
+        if !tokio::fs::try_exists(file).await.unwrap_or(false) {
+            return Err((
+                StatusCode::CONFLICT,
+                format!("Compose file not found on host: {}", file),
+            ));
+        }
+        args.push("-f".to_string());
+        args.push(file.clone());
+    }
+
+    args.extend(command.iter().map(|s| s.to_string()));
+    Ok(args)
+}
@@ -1,20 +1,33 @@
 use super::super::types::ContainerDetailsResponse;
+use super::access::find_container;
 use super::parser;
-use axum::{Json, extract::Path, http::StatusCode};
+use crate::config::SharedConfig;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
 use serde_json::Value;
 use tokio::process::Command;
 
 /// GET /api/containers/:id/details - Get detailed information about a container
 pub async fn get_container_details(
+    State(config): State<SharedConfig>,
     Path(id): Path<String>,
 ) -> Result<Json<ContainerDetailsResponse>, (StatusCode, String)> {
-    let inspect_output = fetch_container_inspect(&id).await?;
-    let container = parse_inspect_json(&inspect_output)?;
-    let details = parser::build_details(&container)?;
+    let listed = find_container(config.container_access(), &id).await?;
+    let container = inspect_container(&listed.info.id).await?;
+    let details = parser::build_details(&container, listed.info.status)?;
 
     Ok(Json(ContainerDetailsResponse { details }))
 }
 
+/// `docker inspect` a single container
+pub(super) async fn inspect_container(id: &str) -> Result<Value, (StatusCode, String)> {
+    let inspect_output = fetch_container_inspect(id).await?;
+    parse_inspect_json(&inspect_output)
+}
+
 async fn fetch_container_inspect(id: &str) -> Result<String, (StatusCode, String)> {
     let output = Command::new("docker")
         .args(["inspect", id])
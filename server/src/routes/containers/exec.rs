@@ -0,0 +1,163 @@
+use super::access::authorize;
+use crate::config::SharedConfig;
+use axum::{
+    extract::{
+        Path, Query, State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    http::{HeaderMap, StatusCode, header},
+    response::Response,
+};
+use serde::Deserialize;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+#[derive(Deserialize)]
+pub struct ExecParams {
+    #[serde(default = "default_cols")]
+    pub cols: u16,
+    #[serde(default = "default_rows")]
+    pub rows: u16,
+}
+
+fn default_cols() -> u16 {
+    80
+}
+
+fn default_rows() -> u16 {
+    24
+}
+
+/// Control messages sent by the client as text frames
+/// (binary frames are terminal input)
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ControlMessage {
+    Resize { cols: u16, rows: u16 },
+}
+
+/// GET /api/containers/:id/exec - Interactive shell over a WebSocket
+///
+/// Runs `docker exec -it` on a local PTY. Output is sent as binary frames;
+/// the socket is closed with reason `exit: <code>` when the shell exits.
+/// Only pages served by sysrat itself may connect.
+pub async fn exec_container(
+    State(config): State<SharedConfig>,
+    Path(id): Path<String>,
+    Query(params): Query<ExecParams>,
+    headers: HeaderMap,
+    ws: WebSocketUpgrade,
+) -> Result<Response, (StatusCode, String)> {
+    ensure_same_origin(&headers)?;
+
+    let settings = config.exec_settings();
+    if !settings.enabled {
+        return Err((
+            StatusCode::FORBIDDEN,
+            "Exec is disabled in sysrat.toml".to_string(),
+        ));
+    }
+
+    let container_id = authorize(config.container_access(), &id, "exec").await?;
+    let command = settings.command.clone();
+
+    Ok(ws.on_upgrade(move |socket| run_session(socket, container_id, command, params)))
+}
+
+/// Reject cross-site WebSocket upgrades: browsers send the `Origin` of the
+/// page opening the socket, which must be this server
+fn ensure_same_origin(headers: &HeaderMap) -> Result<(), (StatusCode, String)> {
+    let header = |name| headers.get(name).and_then(|value| value.to_str().ok());
+    let origin_host = header(header::ORIGIN)
+        .and_then(|origin| origin.split_once("://"))
+        .map(|(_, host)| host);
+
+    match (origin_host, header(header::HOST)) {
+        (Some(origin), Some(host)) if origin.eq_ignore_ascii_case(host) => Ok(()),
+        _ => Err((
+            StatusCode::FORBIDDEN,
+            "Exec is only available from the sysrat page".to_string(),
+        )),
+    }
+}
+
+async fn run_session(
+    mut socket: WebSocket,
+    container_id: String,
+    command: String,
+    size: ExecParams,
+) {
+    let reason = match pump(&mut socket, &container_id, &command, size).await {
+        Ok(code) => format!("exit: {}", code),
+        Err(e) => format!("error: {}", e),
+    };
+    let _ = socket
+        .send(Message::Close(Some(axum::extract::ws::CloseFrame {
+            code: axum::extract::ws::close_code::NORMAL,
+            reason: reason.into(),
+        })))
+        .await;
+}
+
+/// Forward data between the socket and the PTY until either side ends
+/// Returns the exit code of the exec process
+async fn pump(
+    socket: &mut WebSocket,
+    container_id: &str,
+    command: &str,
+    size: ExecParams,
+) -> Result<i32, String> {
+    let (mut pty, pts) = pty_process::open().map_err(|e| e.to_string())?;
+    pty.resize(pty_process::Size::new(size.rows, size.cols))
+        .map_err(|e| e.to_string())?;
+
+    let mut child = pty_process::Command::new("docker")
+        .args(["exec", "-it", container_id])
+        .args(command.split_whitespace())
+        .kill_on_drop(true)
+        .spawn(pts)
+        .map_err(|e| format!("docker exec failed: {}", e))?;
+
+    let mut buf = [0u8; 4096];
+    loop {
+        tokio::select! {
+            status = child.wait() => {
+                // Flush output written right before the exit
+                while let Ok(Ok(n)) =
+                    tokio::time::timeout(std::time::Duration::from_millis(50), pty.read(&mut buf)).await
+                {
+                    if n == 0 || socket.send(Message::Binary(buf[..n].to_vec().into())).await.is_err() {
+                        break;
+                    }
+                }
+                let status = status.map_err(|e| e.to_string())?;
+                return Ok(status.code().unwrap_or(-1));
+            }
+            read = pty.read(&mut buf) => {
+                // EIO once the process side of the PTY is closed
+                let n = read.unwrap_or(0);
+                if n == 0 {
+                    let status = child.wait().await.map_err(|e| e.to_string())?;
+                    return Ok(status.code().unwrap_or(-1));
+                }
+                if socket.send(Message::Binary(buf[..n].to_vec().into())).await.is_err() {
+                    return Ok(-1);
+                }
+            }
+            msg = socket.recv() => {
+                match msg {
+                    Some(Ok(Message::Binary(data))) => {
+                        pty.write_all(&data).await.map_err(|e| e.to_string())?;
+                    }
+                    Some(Ok(Message::Text(text))) => {
+                        if let Ok(ControlMessage::Resize { cols, rows }) = serde_json::from_str(&text) {
+                            let _ = pty.resize(pty_process::Size::new(rows, cols));
+                        }
+                    }
+                    // Client went away: kill_on_drop ends the exec
+                    Some(Ok(Message::Close(_))) | None | Some(Err(_)) => return Ok(-1),
+                    Some(Ok(_)) => {}
+                }
+            }
+        }
+    }
+}
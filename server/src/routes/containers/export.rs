@@ -0,0 +1,375 @@
+use super::super::docker::run_docker;
+use super::super::types::ComposeSnippetResponse;
+use super::access::find_container;
+use super::details::inspect_container;
+use crate::config::SharedConfig;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Networks that exist on every host and need no declaration
+const BUILTIN_NETWORKS: &[&str] = &["bridge", "host", "none"];
+
+/// Labels set by compose itself
+const COMPOSE_LABEL_PREFIX: &str = "com.docker.compose.";
+
+const IMAGE_INSPECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// GET /api/containers/:id/compose - Compose service definition equivalent
+/// to how the container was started
+pub async fn export_compose(
+    State(config): State<SharedConfig>,
+    Path(id): Path<String>,
+) -> Result<Json<ComposeSnippetResponse>, (StatusCode, String)> {
+    let listed = find_container(config.container_access(), &id).await?;
+    let container = inspect_container(&listed.info.id).await?;
+
+    // Settings the image provides anyway are left out; without the image
+    // everything is kept
+    let image_id = container
+        .get("Image")
+        .and_then(|i| i.as_str())
+        .unwrap_or("");
+    let image_config = inspect_image_config(image_id).await.unwrap_or(Value::Null);
+
+    let service = service_name(&listed.info.name);
+    let content = build_snippet(&container, &image_config, &service);
+    let suggested_name = config
+        .new_file_name(&format!("{}.compose", service), &["yml", "yaml"])
+        .await;
+
+    Ok(Json(ComposeSnippetResponse {
+        service,
+        content,
+        suggested_name,
+    }))
+}
+
+async fn inspect_image_config(image_id: &str) -> Option<Value> {
+    if image_id.is_empty() {
+        return None;
+    }
+    let stdout = run_docker(
+        &["image", "inspect", image_id],
+        "image inspect",
+        IMAGE_INSPECT_TIMEOUT,
+    )
+    .await
+    .ok()?;
+    let images: Vec<Value> = serde_json::from_str(&stdout).ok()?;
+    images.into_iter().next()?.get("Config").cloned()
+}
+
+/// Compose service names are lowercase letters, digits, `-` and `_`
+fn service_name(container_name: &str) -> String {
+    let name: String = container_name
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    if name.is_empty() {
+        "service".to_string()
+    } else {
+        name
+    }
+}
+
+fn build_snippet(container: &Value, image_config: &Value, service: &str) -> String {
+    let config = container.get("Config").unwrap_or(&Value::Null);
+    let host_config = container.get("HostConfig").unwrap_or(&Value::Null);
+    let mut yaml = Yaml::default();
+
+    yaml.line(0, "services:");
+    yaml.line(1, &format!("{}:", service));
+    yaml.field(2, "image", str_at(config, "Image"));
+    let name = str_at(container, "Name").trim_start_matches('/');
+    yaml.field(2, "container_name", name);
+
+    for key in ["Entrypoint", "Cmd"] {
+        let value = list_at(config, key);
+        if !value.is_empty() && value != list_at(image_config, key) {
+            let compose_key = if key == "Cmd" {
+                "command"
+            } else {
+                "entrypoint"
+            };
+            yaml.list_inline(2, compose_key, &value);
+        }
+    }
+    for (key, compose_key) in [("WorkingDir", "working_dir"), ("User", "user")] {
+        let value = str_at(config, key);
+        if !value.is_empty() && value != str_at(image_config, key) {
+            yaml.field(2, compose_key, value);
+        }
+    }
+
+    let restart = restart_policy(host_config);
+    if restart != "no" {
+        yaml.field(2, "restart", &restart);
+    }
+
+    let image_env = list_at(image_config, "Env");
+    let environment: BTreeMap<String, String> = list_at(config, "Env")
+        .into_iter()
+        .filter(|entry| !image_env.contains(entry))
+        .filter_map(|entry| {
+            let (key, value) = entry.split_once('=')?;
+            Some((key.to_string(), value.to_string()))
+        })
+        .collect();
+    yaml.map(2, "environment", &environment);
+
+    yaml.list(2, "ports", &ports(host_config));
+
+    let (volumes, named_volumes) = mounts(container);
+    yaml.list(2, "volumes", &volumes);
+
+    // host/none/container:<id> replace networking altogether
+    let network_mode = str_at(host_config, "NetworkMode");
+    let networks =
+        if matches!(network_mode, "host" | "none") || network_mode.starts_with("container:") {
+            yaml.field(2, "network_mode", network_mode);
+            Vec::new()
+        } else {
+            networks(container)
+        };
+    yaml.list(2, "networks", &networks);
+
+    let memory = u64_at(host_config, "Memory");
+    if memory > 0 {
+        yaml.field(2, "mem_limit", &memory.to_string());
+    }
+    let nano_cpus = u64_at(host_config, "NanoCpus");
+    if nano_cpus > 0 {
+        yaml.field(2, "cpus", &format!("{}", nano_cpus as f64 / 1e9));
+    }
+
+    let image_labels = map_at(image_config, "Labels");
+    let labels: BTreeMap<String, String> = map_at(config, "Labels")
+        .into_iter()
+        .filter(|(key, value)| {
+            !key.starts_with(COMPOSE_LABEL_PREFIX) && image_labels.get(key) != Some(value)
+        })
+        .collect();
+    yaml.map(2, "labels", &labels);
+
+    // Volumes and networks that already exist are referenced as external
+    yaml.external("volumes", &named_volumes);
+    yaml.external("networks", &networks);
+
+    yaml.finish()
+}
+
+fn restart_policy(host_config: &Value) -> String {
+    let policy = host_config.get("RestartPolicy").unwrap_or(&Value::Null);
+    let name = str_at(policy, "Name");
+    let retries = u64_at(policy, "MaximumRetryCount");
+    match name {
+        "" => "no".to_string(),
+        "on-failure" if retries > 0 => format!("on-failure:{}", retries),
+        name => name.to_string(),
+    }
+}
+
+/// Published ports as `[ip:]host:container/proto`
+fn ports(host_config: &Value) -> Vec<String> {
+    let Some(bindings) = host_config.get("PortBindings").and_then(|p| p.as_object()) else {
+        return Vec::new();
+    };
+
+    let mut ports = Vec::new();
+    for (container_port, hosts) in bindings {
+        let (port, proto) = container_port
+            .split_once('/')
+            .unwrap_or((container_port.as_str(), "tcp"));
+        let suffix = if proto == "tcp" {
+            String::new()
+        } else {
+            format!("/{}", proto)
+        };
+
+        for host in hosts.as_array().into_iter().flatten() {
+            let host_port = str_at(host, "HostPort");
+            let host_ip = str_at(host, "HostIp");
+            let entry = match (host_ip, host_port) {
+                (_, "") => format!("{}{}", port, suffix),
+                ("" | "0.0.0.0", hp) => format!("{}:{}{}", hp, port, suffix),
+                (ip, hp) if ip.contains(':') => format!("[{}]:{}:{}{}", ip, hp, port, suffix),
+                (ip, hp) => format!("{}:{}:{}{}", ip, hp, port, suffix),
+            };
+            ports.push(entry);
+        }
+    }
+    ports.sort();
+    ports
+}
+
+/// Bind mounts and named volumes as `source:target[:ro]`, plus the named
+/// volume names; anonymous volumes are recreated from the image
+fn mounts(container: &Value) -> (Vec<String>, Vec<String>) {
+    let mut entries = Vec::new();
+    let mut named = Vec::new();
+
+    for mount in container
+        .get("Mounts")
+        .and_then(|m| m.as_array())
+        .into_iter()
+        .flatten()
+    {
+        let source = match str_at(mount, "Type") {
+            "bind" => str_at(mount, "Source"),
+            "volume" => {
+                let name = str_at(mount, "Name");
+                if is_anonymous_volume(name) {
+                    continue;
+                }
+                if !named.iter().any(|n| n == name) {
+                    named.push(name.to_string());
+                }
+                name
+            }
+            _ => continue,
+        };
+
+        let mut entry = format!("{}:{}", source, str_at(mount, "Destination"));
+        if mount.get("RW").and_then(|rw| rw.as_bool()) == Some(false) {
+            entry.push_str(":ro");
+        }
+        entries.push(entry);
+    }
+
+    (entries, named)
+}
+
+/// Anonymous volumes get a random 64 character hex name
+fn is_anonymous_volume(name: &str) -> bool {
+    name.len() == 64 && name.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// User-defined networks the container is attached to
+fn networks(container: &Value) -> Vec<String> {
+    container
+        .get("NetworkSettings")
+        .and_then(|n| n.get("Networks"))
+        .and_then(|n| n.as_object())
+        .map(|networks| {
+            networks
+                .keys()
+                .filter(|name| !BUILTIN_NETWORKS.contains(&name.as_str()))
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn str_at<'a>(value: &'a Value, key: &str) -> &'a str {
+    value.get(key).and_then(|v| v.as_str()).unwrap_or("")
+}
+
+fn u64_at(value: &Value, key: &str) -> u64 {
+    value.get(key).and_then(|v| v.as_u64()).unwrap_or(0)
+}
+
+fn list_at(value: &Value, key: &str) -> Vec<String> {
+    value
+        .get(key)
+        .and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.as_str())
+                .map(|item| item.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn map_at(value: &Value, key: &str) -> BTreeMap<String, String> {
+    value
+        .get(key)
+        .and_then(|v| v.as_object())
+        .map(|map| {
+            map.iter()
+                .filter_map(|(k, v)| Some((k.clone(), v.as_str()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Minimal YAML writer; every scalar is double quoted (JSON string syntax is
+/// valid YAML), so values never need YAML escaping rules of their own
+#[derive(Default)]
+struct Yaml {
+    out: String,
+}
+
+impl Yaml {
+    fn line(&mut self, indent: usize, text: &str) {
+        self.out.push_str(&"  ".repeat(indent));
+        self.out.push_str(text);
+        self.out.push('\n');
+    }
+
+    fn field(&mut self, indent: usize, key: &str, value: &str) {
+        self.line(indent, &format!("{}: {}", key, quote(value)));
+    }
+
+    fn list_inline(&mut self, indent: usize, key: &str, items: &[String]) {
+        let items: Vec<String> = items.iter().map(|item| quote(item)).collect();
+        self.line(indent, &format!("{}: [{}]", key, items.join(", ")));
+    }
+
+    fn list(&mut self, indent: usize, key: &str, items: &[String]) {
+        if items.is_empty() {
+            return;
+        }
+        self.line(indent, &format!("{}:", key));
+        for item in items {
+            self.line(indent + 1, &format!("- {}", quote(item)));
+        }
+    }
+
+    fn map(&mut self, indent: usize, key: &str, map: &BTreeMap<String, String>) {
+        if map.is_empty() {
+            return;
+        }
+        self.line(indent, &format!("{}:", key));
+        for (k, v) in map {
+            self.line(indent + 1, &format!("{}: {}", quote(k), quote(v)));
+        }
+    }
+
+    /// Top-level section declaring existing objects as external
+    fn external(&mut self, key: &str, names: &[String]) {
+        if names.is_empty() {
+            return;
+        }
+        self.line(0, "");
+        self.line(0, &format!("{}:", key));
+        for name in names {
+            self.line(1, &format!("{}:", quote(name)));
+            self.line(2, "external: true");
+        }
+    }
+
+    fn finish(self) -> String {
+        self.out
+    }
+}
+
+/// Double quoted scalar; `$` is doubled so compose takes it literally instead
+/// of interpolating a variable
+fn quote(value: &str) -> String {
+    serde_json::to_string(&value.replace('$', "$$")).unwrap_or_else(|_| "\"\"".to_string())
+}
@@ -0,0 +1,305 @@
+use super::super::docker::run_docker_bytes;
+use super::super::types::{ContainerDirEntry, ContainerDirResponse};
+use super::access::{ensure_action, find_container};
+use super::archive::{self, ArchiveEntry};
+use super::listing::{ListedContainer, fetch_containers};
+use crate::audit;
+use crate::config::SharedConfig;
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use serde::Deserialize;
+use std::path::Component;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::AsyncReadExt;
+use tokio::process::Command;
+
+/// Prefix for file names inside containers ("container:web/etc/app.conf")
+pub const CONTAINER_FILE_PREFIX: &str = "container:";
+
+/// Largest file that is opened in the editor
+const MAX_FILE_SIZE: usize = 1024 * 1024;
+
+/// Room for the tar headers around the file data
+const ARCHIVE_OVERHEAD: usize = 16 * 1024;
+
+const FILES_TIMEOUT: Duration = Duration::from_secs(15);
+
+#[derive(Deserialize)]
+pub struct DirParams {
+    #[serde(default = "default_dir")]
+    pub path: String,
+}
+
+fn default_dir() -> String {
+    "/".to_string()
+}
+
+/// GET /api/containers/:id/files?path=/etc - List a directory inside a
+/// running container (directories first)
+pub async fn list_container_files(
+    State(config): State<SharedConfig>,
+    Path(id): Path<String>,
+    Query(params): Query<DirParams>,
+) -> Result<Json<ContainerDirResponse>, (StatusCode, String)> {
+    ensure_enabled(&config)?;
+    validate_path(&params.path)?;
+    let container = find_container(config.container_access(), &id).await?;
+    ensure_action(config.container_access(), &container, "files")?;
+    if container.info.state != "running" {
+        return Err((
+            StatusCode::CONFLICT,
+            format!("Container {} is not running", container.info.name),
+        ));
+    }
+
+    // -p marks directories with a trailing slash
+    let stdout = run_docker_bytes(
+        &["exec", &container.info.id, "ls", "-1Ap", "--", &params.path],
+        None,
+        "exec ls",
+        FILES_TIMEOUT,
+    )
+    .await?;
+
+    let mut entries: Vec<ContainerDirEntry> = String::from_utf8_lossy(&stdout)
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| match line.strip_suffix('/') {
+            Some(name) => ContainerDirEntry {
+                name: name.to_string(),
+                dir: true,
+            },
+            None => ContainerDirEntry {
+                name: line.to_string(),
+                dir: false,
+            },
+        })
+        .collect();
+    entries.sort_by(|a, b| b.dir.cmp(&a.dir).then_with(|| a.name.cmp(&b.name)));
+
+    Ok(Json(ContainerDirResponse {
+        path: params.path,
+        entries,
+    }))
+}
+
+/// Running containers whose files may be browsed (empty if disabled)
+pub async fn browsable_containers(config: &SharedConfig) -> Vec<String> {
+    if !config.container_files_settings().enabled {
+        return Vec::new();
+    }
+    let access = config.container_access();
+    let mut names: Vec<String> = fetch_containers(access)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|c| c.info.state == "running" && access.allows_action(&c.identity(), "files"))
+        .map(|c| c.info.name)
+        .collect();
+    names.sort();
+    names
+}
+
+/// Read a text file inside a container (`container:<name>/<path>`)
+pub async fn read_container_file(
+    config: &SharedConfig,
+    filename: &str,
+) -> Result<String, (StatusCode, String)> {
+    let (container, path) = resolve(config, filename).await?;
+    let entry = copy_out(&container.info.id, &path).await?;
+
+    String::from_utf8(entry.data).map_err(|_| {
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("Not a text file: {}", path),
+        )
+    })
+}
+
+/// Write a text file inside a container, keeping the previous content as
+/// `<file>.backup` next to it
+///
+/// Only existing files can be written; ownership and mode are kept.
+pub async fn write_container_file(
+    config: &SharedConfig,
+    filename: &str,
+    content: &str,
+) -> Result<(), (StatusCode, String)> {
+    let (container, path) = resolve(config, filename).await?;
+    let existing = copy_out(&container.info.id, &path).await?;
+
+    let (dir, name) = path.rsplit_once('/').unwrap_or(("", &path));
+    let dir = if dir.is_empty() { "/" } else { dir };
+    let backup_name = format!("{}.backup", name);
+    let tar = archive::build(
+        &[(&backup_name, &existing.data), (name, content.as_bytes())],
+        existing.meta,
+    )
+    .map_err(|e| (StatusCode::UNPROCESSABLE_ENTITY, e))?;
+
+    // `--archive` keeps the uid/gid of the headers (docker cp writes as root otherwise)
+    let destination = format!("{}:{}", container.info.id, dir);
+    let result = run_docker_bytes(
+        &["cp", "--archive", "-", &destination],
+        Some(&tar),
+        "cp",
+        FILES_TIMEOUT,
+    )
+    .await;
+    audit::record(
+        config.audit_settings(),
+        "write",
+        filename,
+        &format!("{} bytes", content.len()),
+        result.is_ok(),
+    );
+    result.map(|_| ())
+}
+
+fn ensure_enabled(config: &SharedConfig) -> Result<(), (StatusCode, String)> {
+    if !config.container_files_settings().enabled {
+        return Err((
+            StatusCode::FORBIDDEN,
+            "Container files are disabled in sysrat.toml".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Split a `container:<name>/<path>` file name and check access to it
+async fn resolve(
+    config: &SharedConfig,
+    filename: &str,
+) -> Result<(ListedContainer, String), (StatusCode, String)> {
+    ensure_enabled(config)?;
+    let invalid = || (StatusCode::BAD_REQUEST, "Invalid filename".to_string());
+
+    let rest = filename
+        .strip_prefix(CONTAINER_FILE_PREFIX)
+        .ok_or_else(invalid)?;
+    let (name, path) = rest.split_once('/').ok_or_else(invalid)?;
+    let path = format!("/{}", path);
+    validate_path(&path)?;
+    if path.ends_with('/') {
+        return Err(invalid());
+    }
+
+    let container = find_container(config.container_access(), name).await?;
+    ensure_action(config.container_access(), &container, "files")?;
+    Ok((container, path))
+}
+
+/// Absolute path without `.`/`..` components
+fn validate_path(path: &str) -> Result<(), (StatusCode, String)> {
+    let path_ref = std::path::Path::new(path);
+    let clean = path_ref.is_absolute()
+        && !path.contains('\0')
+        && path_ref
+            .components()
+            .all(|c| matches!(c, Component::RootDir | Component::Normal(_)));
+    if !clean {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Invalid container path: {}", path),
+        ));
+    }
+    Ok(())
+}
+
+/// Copy a single regular file out of a container (`docker cp <id>:<path> -`)
+///
+/// The archive is read up to a size limit so that directories or huge files
+/// are never buffered completely.
+async fn copy_out(container_id: &str, path: &str) -> Result<ArchiveEntry, (StatusCode, String)> {
+    let source = format!("{}:{}", container_id, path);
+    let failed = |e: std::io::Error| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("docker cp failed: {}", e),
+        )
+    };
+    let too_large = || {
+        (
+            StatusCode::PAYLOAD_TOO_LARGE,
+            format!(
+                "Too large to edit (max {} KiB): {}",
+                MAX_FILE_SIZE / 1024,
+                path
+            ),
+        )
+    };
+
+    let mut child = Command::new("docker")
+        .args(["cp", &source, "-"])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(failed)?;
+
+    let limit = MAX_FILE_SIZE + ARCHIVE_OVERHEAD;
+    let mut stdout = child.stdout.take().expect("stdout is piped");
+    let mut tar = Vec::new();
+    let mut limited = (&mut stdout).take(limit as u64 + 1);
+    tokio::time::timeout(FILES_TIMEOUT, limited.read_to_end(&mut tar))
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::REQUEST_TIMEOUT,
+                "docker cp timed out".to_string(),
+            )
+        })?
+        .map_err(failed)?;
+    if tar.len() > limit {
+        // kill_on_drop stops the copy
+        return Err(too_large());
+    }
+    drop(stdout);
+
+    let output = tokio::time::timeout(FILES_TIMEOUT, child.wait_with_output())
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::REQUEST_TIMEOUT,
+                "docker cp timed out".to_string(),
+            )
+        })?
+        .map_err(failed)?;
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        let status = if error.contains("No such container:path") {
+            StatusCode::NOT_FOUND
+        } else {
+            StatusCode::INTERNAL_SERVER_ERROR
+        };
+        return Err((status, format!("docker cp failed: {}", error.trim())));
+    }
+
+    let entry = archive::first_entry(&tar).ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Unexpected docker cp output for {}", path),
+        )
+    })?;
+    match entry.kind {
+        b'0' | b'7' if entry.data.len() > MAX_FILE_SIZE => Err(too_large()),
+        b'0' | b'7' => Ok(entry),
+        b'2' => Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("{} is a symlink to {}", path, entry.link),
+        )),
+        b'5' => Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("{} is a directory", path),
+        )),
+        _ => Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("{} is not a regular file", path),
+        )),
+    }
+}
@@ -1,69 +1,52 @@
-use super::super::types::{ContainerActionResponse, ContainerInfo, ContainerListResponse};
-use super::actions::execute_container_action;
-use axum::{Json, extract::Path, http::StatusCode};
-use tokio::process::Command;
-
-/// GET /api/containers - List all Docker containers
-pub async fn list_containers() -> Result<Json<ContainerListResponse>, (StatusCode, String)> {
-    let output = Command::new("docker")
-        .args([
-            "ps",
-            "-a",
-            "--format",
-            "{{.ID}}\t{{.Names}}\t{{.State}}\t{{.Status}}",
-        ])
-        .output()
-        .await
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to execute docker command: {}", e),
-            )
-        })?;
-
-    if !output.status.success() {
-        let error = String::from_utf8_lossy(&output.stderr);
-        return Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Docker command failed: {}", error),
-        ));
-    }
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let mut containers = Vec::new();
-
-    for line in stdout.lines() {
-        let parts: Vec<&str> = line.split('\t').collect();
-        if parts.len() >= 4 {
-            containers.push(ContainerInfo {
-                id: parts[0].to_string(),
-                name: parts[1].to_string(),
-                state: parts[2].to_string(),
-                status: parts[3].to_string(),
-            });
-        }
-    }
-
-    Ok(Json(ContainerListResponse { containers }))
+use super::super::types::{ContainerListResponse, JobResponse};
+use super::actions::simple_container_job;
+use super::compose::group_projects;
+use super::listing::fetch_containers;
+use crate::config::SharedConfig;
+use crate::jobs::SharedJobs;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+/// GET /api/containers - List all Docker containers (grouped by compose project)
+pub async fn list_containers(
+    State(config): State<SharedConfig>,
+) -> Result<Json<ContainerListResponse>, (StatusCode, String)> {
+    let listed = fetch_containers(config.container_access()).await?;
+    let projects = group_projects(&listed);
+    let containers = listed.into_iter().map(|l| l.info).collect();
+
+    Ok(Json(ContainerListResponse {
+        containers,
+        projects,
+    }))
 }
 
-/// POST /api/containers/:id/start - Start a container
+/// POST /api/containers/:id/start - Start a container (as a job)
 pub async fn start_container(
+    State(config): State<SharedConfig>,
+    State(jobs): State<SharedJobs>,
     Path(id): Path<String>,
-) -> Result<Json<ContainerActionResponse>, (StatusCode, String)> {
-    execute_container_action(&id, "start").await
+) -> Result<Json<JobResponse>, (StatusCode, String)> {
+    simple_container_job(&config, &jobs, &id, "start").await
 }
 
-/// POST /api/containers/:id/stop - Stop a container
+/// POST /api/containers/:id/stop - Stop a container (as a job)
 pub async fn stop_container(
+    State(config): State<SharedConfig>,
+    State(jobs): State<SharedJobs>,
     Path(id): Path<String>,
-) -> Result<Json<ContainerActionResponse>, (StatusCode, String)> {
-    execute_container_action(&id, "stop").await
+) -> Result<Json<JobResponse>, (StatusCode, String)> {
+    simple_container_job(&config, &jobs, &id, "stop").await
 }
 
-/// POST /api/containers/:id/restart - Restart a container
+/// POST /api/containers/:id/restart - Restart a container (as a job)
 pub async fn restart_container(
+    State(config): State<SharedConfig>,
+    State(jobs): State<SharedJobs>,
     Path(id): Path<String>,
-) -> Result<Json<ContainerActionResponse>, (StatusCode, String)> {
-    execute_container_action(&id, "restart").await
+) -> Result<Json<JobResponse>, (StatusCode, String)> {
+    simple_container_job(&config, &jobs, &id, "restart").await
 }
@@ -0,0 +1,159 @@
+use super::listing::{ListedContainer, fetch_all_containers, fetch_containers};
+use super::parser;
+use crate::config::ContainerAccess;
+use axum::http::StatusCode;
+use serde_json::Value;
+use tokio::process::Command;
+
+/// `docker inspect` all visible containers at once
+pub(super) async fn inspect_containers(
+    access: &ContainerAccess,
+) -> Result<Vec<Value>, (StatusCode, String)> {
+    let ids: Vec<String> = fetch_containers(access)
+        .await?
+        .into_iter()
+        .map(|c| c.info.id)
+        .collect();
+    inspect(&ids).await
+}
+
+async fn inspect(ids: &[String]) -> Result<Vec<Value>, (StatusCode, String)> {
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let output = Command::new("docker")
+        .arg("inspect")
+        .args(ids)
+        .output()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to execute docker inspect: {}", e),
+            )
+        })?;
+
+    // Containers may disappear between `ps` and `inspect`, so a failed
+    // status still yields the containers that could be inspected
+    Ok(serde_json::from_slice(&output.stdout).unwrap_or_default())
+}
+
+/// Docker objects referenced by a container
+pub struct ContainerUsage {
+    pub name: String,
+    pub image_id: String,
+    /// Names of the named volumes it mounts
+    pub volumes: Vec<String>,
+    /// Names of the networks it is attached to
+    pub networks: Vec<String>,
+    /// Hidden by the access rules; its name is never shown
+    pub hidden: bool,
+    container: ListedContainer,
+}
+
+impl ContainerUsage {
+    /// Whether the access rules permit `action` on the container
+    pub fn allows(&self, access: &ContainerAccess, action: &str) -> bool {
+        access.allows_action(&self.container.identity(), action)
+    }
+}
+
+/// Image, volumes and networks used by each container
+///
+/// Hidden containers are included so that objects only they use don't look
+/// unused; list users with `user_names` to keep their names out.
+pub async fn container_usage(
+    access: &ContainerAccess,
+) -> Result<Vec<ContainerUsage>, (StatusCode, String)> {
+    let containers = fetch_all_containers(access).await?;
+    let ids: Vec<String> = containers.iter().map(|c| c.info.id.clone()).collect();
+    let inspected = inspect(&ids).await?;
+
+    Ok(containers
+        .into_iter()
+        .filter_map(|container| {
+            let c = inspected
+                .iter()
+                .find(|c| parser::extract_short_id(c) == container.info.id)?;
+            Some(ContainerUsage {
+                name: parser::extract_container_name(c),
+                image_id: parser::extract_image_id(c),
+                volumes: parser::extract_volume_names(c),
+                networks: parser::extract_network_names(c),
+                hidden: !access.is_visible(&container.identity()),
+                container,
+            })
+        })
+        .collect())
+}
+
+/// Names of the visible users, plus a count of the hidden ones
+pub fn user_names<'a>(users: impl IntoIterator<Item = &'a ContainerUsage>) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut hidden = 0;
+    for user in users {
+        if user.hidden {
+            hidden += 1;
+        } else {
+            names.push(user.name.clone());
+        }
+    }
+    if hidden > 0 {
+        names.push(format!("{} hidden", hidden));
+    }
+    names
+}
+
+/// Reject an action on a docker object unless the access rules permit it on
+/// every container using the object
+pub fn ensure_users_allow<'a>(
+    access: &ContainerAccess,
+    users: impl IntoIterator<Item = &'a ContainerUsage>,
+    action: &str,
+    object: &str,
+) -> Result<(), (StatusCode, String)> {
+    for user in users {
+        if !user.allows(access, action) {
+            let name = if user.hidden {
+                "a hidden container"
+            } else {
+                &user.name
+            };
+            return Err((
+                StatusCode::FORBIDDEN,
+                format!(
+                    "Action {} not permitted on {} (used by {})",
+                    action, object, name
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Reject an action on the whole daemon (pruning) unless the access rules
+/// permit it on every container, hidden ones included
+pub async fn ensure_allowed_on_all(
+    access: &ContainerAccess,
+    action: &str,
+) -> Result<(), (StatusCode, String)> {
+    for container in fetch_all_containers(access).await? {
+        let identity = container.identity();
+        if !access.allows_action(&identity, action) {
+            let name = if access.is_visible(&identity) {
+                identity.name
+            } else {
+                "hidden containers"
+            };
+            return Err((
+                StatusCode::FORBIDDEN,
+                format!(
+                    "Action {} not permitted: the access rules restrict {}",
+                    action, name
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,187 @@
+use super::super::docker::run_docker_logged;
+use super::super::types::JobResponse;
+use super::access::{ensure_action, find_container};
+use super::actions::{ACTION_TIMEOUT, container_job, past_tense, simple_container_job};
+use super::compose::{compose_args, group_projects};
+use super::listing::fetch_containers;
+use super::projects::COMPOSE_TIMEOUT;
+use crate::config::SharedConfig;
+use crate::jobs::{JobTarget, SharedJobs};
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct KillParams {
+    /// Signal name or number (default: SIGKILL)
+    #[serde(default)]
+    pub signal: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct RemoveParams {
+    /// Also remove anonymous volumes
+    #[serde(default)]
+    pub volumes: bool,
+    /// Remove even if running
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// POST /api/containers/:id/pause - Pause all processes of a container
+pub async fn pause_container(
+    State(config): State<SharedConfig>,
+    State(jobs): State<SharedJobs>,
+    Path(id): Path<String>,
+) -> Result<Json<JobResponse>, (StatusCode, String)> {
+    simple_container_job(&config, &jobs, &id, "pause").await
+}
+
+/// POST /api/containers/:id/unpause - Resume a paused container
+pub async fn unpause_container(
+    State(config): State<SharedConfig>,
+    State(jobs): State<SharedJobs>,
+    Path(id): Path<String>,
+) -> Result<Json<JobResponse>, (StatusCode, String)> {
+    simple_container_job(&config, &jobs, &id, "unpause").await
+}
+
+/// POST /api/containers/:id/kill?signal=SIGHUP - Send a signal to a container
+pub async fn kill_container(
+    State(config): State<SharedConfig>,
+    State(jobs): State<SharedJobs>,
+    Path(id): Path<String>,
+    Query(params): Query<KillParams>,
+) -> Result<Json<JobResponse>, (StatusCode, String)> {
+    let signal = params.signal.unwrap_or_else(|| "SIGKILL".to_string());
+    validate_signal(&signal)?;
+
+    container_job(
+        &config,
+        &jobs,
+        &id,
+        "kill",
+        |job, container_id| async move {
+            run_docker_logged(
+                &job,
+                &["kill", "--signal", &signal, &container_id],
+                "kill",
+                ACTION_TIMEOUT,
+            )
+            .await?;
+            Ok(format!("sent {}", signal))
+        },
+    )
+    .await
+}
+
+/// POST /api/containers/:id/remove?volumes=true&force=true - Remove a container
+pub async fn remove_container(
+    State(config): State<SharedConfig>,
+    State(jobs): State<SharedJobs>,
+    Path(id): Path<String>,
+    Query(params): Query<RemoveParams>,
+) -> Result<Json<JobResponse>, (StatusCode, String)> {
+    container_job(
+        &config,
+        &jobs,
+        &id,
+        "remove",
+        |job, container_id| async move {
+            let mut args = vec!["rm"];
+            if params.volumes {
+                args.push("--volumes");
+            }
+            if params.force {
+                args.push("--force");
+            }
+            args.push(&container_id);
+            run_docker_logged(&job, &args, "rm", ACTION_TIMEOUT).await?;
+
+            Ok(if params.volumes {
+                "container removed (with volumes)".to_string()
+            } else {
+                format!("container {}", past_tense("rm"))
+            })
+        },
+    )
+    .await
+}
+
+/// POST /api/containers/:id/recreate - Pull the image and recreate the container
+///
+/// Only compose services can be recreated: the compose file is the source of
+/// the container config, so `compose pull` + `compose up --force-recreate`
+/// keeps everything else unchanged.
+pub async fn recreate_container(
+    State(config): State<SharedConfig>,
+    State(jobs): State<SharedJobs>,
+    Path(id): Path<String>,
+) -> Result<Json<JobResponse>, (StatusCode, String)> {
+    let access = config.container_access();
+    let container = find_container(access, &id).await?;
+    ensure_action(access, &container, "recreate")?;
+
+    let (Some(project_name), Some(service)) = (
+        container.info.project.clone(),
+        container.info.service.clone(),
+    ) else {
+        return Err((
+            StatusCode::CONFLICT,
+            "Recreate is only supported for compose services".to_string(),
+        ));
+    };
+
+    let project = group_projects(&fetch_containers(access).await?)
+        .into_iter()
+        .find(|p| p.name == project_name)
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            format!("Project not found: {}", project_name),
+        ))?;
+
+    let pull = compose_args(&project, &["pull", &service]).await?;
+    let up = compose_args(
+        &project,
+        &["up", "-d", "--no-deps", "--force-recreate", &service],
+    )
+    .await?;
+
+    // Compose commands on one project must not overlap, so the project is
+    // locked as well
+    let target = JobTarget {
+        name: container.info.name.clone(),
+        project: Some(project_name),
+        container_ids: vec![container.info.id.clone()],
+    };
+    let job = jobs.start("recreate", target, |job| async move {
+        let pull: Vec<&str> = pull.iter().map(|s| s.as_str()).collect();
+        run_docker_logged(&job, &pull, "compose pull", COMPOSE_TIMEOUT).await?;
+
+        let up: Vec<&str> = up.iter().map(|s| s.as_str()).collect();
+        run_docker_logged(&job, &up, "compose up", COMPOSE_TIMEOUT).await?;
+
+        Ok(format!("service {} pulled and recreated", service))
+    })?;
+
+    Ok(Json(JobResponse { job }))
+}
+
+/// Signals are passed as `SIGNAME`, `NAME` or a number
+fn validate_signal(signal: &str) -> Result<(), (StatusCode, String)> {
+    let valid = !signal.is_empty()
+        && signal.len() <= 16
+        && (signal.chars().all(|c| c.is_ascii_digit())
+            || signal
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '+' || c == '-'));
+
+    if !valid {
+        return Err((StatusCode::BAD_REQUEST, "Invalid signal".into()));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,163 @@
+use super::super::types::ContainerInfo;
+use crate::config::{ContainerAccess, ContainerIdentity};
+use axum::http::StatusCode;
+use std::collections::HashMap;
+use tokio::process::Command;
+
+/// Compose labels read from every container
+const PROJECT_LABEL: &str = "com.docker.compose.project";
+const SERVICE_LABEL: &str = "com.docker.compose.service";
+const CONFIG_FILES_LABEL: &str = "com.docker.compose.project.config_files";
+const WORKING_DIR_LABEL: &str = "com.docker.compose.project.working_dir";
+const CONFIG_HASH_LABEL: &str = "com.docker.compose.config-hash";
+
+/// A container row from `docker ps` plus the compose metadata needed for grouping
+pub(super) struct ListedContainer {
+    pub info: ContainerInfo,
+    pub config_files: Vec<String>,
+    pub working_dir: Option<String>,
+    /// Hash of the service config the container was created from
+    pub config_hash: Option<String>,
+    /// Values of the labels referenced by the access rules
+    pub labels: HashMap<String, String>,
+}
+
+impl ListedContainer {
+    /// Identity used to match access rules
+    pub fn identity(&self) -> ContainerIdentity<'_> {
+        ContainerIdentity {
+            name: &self.info.name,
+            project: self.info.project.as_deref(),
+            labels: &self.labels,
+        }
+    }
+}
+
+/// Number of fields before the access rule labels in a `docker ps` line
+const FIXED_FIELDS: usize = 11;
+
+/// Run `docker ps -a` and parse containers including their compose labels
+/// Containers hidden by the access rules are left out
+pub(super) async fn fetch_containers(
+    access: &ContainerAccess,
+) -> Result<Vec<ListedContainer>, (StatusCode, String)> {
+    Ok(fetch_all_containers(access)
+        .await?
+        .into_iter()
+        .filter(|listed| access.is_visible(&listed.identity()))
+        .collect())
+}
+
+/// Like `fetch_containers`, but including hidden containers
+/// Only for checking the access rules, never to show them
+pub(super) async fn fetch_all_containers(
+    access: &ContainerAccess,
+) -> Result<Vec<ListedContainer>, (StatusCode, String)> {
+    let label_keys = access.label_keys();
+    let mut format = format!(
+        "{{{{.ID}}}}\t{{{{.Names}}}}\t{{{{.State}}}}\t{{{{.Status}}}}\t{{{{.Image}}}}\t{{{{.CreatedAt}}}}\t{}\t{}\t{}\t{}\t{}",
+        label_template(PROJECT_LABEL),
+        label_template(SERVICE_LABEL),
+        label_template(CONFIG_FILES_LABEL),
+        label_template(WORKING_DIR_LABEL),
+        label_template(CONFIG_HASH_LABEL),
+    );
+    for key in &label_keys {
+        format.push('\t');
+        format.push_str(&label_template(key));
+    }
+
+    let output = Command::new("docker")
+        .args(["ps", "-a", "--format", &format])
+        .output()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to execute docker command: {}", e),
+            )
+        })?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Docker command failed: {}", error),
+        ));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(stdout
+        .lines()
+        .filter_map(|line| parse_line(line, &label_keys))
+        .collect())
+}
+
+/// Compose files of all visible projects as `(project, path)` pairs
+pub async fn compose_config_files(
+    access: &ContainerAccess,
+) -> Result<Vec<(String, String)>, (StatusCode, String)> {
+    let mut files = Vec::new();
+    for listed in fetch_containers(access).await? {
+        if let Some(project) = listed.info.project {
+            for path in listed.config_files {
+                if !files.iter().any(|(_, p)| p == &path) {
+                    files.push((project.clone(), path));
+                }
+            }
+        }
+    }
+    Ok(files)
+}
+
+fn label_template(label: &str) -> String {
+    format!("{{{{.Label \"{}\"}}}}", label)
+}
+
+fn parse_line(line: &str, label_keys: &[String]) -> Option<ListedContainer> {
+    let parts: Vec<&str> = line.split('\t').collect();
+    if parts.len() < 6 {
+        return None;
+    }
+
+    let label = |idx: usize| {
+        parts
+            .get(idx)
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+    };
+
+    let config_files = label(8)
+        .map(|files| {
+            files
+                .split(',')
+                .map(|f| f.trim().to_string())
+                .filter(|f| !f.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let labels = label_keys
+        .iter()
+        .enumerate()
+        .filter_map(|(i, key)| Some((key.clone(), label(FIXED_FIELDS + i)?)))
+        .collect();
+
+    Some(ListedContainer {
+        info: ContainerInfo {
+            id: parts[0].to_string(),
+            name: parts[1].to_string(),
+            state: parts[2].to_string(),
+            status: parts[3].to_string(),
+            image: parts[4].to_string(),
+            created: parts[5].to_string(),
+            project: label(6),
+            service: label(7),
+        },
+        config_files,
+        working_dir: label(9),
+        config_hash: label(10),
+        labels,
+    })
+}
@@ -1,7 +1,37 @@
+mod access;
 mod actions;
+mod apply;
+mod archive;
+mod compose;
 mod details;
+mod exec;
+mod export;
+mod files;
 mod handlers;
+mod inspect;
+mod lifecycle;
+mod listing;
+mod mounts;
 mod parser;
+mod projects;
+mod settings;
 
+pub use apply::{apply_project, plan_project};
 pub use details::get_container_details;
+pub use exec::exec_container;
+pub use export::export_compose;
+pub use files::{
+    CONTAINER_FILE_PREFIX, browsable_containers, list_container_files, read_container_file,
+    write_container_file,
+};
 pub use handlers::{list_containers, restart_container, start_container, stop_container};
+pub use inspect::{container_usage, ensure_allowed_on_all, ensure_users_allow, user_names};
+pub use lifecycle::{
+    kill_container, pause_container, recreate_container, remove_container, unpause_container,
+};
+pub use listing::compose_config_files;
+pub use mounts::{container_mounts, users_of};
+pub use projects::{
+    compose_down, compose_up, list_projects, restart_project, start_project, stop_project,
+};
+pub use settings::update_container;
@@ -0,0 +1,72 @@
+use super::super::types::{MountUser, VolumeMount};
+use super::inspect::inspect_containers;
+use super::parser;
+use crate::config::ContainerAccess;
+use axum::http::StatusCode;
+use std::path::{Path, PathBuf};
+
+/// Mounts of a single container
+pub struct ContainerMounts {
+    pub id: String,
+    pub name: String,
+    pub mounts: Vec<VolumeMount>,
+}
+
+/// Inspect all visible containers and collect their mounts
+///
+/// Mount sources are canonicalized (off the async runtime) so `users_of`
+/// can compare them with managed paths.
+pub async fn container_mounts(
+    access: &ContainerAccess,
+) -> Result<Vec<ContainerMounts>, (StatusCode, String)> {
+    let containers: Vec<ContainerMounts> = inspect_containers(access)
+        .await?
+        .iter()
+        .map(|c| ContainerMounts {
+            id: parser::extract_short_id(c),
+            name: parser::extract_container_name(c),
+            mounts: parser::extract_mounts(c),
+        })
+        .collect();
+
+    tokio::task::spawn_blocking(move || {
+        let mut containers = containers;
+        for mount in containers.iter_mut().flat_map(|c| &mut c.mounts) {
+            if let Ok(source) = std::fs::canonicalize(&mount.source) {
+                mount.source = source.to_string_lossy().into_owned();
+            }
+        }
+        containers
+    })
+    .await
+    .map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Failed to resolve mount sources: {}", e),
+        )
+    })
+}
+
+/// Containers whose mount source is `path` or one of its parent directories
+pub async fn users_of(path: &str, containers: &[ContainerMounts]) -> Vec<MountUser> {
+    // Resolve symlinks where possible so equivalent paths compare equal
+    let path = tokio::fs::canonicalize(path)
+        .await
+        .unwrap_or_else(|_| PathBuf::from(path));
+    let mut users = Vec::new();
+
+    for container in containers {
+        for mount in &container.mounts {
+            if path.starts_with(Path::new(&mount.source)) {
+                users.push(MountUser {
+                    id: container.id.clone(),
+                    name: container.name.clone(),
+                    destination: mount.destination.clone(),
+                });
+                break;
+            }
+        }
+    }
+
+    users
+}
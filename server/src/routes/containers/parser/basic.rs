@@ -25,6 +25,14 @@ pub(super) fn extract_image(c: &Value) -> String {
         .to_string()
 }
 
+/// Full image ID (`sha256:...`) the container was created from
+pub(super) fn extract_image_id(c: &Value) -> String {
+    c.get("Image")
+        .and_then(|i| i.as_str())
+        .unwrap_or("")
+        .to_string()
+}
+
 pub(super) fn extract_state(c: &Value) -> String {
     c.get("State")
         .and_then(|s| s.get("Status"))
@@ -33,8 +41,23 @@ pub(super) fn extract_state(c: &Value) -> String {
         .to_string()
 }
 
-pub(super) fn extract_status(c: &Value) -> String {
-    extract_state(c)
+/// Times docker restarted the container under its restart policy
+pub(super) fn extract_restart_count(c: &Value) -> u64 {
+    c.get("RestartCount").and_then(|r| r.as_u64()).unwrap_or(0)
+}
+
+pub(super) fn extract_exit_code(c: &Value) -> i64 {
+    c.get("State")
+        .and_then(|s| s.get("ExitCode"))
+        .and_then(|e| e.as_i64())
+        .unwrap_or(0)
+}
+
+pub(super) fn extract_oom_killed(c: &Value) -> bool {
+    c.get("State")
+        .and_then(|s| s.get("OOMKilled"))
+        .and_then(|o| o.as_bool())
+        .unwrap_or(false)
 }
 
 pub(super) fn extract_created(c: &Value) -> String {
@@ -51,3 +74,13 @@ pub(super) fn extract_started(c: &Value) -> String {
         .unwrap_or("")
         .to_string()
 }
+
+/// Time the container last exited (zero time if it never did)
+pub(super) fn extract_finished(c: &Value) -> String {
+    c.get("State")
+        .and_then(|s| s.get("FinishedAt"))
+        .and_then(|s| s.as_str())
+        .filter(|s| !s.starts_with("0001-"))
+        .unwrap_or("")
+        .to_string()
+}
@@ -1,4 +1,6 @@
+use crate::routes::types::{HealthLogEntry, HealthcheckConfig, ResourceLimits};
 use serde_json::Value;
+use std::collections::BTreeMap;
 
 pub(super) fn extract_environment(c: &Value) -> Vec<String> {
     c.get("Config")
@@ -12,13 +14,24 @@ pub(super) fn extract_environment(c: &Value) -> Vec<String> {
         .unwrap_or_default()
 }
 
+/// Restart policy as passed to `--restart` ("on-failure:3" if retries are capped)
 pub(super) fn extract_restart_policy(c: &Value) -> String {
-    c.get("HostConfig")
-        .and_then(|h| h.get("RestartPolicy"))
+    let policy = c.get("HostConfig").and_then(|h| h.get("RestartPolicy"));
+    let name = policy
         .and_then(|r| r.get("Name"))
         .and_then(|n| n.as_str())
-        .unwrap_or("no")
-        .to_string()
+        .filter(|n| !n.is_empty())
+        .unwrap_or("no");
+    let retries = policy
+        .and_then(|r| r.get("MaximumRetryCount"))
+        .and_then(|n| n.as_u64())
+        .unwrap_or(0);
+
+    if name == "on-failure" && retries > 0 {
+        format!("{}:{}", name, retries)
+    } else {
+        name.to_string()
+    }
 }
 
 pub(super) fn extract_health(c: &Value) -> Option<String> {
@@ -28,3 +41,125 @@ pub(super) fn extract_health(c: &Value) -> Option<String> {
         .and_then(|s| s.as_str())
         .map(|s| s.to_string())
 }
+
+pub(super) fn extract_labels(c: &Value) -> BTreeMap<String, String> {
+    c.get("Config")
+        .and_then(|cfg| cfg.get("Labels"))
+        .and_then(|l| l.as_object())
+        .map(|obj| {
+            obj.iter()
+                .map(|(k, v)| (k.clone(), v.as_str().unwrap_or("").to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// String array under `Config.<key>` (Cmd, Entrypoint), empty when null
+pub(super) fn extract_config_list(c: &Value, key: &str) -> Vec<String> {
+    c.get("Config")
+        .and_then(|cfg| cfg.get(key))
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub(super) fn extract_config_str(c: &Value, key: &str) -> String {
+    c.get("Config")
+        .and_then(|cfg| cfg.get(key))
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string()
+}
+
+pub(super) fn extract_limits(c: &Value) -> ResourceLimits {
+    let host = c.get("HostConfig");
+    let unsigned = |key: &str| {
+        host.and_then(|h| h.get(key))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0)
+    };
+
+    ResourceLimits {
+        memory: unsigned("Memory"),
+        memory_reservation: unsigned("MemoryReservation"),
+        cpu_shares: unsigned("CpuShares"),
+        cpu_quota: host
+            .and_then(|h| h.get("CpuQuota"))
+            .and_then(|v| v.as_i64())
+            .unwrap_or(0),
+        cpu_period: unsigned("CpuPeriod"),
+        nano_cpus: unsigned("NanoCpus"),
+    }
+}
+
+/// Health check from the container config (image default or override)
+pub(super) fn extract_healthcheck(c: &Value) -> Option<HealthcheckConfig> {
+    let check = c.get("Config").and_then(|cfg| cfg.get("Healthcheck"))?;
+    let test: Vec<String> = check
+        .get("Test")
+        .and_then(|t| t.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    // ["NONE"] disables a health check inherited from the image
+    if test.is_empty() || test[0] == "NONE" {
+        return None;
+    }
+
+    let duration = |key: &str| format_duration(check.get(key).and_then(|d| d.as_u64()));
+    Some(HealthcheckConfig {
+        test,
+        interval: duration("Interval"),
+        timeout: duration("Timeout"),
+        start_period: duration("StartPeriod"),
+        retries: check.get("Retries").and_then(|r| r.as_u64()).unwrap_or(0),
+    })
+}
+
+pub(super) fn extract_health_log(c: &Value) -> Vec<HealthLogEntry> {
+    c.get("State")
+        .and_then(|s| s.get("Health"))
+        .and_then(|h| h.get("Log"))
+        .and_then(|l| l.as_array())
+        .map(|log| {
+            log.iter()
+                .map(|entry| HealthLogEntry {
+                    start: entry
+                        .get("Start")
+                        .and_then(|s| s.as_str())
+                        .unwrap_or("")
+                        .to_string(),
+                    exit_code: entry.get("ExitCode").and_then(|e| e.as_i64()).unwrap_or(0),
+                    output: entry
+                        .get("Output")
+                        .and_then(|o| o.as_str())
+                        .unwrap_or("")
+                        .trim()
+                        .to_string(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Docker stores health check durations in nanoseconds; 0/missing means
+/// docker's default
+fn format_duration(nanos: Option<u64>) -> String {
+    let secs = match nanos {
+        Some(n) if n > 0 => n / 1_000_000_000,
+        _ => return "default".to_string(),
+    };
+
+    match (secs / 60, secs % 60) {
+        (0, s) => format!("{}s", s),
+        (m, 0) => format!("{}m", m),
+        (m, s) => format!("{}m{}s", m, s),
+    }
+}
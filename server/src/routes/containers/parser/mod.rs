@@ -3,17 +3,22 @@ mod config;
 mod network;
 mod storage;
 
-use super::super::types::ContainerDetails;
+use super::super::types::{ContainerDetails, VolumeMount};
 use axum::http::StatusCode;
 use serde_json::Value;
 
-pub(super) fn build_details(container: &Value) -> Result<ContainerDetails, (StatusCode, String)> {
+/// `status` is the human readable status from `docker ps` ("Up 2 hours"),
+/// which inspect output doesn't carry
+pub(super) fn build_details(
+    container: &Value,
+    status: String,
+) -> Result<ContainerDetails, (StatusCode, String)> {
     Ok(ContainerDetails {
         id: basic::extract_id(container),
         name: basic::extract_name(container),
         image: basic::extract_image(container),
         state: basic::extract_state(container),
-        status: basic::extract_status(container),
+        status,
         created: basic::extract_created(container),
         started: basic::extract_started(container),
         ports: network::extract_ports(container),
@@ -22,5 +27,42 @@ pub(super) fn build_details(container: &Value) -> Result<ContainerDetails, (Stat
         environment: config::extract_environment(container),
         restart_policy: config::extract_restart_policy(container),
         health: config::extract_health(container),
+        labels: config::extract_labels(container),
+        command: config::extract_config_list(container, "Cmd"),
+        entrypoint: config::extract_config_list(container, "Entrypoint"),
+        working_dir: config::extract_config_str(container, "WorkingDir"),
+        user: config::extract_config_str(container, "User"),
+        limits: config::extract_limits(container),
+        restart_count: basic::extract_restart_count(container),
+        exit_code: basic::extract_exit_code(container),
+        oom_killed: basic::extract_oom_killed(container),
+        finished: basic::extract_finished(container),
+        healthcheck: config::extract_healthcheck(container),
+        health_log: config::extract_health_log(container),
+        raw: container.clone(),
     })
 }
+
+pub(super) fn extract_short_id(container: &Value) -> String {
+    basic::extract_id(container)
+}
+
+pub(super) fn extract_container_name(container: &Value) -> String {
+    basic::extract_name(container)
+}
+
+pub(super) fn extract_image_id(container: &Value) -> String {
+    basic::extract_image_id(container)
+}
+
+pub(super) fn extract_volume_names(container: &Value) -> Vec<String> {
+    storage::extract_volume_names(container)
+}
+
+pub(super) fn extract_network_names(container: &Value) -> Vec<String> {
+    network::extract_networks(container)
+}
+
+pub(super) fn extract_mounts(container: &Value) -> Vec<VolumeMount> {
+    storage::extract_volumes(container)
+}
@@ -9,20 +9,35 @@ pub(super) fn extract_ports(c: &Value) -> Vec<PortMapping> {
         .and_then(|p| p.as_object())
     {
         for (container_port, bindings) in port_map {
-            if let Some(bind_arr) = bindings.as_array() {
-                for bind in bind_arr {
-                    if let Some(host_port) = bind.get("HostPort").and_then(|p| p.as_str()) {
-                        let parts: Vec<&str> = container_port.split('/').collect();
-                        ports.push(PortMapping {
-                            container_port: parts[0].to_string(),
-                            host_port: host_port.to_string(),
-                            protocol: parts.get(1).unwrap_or(&"tcp").to_string(),
-                        });
+            let (port, protocol) = container_port
+                .split_once('/')
+                .unwrap_or((container_port, "tcp"));
+            let mapping = |host_ip: Option<&str>, host_port: Option<&str>| PortMapping {
+                container_port: port.to_string(),
+                host_ip: host_ip.map(|ip| ip.to_string()),
+                host_port: host_port.map(|p| p.to_string()),
+                protocol: protocol.to_string(),
+            };
+
+            // Exposed but unpublished ports have `null` bindings; published
+            // ones have a binding per address family (0.0.0.0 and ::)
+            match bindings.as_array() {
+                Some(bind_arr) if !bind_arr.is_empty() => {
+                    for bind in bind_arr {
+                        ports.push(mapping(
+                            bind.get("HostIp").and_then(|i| i.as_str()),
+                            bind.get("HostPort").and_then(|p| p.as_str()),
+                        ));
                     }
                 }
+                _ => ports.push(mapping(None, None)),
             }
         }
     }
+    ports.sort_by(|a, b| {
+        let key = |p: &PortMapping| p.container_port.parse::<u32>().unwrap_or(u32::MAX);
+        key(a).cmp(&key(b))
+    });
     ports
 }
 
@@ -26,3 +26,18 @@ pub(super) fn extract_volumes(c: &Value) -> Vec<VolumeMount> {
         })
         .unwrap_or_default()
 }
+
+/// Names of the named volumes (not bind mounts) a container mounts
+pub(super) fn extract_volume_names(c: &Value) -> Vec<String> {
+    c.get("Mounts")
+        .and_then(|m| m.as_array())
+        .map(|mounts| {
+            mounts
+                .iter()
+                .filter(|mount| mount.get("Type").and_then(|t| t.as_str()) == Some("volume"))
+                .filter_map(|mount| mount.get("Name").and_then(|n| n.as_str()))
+                .map(|name| name.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,163 @@
+use super::super::docker::run_docker_logged;
+use super::super::types::{JobResponse, ProjectInfo, ProjectListResponse};
+use super::access::ensure_action;
+use super::actions::{ACTION_TIMEOUT, past_tense};
+use super::compose::{compose_args, group_projects, validate_project_name};
+use super::listing::{fetch_all_containers, fetch_containers};
+use crate::config::{ContainerAccess, SharedConfig};
+use crate::jobs::{JobTarget, SharedJobs};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use std::time::Duration;
+
+/// Timeout for `docker compose up/down` (may pull images)
+pub(super) const COMPOSE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// GET /api/projects - List Docker Compose projects with aggregate state
+pub async fn list_projects(
+    State(config): State<SharedConfig>,
+) -> Result<Json<ProjectListResponse>, (StatusCode, String)> {
+    let containers = fetch_containers(config.container_access()).await?;
+    let projects = group_projects(&containers);
+
+    Ok(Json(ProjectListResponse { projects }))
+}
+
+/// POST /api/projects/:name/start - Start all containers of a project
+pub async fn start_project(
+    State(config): State<SharedConfig>,
+    State(jobs): State<SharedJobs>,
+    Path(name): Path<String>,
+) -> Result<Json<JobResponse>, (StatusCode, String)> {
+    execute_project_action(&config, &jobs, &name, "start").await
+}
+
+/// POST /api/projects/:name/stop - Stop all containers of a project
+pub async fn stop_project(
+    State(config): State<SharedConfig>,
+    State(jobs): State<SharedJobs>,
+    Path(name): Path<String>,
+) -> Result<Json<JobResponse>, (StatusCode, String)> {
+    execute_project_action(&config, &jobs, &name, "stop").await
+}
+
+/// POST /api/projects/:name/restart - Restart all containers of a project
+pub async fn restart_project(
+    State(config): State<SharedConfig>,
+    State(jobs): State<SharedJobs>,
+    Path(name): Path<String>,
+) -> Result<Json<JobResponse>, (StatusCode, String)> {
+    execute_project_action(&config, &jobs, &name, "restart").await
+}
+
+/// POST /api/projects/:name/up - Run `docker compose up -d` for a project
+pub async fn compose_up(
+    State(config): State<SharedConfig>,
+    State(jobs): State<SharedJobs>,
+    Path(name): Path<String>,
+) -> Result<Json<JobResponse>, (StatusCode, String)> {
+    execute_compose(&config, &jobs, &name, &["up", "-d"], "project up").await
+}
+
+/// POST /api/projects/:name/down - Run `docker compose down` for a project
+pub async fn compose_down(
+    State(config): State<SharedConfig>,
+    State(jobs): State<SharedJobs>,
+    Path(name): Path<String>,
+) -> Result<Json<JobResponse>, (StatusCode, String)> {
+    execute_compose(&config, &jobs, &name, &["down"], "project down").await
+}
+
+/// Find a visible project; with an action, every container must permit it
+///
+/// Compose commands act on all services of a project, so an action is
+/// refused if any of its containers is hidden by the access rules.
+pub(super) async fn find_project(
+    access: &ContainerAccess,
+    name: &str,
+    action: Option<&str>,
+) -> Result<ProjectInfo, (StatusCode, String)> {
+    validate_project_name(name)?;
+
+    let (containers, hidden): (Vec<_>, Vec<_>) = fetch_all_containers(access)
+        .await?
+        .into_iter()
+        .partition(|c| access.is_visible(&c.identity()));
+    let project = group_projects(&containers)
+        .into_iter()
+        .find(|p| p.name == name)
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            format!("Project not found: {}", name),
+        ))?;
+
+    if let Some(action) = action {
+        if hidden
+            .iter()
+            .any(|c| c.info.project.as_deref() == Some(name))
+        {
+            return Err((
+                StatusCode::FORBIDDEN,
+                format!(
+                    "Action {} not permitted on project {}: it has hidden containers",
+                    action, name
+                ),
+            ));
+        }
+        for container in &containers {
+            if container.info.project.as_deref() == Some(name) {
+                ensure_action(access, container, action)?;
+            }
+        }
+    }
+
+    Ok(project)
+}
+
+async fn execute_project_action(
+    config: &SharedConfig,
+    jobs: &SharedJobs,
+    name: &str,
+    action: &'static str,
+) -> Result<Json<JobResponse>, (StatusCode, String)> {
+    let project = find_project(config.container_access(), name, Some(action)).await?;
+
+    let target = JobTarget::project(&project.name, &project.containers);
+    let job = jobs.start(action, target, |job| async move {
+        let mut args = vec![action];
+        args.extend(project.containers.iter().map(|id| id.as_str()));
+        run_docker_logged(&job, &args, action, ACTION_TIMEOUT).await?;
+
+        Ok(format!(
+            "project {} ({} containers)",
+            past_tense(action),
+            project.containers.len()
+        ))
+    })?;
+
+    Ok(Json(JobResponse { job }))
+}
+
+async fn execute_compose(
+    config: &SharedConfig,
+    jobs: &SharedJobs,
+    name: &str,
+    command: &[&str],
+    message: &'static str,
+) -> Result<Json<JobResponse>, (StatusCode, String)> {
+    let project = find_project(config.container_access(), name, Some(command[0])).await?;
+    let args = compose_args(&project, command).await?;
+    let action = format!("compose {}", command[0]);
+
+    let target = JobTarget::project(&project.name, &project.containers);
+    let job = jobs.start(&action.clone(), target, |job| async move {
+        let args: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+        run_docker_logged(&job, &args, &action, COMPOSE_TIMEOUT).await?;
+        Ok(message.to_string())
+    })?;
+
+    Ok(Json(JobResponse { job }))
+}
@@ -0,0 +1,128 @@
+use super::super::docker::run_docker_logged;
+use super::super::types::{JobResponse, UpdateSettingsRequest};
+use super::actions::{ACTION_TIMEOUT, container_job};
+use crate::audit;
+use crate::config::SharedConfig;
+use crate::jobs::SharedJobs;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+/// Smallest memory limit docker accepts
+const MIN_MEMORY_BYTES: u64 = 6 * 1024 * 1024;
+
+/// POST /api/containers/:id/update - Change restart policy and resource limits
+/// of a container in place (`docker update`)
+pub async fn update_container(
+    State(config): State<SharedConfig>,
+    State(jobs): State<SharedJobs>,
+    Path(id): Path<String>,
+    Json(request): Json<UpdateSettingsRequest>,
+) -> Result<Json<JobResponse>, (StatusCode, String)> {
+    let changes = validate(&request).map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    if changes.is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "Nothing to update".to_string()));
+    }
+
+    let summary = changes
+        .iter()
+        .map(|(flag, value)| format!("{}={}", flag, value))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let audit_settings = config.audit_settings().clone();
+
+    container_job(
+        &config,
+        &jobs,
+        &id,
+        "update",
+        |job, container_id| async move {
+            let mut args = vec!["update".to_string()];
+            for (flag, value) in &changes {
+                args.push(format!("--{}", flag));
+                args.push(value.clone());
+            }
+            args.push(container_id);
+            let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+            let result = run_docker_logged(&job, &args, "update", ACTION_TIMEOUT).await;
+            audit::record(
+                &audit_settings,
+                "update",
+                &job.target(),
+                &summary,
+                result.is_ok(),
+            );
+            result?;
+            Ok(format!("updated {}", summary))
+        },
+    )
+    .await
+}
+
+/// Check the requested settings and turn them into `docker update` flags
+fn validate(request: &UpdateSettingsRequest) -> Result<Vec<(&'static str, String)>, String> {
+    let mut changes = Vec::new();
+
+    if let Some(policy) = &request.restart_policy {
+        let policy = policy.trim();
+        validate_restart_policy(policy)?;
+        changes.push(("restart", policy.to_string()));
+    }
+
+    if let Some(memory) = &request.memory {
+        let memory = memory.trim().to_lowercase();
+        let bytes = parse_memory(&memory)
+            .ok_or_else(|| format!("Invalid memory limit: {} (e.g. 512m, 2g)", memory))?;
+        if bytes != 0 && bytes < MIN_MEMORY_BYTES {
+            return Err("Memory limit must be at least 6m".to_string());
+        }
+        changes.push(("memory", memory));
+    }
+
+    if let Some(cpus) = &request.cpus {
+        let cpus = cpus.trim();
+        match cpus.parse::<f64>() {
+            Ok(n) if n.is_finite() && n >= 0.0 => changes.push(("cpus", cpus.to_string())),
+            _ => return Err(format!("Invalid CPU limit: {} (e.g. 0.5, 2)", cpus)),
+        }
+    }
+
+    Ok(changes)
+}
+
+fn validate_restart_policy(policy: &str) -> Result<(), String> {
+    let valid = match policy.split_once(':') {
+        Some(("on-failure", retries)) => retries.parse::<u32>().is_ok(),
+        Some(_) => false,
+        None => matches!(policy, "no" | "always" | "unless-stopped" | "on-failure"),
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid restart policy: {} (no, always, unless-stopped, on-failure[:N])",
+            policy
+        ))
+    }
+}
+
+/// Parse a docker memory size ("512m", "1.5g", "1048576") into bytes
+fn parse_memory(value: &str) -> Option<u64> {
+    let (number, multiplier) = match value.char_indices().last()? {
+        (i, 'b') => (&value[..i], 1),
+        (i, 'k') => (&value[..i], 1024),
+        (i, 'm') => (&value[..i], 1024 * 1024),
+        (i, 'g') => (&value[..i], 1024 * 1024 * 1024),
+        _ => (value, 1),
+    };
+
+    let number: f64 = number.parse().ok()?;
+    if !number.is_finite() || number < 0.0 {
+        return None;
+    }
+    Some((number * multiplier as f64) as u64)
+}
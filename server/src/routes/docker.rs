@@ -0,0 +1,173 @@
+use crate::jobs::JobHandle;
+use axum::http::StatusCode;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWriteExt, BufReader};
+use tokio::process::Command;
+
+/// Run a docker command with a timeout and return its stdout
+/// `action` is only used to build readable error messages
+pub async fn run_docker(
+    args: &[&str],
+    action: &str,
+    timeout: Duration,
+) -> Result<String, (StatusCode, String)> {
+    let stdout = run_docker_bytes(args, None, action, timeout).await?;
+    Ok(String::from_utf8_lossy(&stdout).to_string())
+}
+
+/// Like [`run_docker`] for binary output (`docker cp` archives), optionally
+/// feeding `stdin` to the command
+pub async fn run_docker_bytes(
+    args: &[&str],
+    stdin: Option<&[u8]>,
+    action: &str,
+    timeout: Duration,
+) -> Result<Vec<u8>, (StatusCode, String)> {
+    let failed = |e: std::io::Error| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("docker {} failed: {}", action, e),
+        )
+    };
+
+    let run = async {
+        let mut child = Command::new("docker")
+            .args(args)
+            .stdin(if stdin.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()?;
+        if let (Some(input), Some(mut pipe)) = (stdin, child.stdin.take()) {
+            pipe.write_all(input).await?;
+            // Dropping the pipe closes stdin so docker sees the end of input
+        }
+        child.wait_with_output().await
+    };
+
+    let output = tokio::time::timeout(timeout, run)
+        .await
+        .map_err(|_| {
+            (
+                StatusCode::REQUEST_TIMEOUT,
+                format!("docker {} timed out", action),
+            )
+        })?
+        .map_err(failed)?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("docker {} failed: {}", action, error),
+        ));
+    }
+
+    Ok(output.stdout)
+}
+
+/// Like [`run_docker`], but reports the command as the job's current step
+/// and copies its output into the job log line by line
+pub async fn run_docker_logged(
+    job: &JobHandle,
+    args: &[&str],
+    action: &str,
+    timeout: Duration,
+) -> Result<String, (StatusCode, String)> {
+    job.step(format!("docker {}", action));
+
+    let mut child = Command::new("docker")
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("docker {} failed: {}", action, e),
+            )
+        })?;
+
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+    let run = async {
+        let (stdout, stderr) = tokio::join!(log_lines(job, stdout), log_lines(job, stderr));
+        (child.wait().await, stdout, stderr)
+    };
+
+    let (status, stdout, stderr) = tokio::time::timeout(timeout, run).await.map_err(|_| {
+        (
+            StatusCode::REQUEST_TIMEOUT,
+            format!("docker {} timed out", action),
+        )
+    })?;
+    let status = status.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("docker {} failed: {}", action, e),
+        )
+    })?;
+
+    if !status.success() {
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("docker {} failed: {}", action, stderr.trim()),
+        ));
+    }
+
+    Ok(stdout)
+}
+
+/// Read a child pipe to the end, logging each line to the job
+async fn log_lines(job: &JobHandle, pipe: Option<impl AsyncRead + Unpin>) -> String {
+    let Some(pipe) = pipe else {
+        return String::new();
+    };
+
+    let mut collected = String::new();
+    let mut lines = BufReader::new(pipe).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        job.log(line.clone());
+        collected.push_str(&line);
+        collected.push('\n');
+    }
+    collected
+}
+
+/// Validates a docker object name or ID from a request path (containers,
+/// volumes, networks). Docker names start with an alphanumeric character and
+/// may contain `_.-`, which also rules out values that docker would parse as
+/// options
+pub fn validate_docker_name(name: &str, what: &str) -> Result<(), (StatusCode, String)> {
+    let valid = name.len() <= 128
+        && name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphanumeric())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'));
+
+    if !valid {
+        return Err((StatusCode::BAD_REQUEST, format!("Invalid {}", what)));
+    }
+
+    Ok(())
+}
+
+/// Space freed by a `docker ... prune` run, from its last output line
+/// ("Total reclaimed space: 1.2GB")
+pub fn reclaimed_space(stdout: &str) -> String {
+    stdout
+        .lines()
+        .rev()
+        .find_map(|l| l.strip_prefix("Total reclaimed space:"))
+        .map(|s| s.trim().to_string())
+        .unwrap_or_else(|| "0B".to_string())
+}
@@ -0,0 +1,178 @@
+use super::super::containers::{
+    container_usage, ensure_allowed_on_all, ensure_users_allow, user_names,
+};
+use super::super::docker::{reclaimed_space, run_docker};
+use super::super::types::{
+    ContainerActionResponse, ImageDetails, ImageDetailsResponse, ImageListResponse,
+};
+use super::listing::{fetch_images, format_size, validate_image_id};
+use crate::config::SharedConfig;
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use serde::Deserialize;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::process::Command;
+
+/// Timeout for image removal and pruning
+const IMAGE_TIMEOUT: Duration = Duration::from_secs(120);
+
+#[derive(Deserialize)]
+pub struct RemoveImageParams {
+    /// Remove even if tagged in several repositories or used by stopped containers
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// GET /api/images - List images with size, tags and the containers using them
+pub async fn list_images(
+    State(config): State<SharedConfig>,
+) -> Result<Json<ImageListResponse>, (StatusCode, String)> {
+    let images = fetch_images(config.container_access()).await?;
+    Ok(Json(ImageListResponse { images }))
+}
+
+/// GET /api/images/:id - Inspect an image
+pub async fn inspect_image(
+    State(config): State<SharedConfig>,
+    Path(id): Path<String>,
+) -> Result<Json<ImageDetailsResponse>, (StatusCode, String)> {
+    validate_image_id(&id)?;
+
+    let output = Command::new("docker")
+        .args(["image", "inspect", &id])
+        .output()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to execute docker inspect: {}", e),
+            )
+        })?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err((StatusCode::NOT_FOUND, format!("Image not found: {}", error)));
+    }
+
+    let json: Vec<Value> = serde_json::from_slice(&output.stdout).map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Failed to parse docker inspect output: {}", e),
+        )
+    })?;
+    let image = json
+        .first()
+        .ok_or((StatusCode::NOT_FOUND, "Image not found".to_string()))?;
+
+    let full_id = str_field(image, &["Id"]);
+    let usage = container_usage(config.container_access())
+        .await
+        .unwrap_or_default();
+    let containers = user_names(usage.iter().filter(|c| c.image_id == full_id));
+
+    Ok(Json(ImageDetailsResponse {
+        details: ImageDetails {
+            id: full_id,
+            tags: str_list(image, &["RepoTags"]),
+            digests: str_list(image, &["RepoDigests"]),
+            created: str_field(image, &["Created"]),
+            size: format_size(image.get("Size").and_then(|s| s.as_u64()).unwrap_or(0)),
+            architecture: str_field(image, &["Architecture"]),
+            os: str_field(image, &["Os"]),
+            entrypoint: str_list(image, &["Config", "Entrypoint"]),
+            cmd: str_list(image, &["Config", "Cmd"]),
+            environment: str_list(image, &["Config", "Env"]),
+            exposed_ports: image
+                .pointer("/Config/ExposedPorts")
+                .and_then(|p| p.as_object())
+                .map(|ports| ports.keys().cloned().collect())
+                .unwrap_or_default(),
+            layers: image
+                .pointer("/RootFS/Layers")
+                .and_then(|l| l.as_array())
+                .map_or(0, |l| l.len()),
+            containers,
+        },
+    }))
+}
+
+/// POST /api/images/:id/remove?force=true - Remove an image
+///
+/// Every container using the image must permit "remove" in the access rules.
+pub async fn remove_image(
+    State(config): State<SharedConfig>,
+    Path(id): Path<String>,
+    Query(params): Query<RemoveImageParams>,
+) -> Result<Json<ContainerActionResponse>, (StatusCode, String)> {
+    validate_image_id(&id)?;
+
+    let full_id = run_docker(
+        &["image", "inspect", "--format", "{{.Id}}", &id],
+        "image inspect",
+        IMAGE_TIMEOUT,
+    )
+    .await?;
+    let full_id = full_id.trim();
+    let usage = container_usage(config.container_access()).await?;
+    ensure_users_allow(
+        config.container_access(),
+        usage.iter().filter(|c| c.image_id == full_id),
+        "remove",
+        "image",
+    )?;
+
+    let mut args = vec!["image", "rm"];
+    if params.force {
+        args.push("--force");
+    }
+    args.push(&id);
+    run_docker(&args, "image rm", IMAGE_TIMEOUT).await?;
+
+    Ok(Json(ContainerActionResponse {
+        success: true,
+        message: "image removed".to_string(),
+    }))
+}
+
+/// POST /api/images/prune - Remove dangling images
+///
+/// Acts on the whole daemon, so every container must permit "prune".
+pub async fn prune_images(
+    State(config): State<SharedConfig>,
+) -> Result<Json<ContainerActionResponse>, (StatusCode, String)> {
+    ensure_allowed_on_all(config.container_access(), "prune").await?;
+    let stdout = run_docker(&["image", "prune", "--force"], "image prune", IMAGE_TIMEOUT).await?;
+
+    Ok(Json(ContainerActionResponse {
+        success: true,
+        message: format!(
+            "dangling images pruned ({} reclaimed)",
+            reclaimed_space(&stdout)
+        ),
+    }))
+}
+
+fn str_field(value: &Value, path: &[&str]) -> String {
+    path.iter()
+        .try_fold(value, |v, key| v.get(key))
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string()
+}
+
+fn str_list(value: &Value, path: &[&str]) -> Vec<String> {
+    path.iter()
+        .try_fold(value, |v, key| v.get(key))
+        .and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|i| i.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
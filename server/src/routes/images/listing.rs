@@ -0,0 +1,105 @@
+use super::super::containers::{container_usage, user_names};
+use super::super::types::ImageInfo;
+use crate::config::ContainerAccess;
+use axum::http::StatusCode;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use tokio::process::Command;
+
+/// One line of `docker image ls --format '{{json .}}'` (one per tag)
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ImageLine {
+    #[serde(rename = "ID")]
+    id: String,
+    repository: String,
+    tag: String,
+    size: String,
+    created_since: String,
+}
+
+/// List images (one entry per image ID) with the containers using them
+pub(super) async fn fetch_images(
+    access: &ContainerAccess,
+) -> Result<Vec<ImageInfo>, (StatusCode, String)> {
+    let output = Command::new("docker")
+        .args(["image", "ls", "--no-trunc", "--format", "{{json .}}"])
+        .output()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to execute docker command: {}", e),
+            )
+        })?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Docker command failed: {}", error),
+        ));
+    }
+
+    // Usage is informational; without it the list still works
+    let usage = container_usage(access).await.unwrap_or_default();
+
+    let mut images: BTreeMap<String, ImageInfo> = BTreeMap::new();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for line in stdout.lines() {
+        let Ok(line) = serde_json::from_str::<ImageLine>(line) else {
+            continue;
+        };
+
+        let image = images.entry(line.id.clone()).or_insert_with(|| ImageInfo {
+            id: line.id.clone(),
+            tags: Vec::new(),
+            size: line.size.clone(),
+            created: line.created_since.clone(),
+            dangling: true,
+            containers: user_names(usage.iter().filter(|c| c.image_id == line.id)),
+        });
+
+        if line.repository != "<none>" {
+            image.dangling = false;
+            if line.tag != "<none>" {
+                image.tags.push(format!("{}:{}", line.repository, line.tag));
+            } else {
+                image.tags.push(line.repository);
+            }
+        }
+    }
+
+    // Tagged images first, then by first tag
+    let mut images: Vec<ImageInfo> = images.into_values().collect();
+    images.sort_by(|a, b| {
+        a.dangling
+            .cmp(&b.dangling)
+            .then_with(|| a.tags.first().cmp(&b.tags.first()))
+    });
+    Ok(images)
+}
+
+/// Validates an image ID (`sha256:<hex>` or a hex prefix of at least 12 chars)
+pub(super) fn validate_image_id(id: &str) -> Result<(), (StatusCode, String)> {
+    let hex = id.strip_prefix("sha256:").unwrap_or(id);
+    let valid = (12..=64).contains(&hex.len()) && hex.chars().all(|c| c.is_ascii_hexdigit());
+
+    if !valid {
+        return Err((StatusCode::BAD_REQUEST, "Invalid image id".into()));
+    }
+
+    Ok(())
+}
+
+/// Human readable size for a byte count (like docker's output)
+pub(super) fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "kB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1000.0 && unit < UNITS.len() - 1 {
+        size /= 1000.0;
+        unit += 1;
+    }
+    format!("{:.1}{}", size, UNITS[unit])
+}
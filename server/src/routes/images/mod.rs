@@ -0,0 +1,4 @@
+mod handlers;
+mod listing;
+
+pub use handlers::{inspect_image, list_images, prune_images, remove_image};
@@ -0,0 +1,22 @@
+use super::types::{JobListResponse, JobResponse};
+use crate::jobs::SharedJobs;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+/// GET /api/jobs - Running and recently finished jobs, newest first
+pub async fn list_jobs(State(jobs): State<SharedJobs>) -> Json<JobListResponse> {
+    Json(JobListResponse { jobs: jobs.list() })
+}
+
+/// GET /api/jobs/:id - Status, current step and output of a job
+pub async fn get_job(
+    State(jobs): State<SharedJobs>,
+    Path(id): Path<u64>,
+) -> Result<Json<JobResponse>, (StatusCode, String)> {
+    jobs.get(id)
+        .map(|job| Json(JobResponse { job }))
+        .ok_or((StatusCode::NOT_FOUND, format!("Job not found: {}", id)))
+}
@@ -1,8 +1,21 @@
 mod configs;
 mod containers;
+mod docker;
+mod images;
+mod jobs;
+mod networks;
 mod types;
+mod volumes;
 
-pub use configs::{list_configs, read_config, write_config};
+pub use configs::{create_config, list_configs, read_config, write_config};
 pub use containers::{
-    get_container_details, list_containers, restart_container, start_container, stop_container,
+    apply_project, compose_down, compose_up, exec_container, export_compose, get_container_details,
+    kill_container, list_container_files, list_containers, list_projects, pause_container,
+    plan_project, recreate_container, remove_container, restart_container, restart_project,
+    start_container, start_project, stop_container, stop_project, unpause_container,
+    update_container,
 };
+pub use images::{inspect_image, list_images, prune_images, remove_image};
+pub use jobs::{get_job, list_jobs};
+pub use networks::{list_networks, prune_networks, remove_network};
+pub use volumes::{list_volumes, prune_volumes, remove_volume};
@@ -0,0 +1,100 @@
+use super::super::containers::ensure_allowed_on_all;
+use super::super::docker::{run_docker, validate_docker_name};
+use super::super::types::{ContainerActionResponse, NetworkListResponse};
+use super::listing::{BUILTIN_NETWORKS, fetch_networks};
+use crate::config::SharedConfig;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use std::time::Duration;
+
+/// Timeout for network removal and pruning
+const NETWORK_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// GET /api/networks - List networks with subnets and attached containers
+pub async fn list_networks(
+    State(config): State<SharedConfig>,
+) -> Result<Json<NetworkListResponse>, (StatusCode, String)> {
+    let networks = fetch_networks(config.container_access()).await?;
+    Ok(Json(NetworkListResponse { networks }))
+}
+
+/// POST /api/networks/:id/remove - Remove a network without attached containers
+pub async fn remove_network(
+    State(config): State<SharedConfig>,
+    Path(id): Path<String>,
+) -> Result<Json<ContainerActionResponse>, (StatusCode, String)> {
+    validate_docker_name(&id, "network id")?;
+
+    if BUILTIN_NETWORKS.contains(&id.as_str()) {
+        return Err((
+            StatusCode::FORBIDDEN,
+            format!("Network {} is predefined and cannot be removed", id),
+        ));
+    }
+
+    // Resolve names and ID prefixes to the listed network
+    let network = fetch_networks(config.container_access())
+        .await?
+        .into_iter()
+        .find(|n| n.id == id || n.name == id || n.id.starts_with(&id))
+        .ok_or((StatusCode::NOT_FOUND, "Network not found".to_string()))?;
+    if network.builtin {
+        return Err((
+            StatusCode::FORBIDDEN,
+            format!(
+                "Network {} is predefined and cannot be removed",
+                network.name
+            ),
+        ));
+    }
+
+    if !network.containers.is_empty() {
+        return Err((
+            StatusCode::CONFLICT,
+            format!("Network is used by: {}", network.containers.join(", ")),
+        ));
+    }
+
+    run_docker(
+        &["network", "rm", &network.id],
+        "network rm",
+        NETWORK_TIMEOUT,
+    )
+    .await?;
+
+    Ok(Json(ContainerActionResponse {
+        success: true,
+        message: "network removed".to_string(),
+    }))
+}
+
+/// POST /api/networks/prune - Remove networks without attached containers
+///
+/// Acts on the whole daemon, so every container must permit "prune".
+pub async fn prune_networks(
+    State(config): State<SharedConfig>,
+) -> Result<Json<ContainerActionResponse>, (StatusCode, String)> {
+    ensure_allowed_on_all(config.container_access(), "prune").await?;
+    let stdout = run_docker(
+        &["network", "prune", "--force"],
+        "network prune",
+        NETWORK_TIMEOUT,
+    )
+    .await?;
+
+    // Lists removed networks after a "Deleted Networks:" header
+    let removed = stdout
+        .lines()
+        .skip_while(|l| !l.starts_with("Deleted Networks"))
+        .skip(1)
+        .filter(|l| !l.trim().is_empty())
+        .count();
+
+    Ok(Json(ContainerActionResponse {
+        success: true,
+        message: format!("{} unused networks pruned", removed),
+    }))
+}
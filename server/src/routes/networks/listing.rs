@@ -0,0 +1,106 @@
+use super::super::containers::{container_usage, user_names};
+use super::super::types::NetworkInfo;
+use crate::config::ContainerAccess;
+use axum::http::StatusCode;
+use serde_json::Value;
+use tokio::process::Command;
+
+/// Networks created by docker itself, which cannot be removed
+pub(super) const BUILTIN_NETWORKS: [&str; 3] = ["bridge", "host", "none"];
+
+/// List networks with their subnets and the containers attached to them
+pub(super) async fn fetch_networks(
+    access: &ContainerAccess,
+) -> Result<Vec<NetworkInfo>, (StatusCode, String)> {
+    let ids = docker_output(&["network", "ls", "--quiet", "--no-trunc"]).await?;
+    let ids: Vec<&str> = ids.lines().filter(|l| !l.is_empty()).collect();
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut args = vec!["network", "inspect"];
+    args.extend(&ids);
+    let json: Vec<Value> = serde_json::from_str(&docker_output(&args).await?).map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Failed to parse docker network inspect output: {}", e),
+        )
+    })?;
+
+    // Attached containers come from the container side so that hidden
+    // containers are counted without their names
+    let usage = container_usage(access).await.unwrap_or_default();
+
+    let mut networks: Vec<NetworkInfo> = json
+        .iter()
+        .map(|n| {
+            let name = str_field(n, "Name");
+            NetworkInfo {
+                id: str_field(n, "Id"),
+                driver: str_field(n, "Driver"),
+                scope: str_field(n, "Scope"),
+                internal: n.get("Internal").and_then(|i| i.as_bool()).unwrap_or(false),
+                builtin: BUILTIN_NETWORKS.contains(&name.as_str()),
+                subnets: extract_subnets(n),
+                containers: user_names(usage.iter().filter(|c| c.networks.contains(&name))),
+                name,
+            }
+        })
+        .collect();
+
+    networks.sort_by(|a, b| a.builtin.cmp(&b.builtin).then_with(|| a.name.cmp(&b.name)));
+    Ok(networks)
+}
+
+/// IPAM subnets as `subnet` or `subnet via gateway`
+fn extract_subnets(network: &Value) -> Vec<String> {
+    network
+        .pointer("/IPAM/Config")
+        .and_then(|c| c.as_array())
+        .map(|configs| {
+            configs
+                .iter()
+                .filter_map(|c| {
+                    let subnet = c.get("Subnet").and_then(|s| s.as_str())?;
+                    Some(match c.get("Gateway").and_then(|g| g.as_str()) {
+                        Some(gateway) if !gateway.is_empty() => {
+                            format!("{} via {}", subnet, gateway)
+                        }
+                        _ => subnet.to_string(),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn str_field(value: &Value, key: &str) -> String {
+    value
+        .get(key)
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string()
+}
+
+async fn docker_output(args: &[&str]) -> Result<String, (StatusCode, String)> {
+    let output = Command::new("docker")
+        .args(args)
+        .output()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to execute docker command: {}", e),
+            )
+        })?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Docker command failed: {}", error),
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
@@ -0,0 +1,4 @@
+mod handlers;
+mod listing;
+
+pub use handlers::{list_networks, prune_networks, remove_network};
@@ -1,4 +1,7 @@
+use crate::jobs::JobInfo;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
 
 #[derive(Serialize, Clone)]
 pub struct FileInfo {
@@ -8,11 +11,27 @@ pub struct FileInfo {
     /// Optional theme variant for this file
     #[serde(skip_serializing_if = "Option::is_none")]
     pub theme: Option<String>,
+    /// Compose project for discovered compose files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compose_project: Option<String>,
+    /// Containers bind-mounting this file (or one of its parent directories)
+    pub used_by: Vec<MountUser>,
+}
+
+/// A container that bind-mounts a managed config
+#[derive(Serialize, Clone)]
+pub struct MountUser {
+    pub id: String,
+    pub name: String,
+    /// Mount destination inside the container
+    pub destination: String,
 }
 
 #[derive(Serialize)]
 pub struct FileListResponse {
     pub files: Vec<FileInfo>,
+    /// Running containers whose files can be browsed (`container:<name>`)
+    pub containers: Vec<String>,
 }
 
 #[derive(Serialize)]
@@ -30,17 +49,164 @@ pub struct WriteConfigResponse {
     pub success: bool,
 }
 
+/// Directory listing inside a container
+#[derive(Serialize)]
+pub struct ContainerDirResponse {
+    pub path: String,
+    pub entries: Vec<ContainerDirEntry>,
+}
+
+#[derive(Serialize)]
+pub struct ContainerDirEntry {
+    pub name: String,
+    pub dir: bool,
+}
+
+/// Compose service generated from a container
+#[derive(Serialize)]
+pub struct ComposeSnippetResponse {
+    pub service: String,
+    /// YAML with a single service (plus external volumes/networks)
+    pub content: String,
+    /// Name the snippet can be saved under (None if no directory takes it)
+    pub suggested_name: Option<String>,
+}
+
 #[derive(Serialize, Clone)]
 pub struct ContainerInfo {
     pub id: String,
     pub name: String,
     pub state: String,
     pub status: String,
+    pub image: String,
+    /// Creation time as printed by `docker ps` ("2024-05-01 12:00:00 +0000 UTC")
+    pub created: String,
+    /// Compose project (from the `com.docker.compose.project` label)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// Compose service (from the `com.docker.compose.service` label)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
 }
 
 #[derive(Serialize)]
 pub struct ContainerListResponse {
     pub containers: Vec<ContainerInfo>,
+    pub projects: Vec<ProjectInfo>,
+}
+
+/// Aggregated view of a Docker Compose project
+#[derive(Serialize, Clone)]
+pub struct ProjectInfo {
+    pub name: String,
+    /// IDs of all containers belonging to the project
+    pub containers: Vec<String>,
+    pub running: usize,
+    pub unhealthy: usize,
+    /// Compose files from `com.docker.compose.project.config_files`
+    pub config_files: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_dir: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct ProjectListResponse {
+    pub projects: Vec<ProjectInfo>,
+}
+
+/// What `compose up` would do with a service
+#[derive(Serialize, Clone)]
+pub struct ServicePlan {
+    pub service: String,
+    /// One of: create, recreate, unchanged, orphan
+    pub action: String,
+}
+
+#[derive(Serialize)]
+pub struct ApplyPlanResponse {
+    /// Whether `compose config` accepted the compose files
+    pub valid: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub services: Vec<ServicePlan>,
+}
+
+#[derive(Serialize, Clone)]
+pub struct ImageInfo {
+    /// Full image ID (`sha256:...`)
+    pub id: String,
+    /// `repository:tag` references (empty for dangling images)
+    pub tags: Vec<String>,
+    pub size: String,
+    /// Relative creation time (e.g. "3 weeks ago")
+    pub created: String,
+    pub dangling: bool,
+    /// Names of containers created from this image
+    pub containers: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct ImageListResponse {
+    pub images: Vec<ImageInfo>,
+}
+
+#[derive(Serialize)]
+pub struct ImageDetails {
+    pub id: String,
+    pub tags: Vec<String>,
+    pub digests: Vec<String>,
+    pub created: String,
+    pub size: String,
+    pub architecture: String,
+    pub os: String,
+    pub entrypoint: Vec<String>,
+    pub cmd: Vec<String>,
+    pub environment: Vec<String>,
+    pub exposed_ports: Vec<String>,
+    pub layers: usize,
+    pub containers: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct ImageDetailsResponse {
+    pub details: ImageDetails,
+}
+
+#[derive(Serialize)]
+pub struct VolumeInfo {
+    pub name: String,
+    pub driver: String,
+    pub mountpoint: String,
+    pub scope: String,
+    /// Disk usage, only known when `docker system df` reports it
+    pub size: Option<String>,
+    /// Names of containers mounting this volume
+    pub containers: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct VolumeListResponse {
+    pub volumes: Vec<VolumeInfo>,
+}
+
+#[derive(Serialize)]
+pub struct NetworkInfo {
+    pub id: String,
+    pub name: String,
+    pub driver: String,
+    pub scope: String,
+    pub internal: bool,
+    /// Predefined networks (bridge, host, none) that docker refuses to remove
+    pub builtin: bool,
+    /// IPAM subnets, with the gateway when one is configured
+    pub subnets: Vec<String>,
+    /// Names of containers attached to this network
+    pub containers: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct NetworkListResponse {
+    pub networks: Vec<NetworkInfo>,
 }
 
 #[derive(Serialize)]
@@ -49,13 +215,72 @@ pub struct ContainerActionResponse {
     pub message: String,
 }
 
+/// Returned by actions that run in the background, and by the job endpoint
+#[derive(Serialize)]
+pub struct JobResponse {
+    pub job: JobInfo,
+}
+
+#[derive(Serialize)]
+pub struct JobListResponse {
+    pub jobs: Vec<JobInfo>,
+}
+
+/// Settings changed with `docker update`; omitted fields are left alone
+#[derive(Deserialize)]
+pub struct UpdateSettingsRequest {
+    #[serde(default)]
+    pub restart_policy: Option<String>,
+    /// Memory limit with unit ("512m", "2g"), "0" for no limit
+    #[serde(default)]
+    pub memory: Option<String>,
+    /// Number of CPUs ("1.5"), "0" for no limit
+    #[serde(default)]
+    pub cpus: Option<String>,
+}
+
 #[derive(Serialize, Clone)]
 pub struct PortMapping {
     pub container_port: String,
-    pub host_port: String,
+    /// Host address the port is published on (`0.0.0.0`, `::`), None if only exposed
+    pub host_ip: Option<String>,
+    pub host_port: Option<String>,
     pub protocol: String,
 }
 
+/// Resource limits from the host config (0 means unlimited/default)
+#[derive(Serialize, Clone)]
+pub struct ResourceLimits {
+    /// Memory limit in bytes
+    pub memory: u64,
+    /// Soft memory limit in bytes
+    pub memory_reservation: u64,
+    /// Relative CPU weight (default 1024)
+    pub cpu_shares: u64,
+    /// CPU time per period in microseconds (-1 or 0 for no quota)
+    pub cpu_quota: i64,
+    pub cpu_period: u64,
+    /// CPU count in billionths (`--cpus`)
+    pub nano_cpus: u64,
+}
+
+#[derive(Serialize, Clone)]
+pub struct HealthcheckConfig {
+    pub test: Vec<String>,
+    pub interval: String,
+    pub timeout: String,
+    pub start_period: String,
+    pub retries: u64,
+}
+
+/// One health check run (docker keeps the last five)
+#[derive(Serialize, Clone)]
+pub struct HealthLogEntry {
+    pub start: String,
+    pub exit_code: i64,
+    pub output: String,
+}
+
 #[derive(Serialize, Clone)]
 pub struct VolumeMount {
     pub source: String,
@@ -78,6 +303,20 @@ pub struct ContainerDetails {
     pub environment: Vec<String>,
     pub restart_policy: String,
     pub health: Option<String>,
+    pub labels: BTreeMap<String, String>,
+    pub command: Vec<String>,
+    pub entrypoint: Vec<String>,
+    pub working_dir: String,
+    pub user: String,
+    pub limits: ResourceLimits,
+    pub restart_count: u64,
+    pub exit_code: i64,
+    pub oom_killed: bool,
+    pub finished: String,
+    pub healthcheck: Option<HealthcheckConfig>,
+    pub health_log: Vec<HealthLogEntry>,
+    /// Unmodified `docker inspect` output
+    pub raw: Value,
 }
 
 #[derive(Serialize)]
@@ -0,0 +1,69 @@
+use super::super::containers::{container_usage, ensure_allowed_on_all, user_names};
+use super::super::docker::{reclaimed_space, run_docker, validate_docker_name};
+use super::super::types::{ContainerActionResponse, VolumeListResponse};
+use super::listing::fetch_volumes;
+use crate::config::SharedConfig;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use std::time::Duration;
+
+/// Timeout for volume removal and pruning
+const VOLUME_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// GET /api/volumes - List volumes with size and the containers using them
+pub async fn list_volumes(
+    State(config): State<SharedConfig>,
+) -> Result<Json<VolumeListResponse>, (StatusCode, String)> {
+    let volumes = fetch_volumes(config.container_access()).await?;
+    Ok(Json(VolumeListResponse { volumes }))
+}
+
+/// POST /api/volumes/:name/remove - Remove an unused volume
+pub async fn remove_volume(
+    State(config): State<SharedConfig>,
+    Path(name): Path<String>,
+) -> Result<Json<ContainerActionResponse>, (StatusCode, String)> {
+    validate_docker_name(&name, "volume name")?;
+
+    let usage = container_usage(config.container_access()).await?;
+    let users = user_names(usage.iter().filter(|c| c.volumes.contains(&name)));
+    if !users.is_empty() {
+        return Err((
+            StatusCode::CONFLICT,
+            format!("Volume is used by: {}", users.join(", ")),
+        ));
+    }
+
+    run_docker(&["volume", "rm", &name], "volume rm", VOLUME_TIMEOUT).await?;
+
+    Ok(Json(ContainerActionResponse {
+        success: true,
+        message: "volume removed".to_string(),
+    }))
+}
+
+/// POST /api/volumes/prune - Remove unused anonymous volumes
+///
+/// Acts on the whole daemon, so every container must permit "prune".
+pub async fn prune_volumes(
+    State(config): State<SharedConfig>,
+) -> Result<Json<ContainerActionResponse>, (StatusCode, String)> {
+    ensure_allowed_on_all(config.container_access(), "prune").await?;
+    let stdout = run_docker(
+        &["volume", "prune", "--force"],
+        "volume prune",
+        VOLUME_TIMEOUT,
+    )
+    .await?;
+
+    Ok(Json(ContainerActionResponse {
+        success: true,
+        message: format!(
+            "unused anonymous volumes pruned ({} reclaimed)",
+            reclaimed_space(&stdout)
+        ),
+    }))
+}
@@ -0,0 +1,96 @@
+use super::super::containers::{container_usage, user_names};
+use super::super::types::VolumeInfo;
+use crate::config::ContainerAccess;
+use axum::http::StatusCode;
+use serde::Deserialize;
+use std::collections::HashMap;
+use tokio::process::Command;
+
+/// One line of `docker volume ls --format '{{json .}}'`
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VolumeLine {
+    name: String,
+    driver: String,
+    #[serde(default)]
+    mountpoint: String,
+    #[serde(default)]
+    scope: String,
+}
+
+/// One entry of `docker system df -v` volumes
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VolumeUsage {
+    name: String,
+    size: String,
+}
+
+/// List volumes with their size and the containers mounting them
+pub(super) async fn fetch_volumes(
+    access: &ContainerAccess,
+) -> Result<Vec<VolumeInfo>, (StatusCode, String)> {
+    let output = Command::new("docker")
+        .args(["volume", "ls", "--format", "{{json .}}"])
+        .output()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to execute docker command: {}", e),
+            )
+        })?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Docker command failed: {}", error),
+        ));
+    }
+
+    // Usage and sizes are informational; without them the list still works
+    let usage = container_usage(access).await.unwrap_or_default();
+    let sizes = volume_sizes().await;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut volumes: Vec<VolumeInfo> = stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<VolumeLine>(line).ok())
+        .map(|line| VolumeInfo {
+            containers: user_names(usage.iter().filter(|c| c.volumes.contains(&line.name))),
+            size: sizes.get(&line.name).cloned(),
+            name: line.name,
+            driver: line.driver,
+            mountpoint: line.mountpoint,
+            scope: line.scope,
+        })
+        .collect();
+
+    volumes.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(volumes)
+}
+
+/// Volume sizes by name from `docker system df -v`
+///
+/// Only the local driver reports sizes, and computing them can be slow on
+/// large volumes, so any failure just leaves the sizes unknown
+async fn volume_sizes() -> HashMap<String, String> {
+    let Ok(output) = Command::new("docker")
+        .args(["system", "df", "-v", "--format", "{{json .Volumes}}"])
+        .output()
+        .await
+    else {
+        return HashMap::new();
+    };
+
+    serde_json::from_slice::<Vec<VolumeUsage>>(&output.stdout)
+        .map(|volumes| {
+            volumes
+                .into_iter()
+                .filter(|v| v.size != "N/A")
+                .map(|v| (v.name, v.size))
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,4 @@
+mod handlers;
+mod listing;
+
+pub use handlers::{list_volumes, prune_volumes, remove_volume};
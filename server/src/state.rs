@@ -0,0 +1,22 @@
+use crate::config::SharedConfig;
+use crate::jobs::SharedJobs;
+use axum::extract::FromRef;
+
+/// Router state; handlers extract the part they need
+#[derive(Clone)]
+pub struct ServerState {
+    pub config: SharedConfig,
+    pub jobs: SharedJobs,
+}
+
+impl FromRef<ServerState> for SharedConfig {
+    fn from_ref(state: &ServerState) -> Self {
+        state.config.clone()
+    }
+}
+
+impl FromRef<ServerState> for SharedJobs {
+    fn from_ref(state: &ServerState) -> Self {
+        state.jobs.clone()
+    }
+}